@@ -0,0 +1,339 @@
+use alloc::{string::String, vec::Vec};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractPackageHash, Key, U256,
+};
+
+pub const CHANGE_SET_SIGNER_SET_TAG: u8 = 0;
+pub const CHANGE_SET_STABLE_COMMISSION_PERCENT_TAG: u8 = 1;
+pub const CHANGE_SET_TOKEN_STABLE_COMMISSION_PERCENT_TAG: u8 = 2;
+pub const CHANGE_GRANT_ROLE_TAG: u8 = 3;
+pub const CHANGE_REVOKE_ROLE_TAG: u8 = 4;
+pub const CHANGE_SET_TIMELOCK_DELAY_TAG: u8 = 5;
+pub const CHANGE_SET_MAX_DEADLINE_HORIZON_TAG: u8 = 6;
+pub const CHANGE_SET_TREASURY_TAG: u8 = 7;
+pub const CHANGE_SET_BRIDGE_OUT_LIMITS_TAG: u8 = 8;
+pub const CHANGE_REMOVE_TOKEN_TAG: u8 = 9;
+pub const CHANGE_SET_TOKEN_MODE_TAG: u8 = 10;
+pub const CHANGE_REBALANCE_LIQUIDITY_TAG: u8 = 11;
+pub const CHANGE_ADD_TOKEN_TAG: u8 = 12;
+pub const CHANGE_SET_BRIDGE_IN_LIMITS_TAG: u8 = 13;
+
+/// Privileged change applied by `execute_change` once its timelock has elapsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    SetSignerSet {
+        signers: Vec<String>,
+        threshold: u8,
+    },
+    SetStableCommissionPercent {
+        value: U256,
+    },
+    /// `None` makes the token fall back to the global stable commission percent.
+    SetTokenStableCommissionPercent {
+        token_contract: ContractPackageHash,
+        value: Option<U256>,
+    },
+    GrantRole {
+        role: String,
+        account: Key,
+    },
+    RevokeRole {
+        role: String,
+        account: Key,
+    },
+    SetTimelockDelay {
+        delay: u64,
+    },
+    SetMaxDeadlineHorizon {
+        horizon: u64,
+    },
+    /// Account or contract receiving the liquidity moved by `rebalance_liquidity`.
+    SetTreasury {
+        treasury: Key,
+    },
+    SetBridgeOutLimits {
+        token_contract: ContractPackageHash,
+        max_amount: U256,
+        outflow_cap: U256,
+        outflow_window: u64,
+    },
+    RemoveToken {
+        token_contract: ContractPackageHash,
+    },
+    SetTokenMode {
+        token_contract: ContractPackageHash,
+        mode: u8,
+    },
+    RebalanceLiquidity {
+        token_contract: ContractPackageHash,
+        amount: U256,
+    },
+    AddToken {
+        token_contract: ContractPackageHash,
+    },
+    SetBridgeInLimits {
+        token_contract: ContractPackageHash,
+        min_amount: U256,
+        max_amount: U256,
+    },
+}
+
+impl CLTyped for Change {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Change {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        match self {
+            Change::SetSignerSet { signers, threshold } => {
+                buffer.push(CHANGE_SET_SIGNER_SET_TAG);
+                buffer.extend(signers.to_bytes()?);
+                buffer.extend(threshold.to_bytes()?);
+            }
+            Change::SetStableCommissionPercent { value } => {
+                buffer.push(CHANGE_SET_STABLE_COMMISSION_PERCENT_TAG);
+                buffer.extend(value.to_bytes()?);
+            }
+            Change::SetTokenStableCommissionPercent {
+                token_contract,
+                value,
+            } => {
+                buffer.push(CHANGE_SET_TOKEN_STABLE_COMMISSION_PERCENT_TAG);
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(value.to_bytes()?);
+            }
+            Change::GrantRole { role, account } => {
+                buffer.push(CHANGE_GRANT_ROLE_TAG);
+                buffer.extend(role.to_bytes()?);
+                buffer.extend(account.to_bytes()?);
+            }
+            Change::RevokeRole { role, account } => {
+                buffer.push(CHANGE_REVOKE_ROLE_TAG);
+                buffer.extend(role.to_bytes()?);
+                buffer.extend(account.to_bytes()?);
+            }
+            Change::SetTimelockDelay { delay } => {
+                buffer.push(CHANGE_SET_TIMELOCK_DELAY_TAG);
+                buffer.extend(delay.to_bytes()?);
+            }
+            Change::SetMaxDeadlineHorizon { horizon } => {
+                buffer.push(CHANGE_SET_MAX_DEADLINE_HORIZON_TAG);
+                buffer.extend(horizon.to_bytes()?);
+            }
+            Change::SetTreasury { treasury } => {
+                buffer.push(CHANGE_SET_TREASURY_TAG);
+                buffer.extend(treasury.to_bytes()?);
+            }
+            Change::SetBridgeOutLimits {
+                token_contract,
+                max_amount,
+                outflow_cap,
+                outflow_window,
+            } => {
+                buffer.push(CHANGE_SET_BRIDGE_OUT_LIMITS_TAG);
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(max_amount.to_bytes()?);
+                buffer.extend(outflow_cap.to_bytes()?);
+                buffer.extend(outflow_window.to_bytes()?);
+            }
+            Change::RemoveToken { token_contract } => {
+                buffer.push(CHANGE_REMOVE_TOKEN_TAG);
+                buffer.extend(token_contract.to_bytes()?);
+            }
+            Change::SetTokenMode {
+                token_contract,
+                mode,
+            } => {
+                buffer.push(CHANGE_SET_TOKEN_MODE_TAG);
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(mode.to_bytes()?);
+            }
+            Change::RebalanceLiquidity {
+                token_contract,
+                amount,
+            } => {
+                buffer.push(CHANGE_REBALANCE_LIQUIDITY_TAG);
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(amount.to_bytes()?);
+            }
+            Change::AddToken { token_contract } => {
+                buffer.push(CHANGE_ADD_TOKEN_TAG);
+                buffer.extend(token_contract.to_bytes()?);
+            }
+            Change::SetBridgeInLimits {
+                token_contract,
+                min_amount,
+                max_amount,
+            } => {
+                buffer.push(CHANGE_SET_BRIDGE_IN_LIMITS_TAG);
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(min_amount.to_bytes()?);
+                buffer.extend(max_amount.to_bytes()?);
+            }
+        }
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        1 + match self {
+            Change::SetSignerSet { signers, threshold } => {
+                signers.serialized_length() + threshold.serialized_length()
+            }
+            Change::SetStableCommissionPercent { value } => value.serialized_length(),
+            Change::SetTokenStableCommissionPercent {
+                token_contract,
+                value,
+            } => token_contract.serialized_length() + value.serialized_length(),
+            Change::GrantRole { role, account } | Change::RevokeRole { role, account } => {
+                role.serialized_length() + account.serialized_length()
+            }
+            Change::SetTimelockDelay { delay } => delay.serialized_length(),
+            Change::SetMaxDeadlineHorizon { horizon } => horizon.serialized_length(),
+            Change::SetTreasury { treasury } => treasury.serialized_length(),
+            Change::SetBridgeOutLimits {
+                token_contract,
+                max_amount,
+                outflow_cap,
+                outflow_window,
+            } => {
+                token_contract.serialized_length()
+                    + max_amount.serialized_length()
+                    + outflow_cap.serialized_length()
+                    + outflow_window.serialized_length()
+            }
+            Change::RemoveToken { token_contract } | Change::AddToken { token_contract } => {
+                token_contract.serialized_length()
+            }
+            Change::SetTokenMode {
+                token_contract,
+                mode,
+            } => token_contract.serialized_length() + mode.serialized_length(),
+            Change::RebalanceLiquidity {
+                token_contract,
+                amount,
+            } => token_contract.serialized_length() + amount.serialized_length(),
+            Change::SetBridgeInLimits {
+                token_contract,
+                min_amount,
+                max_amount,
+            } => {
+                token_contract.serialized_length()
+                    + min_amount.serialized_length()
+                    + max_amount.serialized_length()
+            }
+        }
+    }
+}
+
+impl FromBytes for Change {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            CHANGE_SET_SIGNER_SET_TAG => {
+                let (signers, remainder) = Vec::<String>::from_bytes(remainder)?;
+                let (threshold, remainder) = u8::from_bytes(remainder)?;
+                Ok((Change::SetSignerSet { signers, threshold }, remainder))
+            }
+            CHANGE_SET_STABLE_COMMISSION_PERCENT_TAG => {
+                let (value, remainder) = U256::from_bytes(remainder)?;
+                Ok((Change::SetStableCommissionPercent { value }, remainder))
+            }
+            CHANGE_SET_TOKEN_STABLE_COMMISSION_PERCENT_TAG => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (value, remainder) = Option::<U256>::from_bytes(remainder)?;
+                Ok((
+                    Change::SetTokenStableCommissionPercent {
+                        token_contract,
+                        value,
+                    },
+                    remainder,
+                ))
+            }
+            CHANGE_GRANT_ROLE_TAG => {
+                let (role, remainder) = String::from_bytes(remainder)?;
+                let (account, remainder) = Key::from_bytes(remainder)?;
+                Ok((Change::GrantRole { role, account }, remainder))
+            }
+            CHANGE_REVOKE_ROLE_TAG => {
+                let (role, remainder) = String::from_bytes(remainder)?;
+                let (account, remainder) = Key::from_bytes(remainder)?;
+                Ok((Change::RevokeRole { role, account }, remainder))
+            }
+            CHANGE_SET_TIMELOCK_DELAY_TAG => {
+                let (delay, remainder) = u64::from_bytes(remainder)?;
+                Ok((Change::SetTimelockDelay { delay }, remainder))
+            }
+            CHANGE_SET_MAX_DEADLINE_HORIZON_TAG => {
+                let (horizon, remainder) = u64::from_bytes(remainder)?;
+                Ok((Change::SetMaxDeadlineHorizon { horizon }, remainder))
+            }
+            CHANGE_SET_TREASURY_TAG => {
+                let (treasury, remainder) = Key::from_bytes(remainder)?;
+                Ok((Change::SetTreasury { treasury }, remainder))
+            }
+            CHANGE_SET_BRIDGE_OUT_LIMITS_TAG => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (max_amount, remainder) = U256::from_bytes(remainder)?;
+                let (outflow_cap, remainder) = U256::from_bytes(remainder)?;
+                let (outflow_window, remainder) = u64::from_bytes(remainder)?;
+                Ok((
+                    Change::SetBridgeOutLimits {
+                        token_contract,
+                        max_amount,
+                        outflow_cap,
+                        outflow_window,
+                    },
+                    remainder,
+                ))
+            }
+            CHANGE_REMOVE_TOKEN_TAG => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                Ok((Change::RemoveToken { token_contract }, remainder))
+            }
+            CHANGE_SET_TOKEN_MODE_TAG => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (mode, remainder) = u8::from_bytes(remainder)?;
+                Ok((
+                    Change::SetTokenMode {
+                        token_contract,
+                        mode,
+                    },
+                    remainder,
+                ))
+            }
+            CHANGE_REBALANCE_LIQUIDITY_TAG => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                Ok((
+                    Change::RebalanceLiquidity {
+                        token_contract,
+                        amount,
+                    },
+                    remainder,
+                ))
+            }
+            CHANGE_ADD_TOKEN_TAG => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                Ok((Change::AddToken { token_contract }, remainder))
+            }
+            CHANGE_SET_BRIDGE_IN_LIMITS_TAG => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (min_amount, remainder) = U256::from_bytes(remainder)?;
+                let (max_amount, remainder) = U256::from_bytes(remainder)?;
+                Ok((
+                    Change::SetBridgeInLimits {
+                        token_contract,
+                        min_amount,
+                        max_amount,
+                    },
+                    remainder,
+                ))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
@@ -1,14 +1,57 @@
-use alloc::{string::String, vec::Vec};
+use alloc::{string::String, vec, vec::Vec};
 use casper_types::{
-    bytesrepr::{self, FromBytes, ToBytes},
-    ContractPackageHash, Key, U128, U256,
+    bytesrepr::{self, Bytes, FromBytes, ToBytes, U32_SERIALIZED_LENGTH, U8_SERIALIZED_LENGTH},
+    CLType, CLTyped, ContractPackageHash, Key, U128, U256,
 };
-use contract_util::event::ContractEvent;
+use contract_util::event::{ces_item_key, ContractEvent, Schemas, CES_EVENT_PREFIX};
+
+use crate::change::Change;
 
 pub const BRIDGE_EVENT_FUNDS_IN_TAG: u8 = 0;
 pub const BRIDGE_EVENT_FUNDS_OUT_TAG: u8 = 1;
 pub const BRIDGE_EVENT_TRANSFER_OUT: u8 = 2;
 pub const BRIDGE_EVENT_WITHDRAW_COMMISSION: u8 = 3;
+pub const BRIDGE_EVENT_PAUSED: u8 = 4;
+pub const BRIDGE_EVENT_UNPAUSED: u8 = 5;
+pub const BRIDGE_EVENT_SIGNER_SET_UPDATED: u8 = 6;
+pub const BRIDGE_EVENT_ROLE_GRANTED: u8 = 7;
+pub const BRIDGE_EVENT_ROLE_REVOKED: u8 = 8;
+pub const BRIDGE_EVENT_LIQUIDITY_MOVED: u8 = 9;
+pub const BRIDGE_EVENT_CHANGE_PROPOSED: u8 = 10;
+pub const BRIDGE_EVENT_CHANGE_EXECUTED: u8 = 11;
+pub const BRIDGE_EVENT_CHANGE_CANCELLED: u8 = 12;
+pub const BRIDGE_EVENT_SIGNER_CHANGED: u8 = 13;
+pub const BRIDGE_EVENT_STABLE_COMMISSION_CHANGED: u8 = 14;
+pub const BRIDGE_EVENT_TOKEN_STABLE_COMMISSION_CHANGED: u8 = 15;
+pub const BRIDGE_EVENT_TOKEN_CONFIG_CHANGED: u8 = 16;
+pub const BRIDGE_EVENT_TOKEN_LIMITS_CHANGED: u8 = 17;
+pub const BRIDGE_EVENT_TIMELOCK_DELAY_CHANGED: u8 = 18;
+pub const BRIDGE_EVENT_MAX_DEADLINE_HORIZON_CHANGED: u8 = 19;
+pub const BRIDGE_EVENT_TREASURY_CHANGED: u8 = 20;
+
+/// Version of the event envelope format.
+///
+/// Within a version, variants only get new tags and fields are only appended to the end of a
+/// payload, so decoders of that version skip what they don't know about. Events emitted before
+/// fields were appended to their variant decode into the `*V1` variants.
+pub const EVENT_ENCODING_VERSION: u8 = 1;
+
+/// First byte of every event envelope.
+///
+/// Events emitted before the envelope start with their bare tag, so the marker lies outside the
+/// legacy tags to tell the two encodings apart.
+pub const EVENT_ENVELOPE_MAGIC: u8 = 0xee;
+
+/// Highest tag of the events emitted before the envelope, as a bare tag followed by the fields.
+const LEGACY_EVENT_MAX_TAG: u8 = BRIDGE_EVENT_WITHDRAW_COMMISSION;
+
+/// Length of the envelope preceding the event fields: the marker, the version, the tag and the
+/// payload length.
+const EVENT_ENVELOPE_HEADER_LENGTH: usize =
+    U8_SERIALIZED_LENGTH + U8_SERIALIZED_LENGTH + U8_SERIALIZED_LENGTH + U32_SERIALIZED_LENGTH;
+
+/// Token identifier used in events of native CSPR transfers.
+pub const NATIVE_TOKEN_CONTRACT: ContractPackageHash = ContractPackageHash::new([0u8; 32]);
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum BridgeEvent {
@@ -31,24 +74,202 @@ pub enum BridgeEvent {
         transaction_id: U256,
         recipient: Key,
     },
+    /// Fields after `recipient` are appended to the original layout, kept as `TransferOutV1`.
     TransferOut {
         token_contract: ContractPackageHash,
         total_sum_for_transfer: U256,
         nonce: U128,
         recipient: Key,
+        amount: U256,
+        commission: U256,
+        transaction_id: U256,
     },
+    /// `recipient` is appended to the original layout, kept as `WithdrawCommissionV1`.
     WithdrawCommission {
         token_contract: ContractPackageHash,
         amount: U256,
+        recipient: Key,
+    },
+    Paused,
+    Unpaused,
+    SignerSetUpdated {
+        signers: Vec<String>,
+        threshold: u8,
+    },
+    RoleGranted {
+        role: String,
+        account: Key,
+    },
+    RoleRevoked {
+        role: String,
+        account: Key,
+    },
+    /// Pool funds moved by an operator, not a user transfer.
+    LiquidityMoved {
+        token_contract: ContractPackageHash,
+        amount: U256,
+        from: Key,
+        to: Key,
+    },
+    ChangeProposed {
+        id: u64,
+        change: Change,
+        eta: u64,
+    },
+    ChangeExecuted {
+        id: u64,
+    },
+    ChangeCancelled {
+        id: u64,
+    },
+    /// Signer set replaced by a single signer. `old` is the first signer of the replaced set,
+    /// empty if there was none.
+    SignerChanged {
+        old: String,
+        new: String,
+    },
+    /// Global stable commission percent changed, in BPS. Migrating a whole percent value to BPS
+    /// doesn't change the commission and is not reported.
+    StableCommissionChanged {
+        old: U256,
+        new: U256,
+    },
+    /// Stable commission override of a token changed, in BPS. `None` is no override, the token
+    /// then pays the global stable commission percent.
+    TokenStableCommissionChanged {
+        token_contract: ContractPackageHash,
+        old: Option<U256>,
+        new: Option<U256>,
+    },
+    /// Token added, removed or switched between the lock and mint/burn modes.
+    TokenConfigChanged {
+        token_contract: ContractPackageHash,
+        enabled: bool,
+        mode: u8,
+    },
+    /// Bridge in or bridge out limits of a token changed. Zero maximums and caps are unlimited.
+    TokenLimitsChanged {
+        token_contract: ContractPackageHash,
+        min_bridge_in: U256,
+        max_bridge_in: U256,
+        max_bridge_out: U256,
+        outflow_cap: U256,
+        outflow_window: u64,
+    },
+    TimelockDelayChanged {
+        old: u64,
+        new: u64,
+    },
+    MaxDeadlineHorizonChanged {
+        old: u64,
+        new: u64,
+    },
+    /// Account or contract receiving rebalanced liquidity changed.
+    TreasuryChanged {
+        old: Option<Key>,
+        new: Key,
+    },
+    /// `TransferOut` as emitted before the amount, commission and transaction id were appended.
+    /// Only decoded from historical events.
+    TransferOutV1 {
+        token_contract: ContractPackageHash,
+        total_sum_for_transfer: U256,
+        nonce: U128,
+        recipient: Key,
+    },
+    /// `WithdrawCommission` as emitted before the recipient was appended. Only decoded from
+    /// historical events.
+    WithdrawCommissionV1 {
+        token_contract: ContractPackageHash,
+        amount: U256,
     },
 }
 
-impl ContractEvent for BridgeEvent {}
+/// Encoded event: the envelope marker, the encoding version, the variant tag and the
+/// length-prefixed variant fields.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventEnvelope {
+    pub version: u8,
+    pub tag: u8,
+    pub payload: Bytes,
+}
 
-impl ToBytes for BridgeEvent {
+impl ToBytes for EventEnvelope {
     fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
         let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.push(EVENT_ENVELOPE_MAGIC);
+        buffer.push(self.version);
+        buffer.push(self.tag);
+        buffer.extend(self.payload.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        U8_SERIALIZED_LENGTH
+            + U8_SERIALIZED_LENGTH
+            + U8_SERIALIZED_LENGTH
+            + self.payload.serialized_length()
+    }
+}
+
+impl FromBytes for EventEnvelope {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (magic, remainder) = u8::from_bytes(bytes)?;
+        if magic != EVENT_ENVELOPE_MAGIC {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (version, remainder) = u8::from_bytes(remainder)?;
+        if version != EVENT_ENCODING_VERSION {
+            return Err(bytesrepr::Error::Formatting);
+        }
+        let (tag, remainder) = u8::from_bytes(remainder)?;
+        let (payload, remainder) = Bytes::from_bytes(remainder)?;
+        Ok((
+            EventEnvelope {
+                version,
+                tag,
+                payload,
+            },
+            remainder,
+        ))
+    }
+}
+
+impl BridgeEvent {
+    /// Tag the variant is encoded with.
+    pub fn tag(&self) -> u8 {
+        match self {
+            BridgeEvent::FundsIn { .. } => BRIDGE_EVENT_FUNDS_IN_TAG,
+            BridgeEvent::FundsOut { .. } => BRIDGE_EVENT_FUNDS_OUT_TAG,
+            BridgeEvent::TransferOut { .. } => BRIDGE_EVENT_TRANSFER_OUT,
+            BridgeEvent::WithdrawCommission { .. } => BRIDGE_EVENT_WITHDRAW_COMMISSION,
+            BridgeEvent::Paused => BRIDGE_EVENT_PAUSED,
+            BridgeEvent::Unpaused => BRIDGE_EVENT_UNPAUSED,
+            BridgeEvent::SignerSetUpdated { .. } => BRIDGE_EVENT_SIGNER_SET_UPDATED,
+            BridgeEvent::RoleGranted { .. } => BRIDGE_EVENT_ROLE_GRANTED,
+            BridgeEvent::RoleRevoked { .. } => BRIDGE_EVENT_ROLE_REVOKED,
+            BridgeEvent::LiquidityMoved { .. } => BRIDGE_EVENT_LIQUIDITY_MOVED,
+            BridgeEvent::ChangeProposed { .. } => BRIDGE_EVENT_CHANGE_PROPOSED,
+            BridgeEvent::ChangeExecuted { .. } => BRIDGE_EVENT_CHANGE_EXECUTED,
+            BridgeEvent::ChangeCancelled { .. } => BRIDGE_EVENT_CHANGE_CANCELLED,
+            BridgeEvent::SignerChanged { .. } => BRIDGE_EVENT_SIGNER_CHANGED,
+            BridgeEvent::StableCommissionChanged { .. } => BRIDGE_EVENT_STABLE_COMMISSION_CHANGED,
+            BridgeEvent::TokenStableCommissionChanged { .. } => {
+                BRIDGE_EVENT_TOKEN_STABLE_COMMISSION_CHANGED
+            }
+            BridgeEvent::TokenConfigChanged { .. } => BRIDGE_EVENT_TOKEN_CONFIG_CHANGED,
+            BridgeEvent::TokenLimitsChanged { .. } => BRIDGE_EVENT_TOKEN_LIMITS_CHANGED,
+            BridgeEvent::TimelockDelayChanged { .. } => BRIDGE_EVENT_TIMELOCK_DELAY_CHANGED,
+            BridgeEvent::MaxDeadlineHorizonChanged { .. } => {
+                BRIDGE_EVENT_MAX_DEADLINE_HORIZON_CHANGED
+            }
+            BridgeEvent::TreasuryChanged { .. } => BRIDGE_EVENT_TREASURY_CHANGED,
+            BridgeEvent::TransferOutV1 { .. } => BRIDGE_EVENT_TRANSFER_OUT,
+            BridgeEvent::WithdrawCommissionV1 { .. } => BRIDGE_EVENT_WITHDRAW_COMMISSION,
+        }
+    }
 
+    fn fields_serialized_length(&self) -> usize {
         match self {
             BridgeEvent::FundsIn {
                 token_contract,
@@ -61,16 +282,15 @@ impl ToBytes for BridgeEvent {
                 transaction_id,
                 sender,
             } => {
-                buffer.push(BRIDGE_EVENT_FUNDS_IN_TAG);
-                buffer.extend(token_contract.to_bytes()?);
-                buffer.extend(destination_chain.to_bytes()?);
-                buffer.extend(destination_address.to_bytes()?);
-                buffer.extend(amount.to_bytes()?);
-                buffer.extend(gas_commission.to_bytes()?);
-                buffer.extend(stable_commission_percent.to_bytes()?);
-                buffer.extend(nonce.to_bytes()?);
-                buffer.extend(transaction_id.to_bytes()?);
-                buffer.extend(sender.to_bytes()?);
+                token_contract.serialized_length()
+                    + destination_chain.serialized_length()
+                    + destination_address.serialized_length()
+                    + amount.serialized_length()
+                    + gas_commission.serialized_length()
+                    + stable_commission_percent.serialized_length()
+                    + nonce.serialized_length()
+                    + transaction_id.serialized_length()
+                    + sender.serialized_length()
             }
             BridgeEvent::FundsOut {
                 token_contract,
@@ -80,77 +300,111 @@ impl ToBytes for BridgeEvent {
                 transaction_id,
                 recipient,
             } => {
-                buffer.push(BRIDGE_EVENT_FUNDS_OUT_TAG);
-                buffer.extend(token_contract.to_bytes()?);
-                buffer.extend(source_chain.to_bytes()?);
-                buffer.extend(source_address.to_bytes()?);
-                buffer.extend(amount.to_bytes()?);
-                buffer.extend(transaction_id.to_bytes()?);
-                buffer.extend(recipient.to_bytes()?);
+                token_contract.serialized_length()
+                    + source_chain.serialized_length()
+                    + source_address.serialized_length()
+                    + amount.serialized_length()
+                    + transaction_id.serialized_length()
+                    + recipient.serialized_length()
             }
             BridgeEvent::TransferOut {
                 token_contract,
                 total_sum_for_transfer,
                 nonce,
                 recipient,
+                amount,
+                commission,
+                transaction_id,
             } => {
-                buffer.push(BRIDGE_EVENT_TRANSFER_OUT);
-                buffer.extend(token_contract.to_bytes()?);
-                buffer.extend(total_sum_for_transfer.to_bytes()?);
-                buffer.extend(nonce.to_bytes()?);
-                buffer.extend(recipient.to_bytes()?);
+                token_contract.serialized_length()
+                    + total_sum_for_transfer.serialized_length()
+                    + nonce.serialized_length()
+                    + recipient.serialized_length()
+                    + amount.serialized_length()
+                    + commission.serialized_length()
+                    + transaction_id.serialized_length()
             }
             BridgeEvent::WithdrawCommission {
                 token_contract,
                 amount,
+                recipient,
             } => {
-                buffer.push(BRIDGE_EVENT_WITHDRAW_COMMISSION);
-                buffer.extend(token_contract.to_bytes()?);
-                buffer.extend(amount.to_bytes()?);
+                token_contract.serialized_length()
+                    + amount.serialized_length()
+                    + recipient.serialized_length()
             }
-        }
-
-        Ok(buffer)
-    }
-
-    fn serialized_length(&self) -> usize {
-        match self {
-            BridgeEvent::FundsIn {
+            BridgeEvent::Paused | BridgeEvent::Unpaused => 0,
+            BridgeEvent::SignerSetUpdated { signers, threshold } => {
+                signers.serialized_length() + threshold.serialized_length()
+            }
+            BridgeEvent::RoleGranted { role, account }
+            | BridgeEvent::RoleRevoked { role, account } => {
+                role.serialized_length() + account.serialized_length()
+            }
+            BridgeEvent::LiquidityMoved {
                 token_contract,
-                destination_chain,
-                destination_address,
                 amount,
-                gas_commission,
-                stable_commission_percent,
-                nonce,
-                transaction_id,
-                sender,
+                from,
+                to,
             } => {
-                destination_chain.serialized_length()
-                    + destination_address.serialized_length()
+                token_contract.serialized_length()
                     + amount.serialized_length()
-                    + gas_commission.serialized_length()
-                    + stable_commission_percent.serialized_length()
-                    + nonce.serialized_length()
-                    + sender.serialized_length()
-                    + token_contract.serialized_length()
+                    + from.serialized_length()
+                    + to.serialized_length()
             }
-            BridgeEvent::FundsOut {
+            BridgeEvent::ChangeProposed { id, change, eta } => {
+                id.serialized_length() + change.serialized_length() + eta.serialized_length()
+            }
+            BridgeEvent::ChangeExecuted { id } | BridgeEvent::ChangeCancelled { id } => {
+                id.serialized_length()
+            }
+            BridgeEvent::SignerChanged { old, new } => {
+                old.serialized_length() + new.serialized_length()
+            }
+            BridgeEvent::StableCommissionChanged { old, new } => {
+                old.serialized_length() + new.serialized_length()
+            }
+            BridgeEvent::TokenStableCommissionChanged {
                 token_contract,
-                source_chain,
-                source_address,
-                amount,
-                transaction_id,
-                recipient,
+                old,
+                new,
             } => {
-                source_chain.serialized_length()
-                    + source_address.serialized_length()
-                    + amount.serialized_length()
-                    + transaction_id.serialized_length()
-                    + recipient.serialized_length()
-                    + token_contract.serialized_length()
+                token_contract.serialized_length()
+                    + old.serialized_length()
+                    + new.serialized_length()
             }
-            BridgeEvent::TransferOut {
+            BridgeEvent::TokenConfigChanged {
+                token_contract,
+                enabled,
+                mode,
+            } => {
+                token_contract.serialized_length()
+                    + enabled.serialized_length()
+                    + mode.serialized_length()
+            }
+            BridgeEvent::TokenLimitsChanged {
+                token_contract,
+                min_bridge_in,
+                max_bridge_in,
+                max_bridge_out,
+                outflow_cap,
+                outflow_window,
+            } => {
+                token_contract.serialized_length()
+                    + min_bridge_in.serialized_length()
+                    + max_bridge_in.serialized_length()
+                    + max_bridge_out.serialized_length()
+                    + outflow_cap.serialized_length()
+                    + outflow_window.serialized_length()
+            }
+            BridgeEvent::TimelockDelayChanged { old, new }
+            | BridgeEvent::MaxDeadlineHorizonChanged { old, new } => {
+                old.serialized_length() + new.serialized_length()
+            }
+            BridgeEvent::TreasuryChanged { old, new } => {
+                old.serialized_length() + new.serialized_length()
+            }
+            BridgeEvent::TransferOutV1 {
                 token_contract,
                 total_sum_for_transfer,
                 nonce,
@@ -161,17 +415,22 @@ impl ToBytes for BridgeEvent {
                     + nonce.serialized_length()
                     + recipient.serialized_length()
             }
-            BridgeEvent::WithdrawCommission {
+            BridgeEvent::WithdrawCommissionV1 {
                 token_contract,
                 amount,
             } => token_contract.serialized_length() + amount.serialized_length(),
         }
     }
-}
 
-impl FromBytes for BridgeEvent {
-    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
-        let (tag, remainder) = u8::from_bytes(bytes)?;
+    /// Decodes the fields of the variant with `tag`, returning the bytes that follow them.
+    ///
+    /// `bounded` tells whether `remainder` ends with the fields, as envelope payloads and CES
+    /// items do. Only then are fields appended since an event was emitted read, when present.
+    fn fields_from_bytes(
+        tag: u8,
+        remainder: &[u8],
+        bounded: bool,
+    ) -> Result<(Self, &[u8]), bytesrepr::Error> {
         match tag {
             BRIDGE_EVENT_FUNDS_IN_TAG => {
                 let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
@@ -222,12 +481,29 @@ impl FromBytes for BridgeEvent {
                 let (total_sum_for_transfer, remainder) = U256::from_bytes(remainder)?;
                 let (nonce, remainder) = U128::from_bytes(remainder)?;
                 let (recipient, remainder) = Key::from_bytes(remainder)?;
+                if !bounded || remainder.is_empty() {
+                    return Ok((
+                        BridgeEvent::TransferOutV1 {
+                            token_contract,
+                            total_sum_for_transfer,
+                            nonce,
+                            recipient,
+                        },
+                        remainder,
+                    ));
+                }
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                let (commission, remainder) = U256::from_bytes(remainder)?;
+                let (transaction_id, remainder) = U256::from_bytes(remainder)?;
                 Ok((
                     BridgeEvent::TransferOut {
                         token_contract,
                         total_sum_for_transfer,
                         nonce,
                         recipient,
+                        amount,
+                        commission,
+                        transaction_id,
                     },
                     remainder,
                 ))
@@ -235,15 +511,627 @@ impl FromBytes for BridgeEvent {
             BRIDGE_EVENT_WITHDRAW_COMMISSION => {
                 let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
                 let (amount, remainder) = U256::from_bytes(remainder)?;
+                if !bounded || remainder.is_empty() {
+                    return Ok((
+                        BridgeEvent::WithdrawCommissionV1 {
+                            token_contract,
+                            amount,
+                        },
+                        remainder,
+                    ));
+                }
+                let (recipient, remainder) = Key::from_bytes(remainder)?;
                 Ok((
                     BridgeEvent::WithdrawCommission {
                         token_contract,
                         amount,
+                        recipient,
+                    },
+                    remainder,
+                ))
+            }
+            BRIDGE_EVENT_PAUSED => Ok((BridgeEvent::Paused, remainder)),
+            BRIDGE_EVENT_UNPAUSED => Ok((BridgeEvent::Unpaused, remainder)),
+            BRIDGE_EVENT_SIGNER_SET_UPDATED => {
+                let (signers, remainder) = Vec::<String>::from_bytes(remainder)?;
+                let (threshold, remainder) = u8::from_bytes(remainder)?;
+                Ok((
+                    BridgeEvent::SignerSetUpdated { signers, threshold },
+                    remainder,
+                ))
+            }
+            BRIDGE_EVENT_ROLE_GRANTED => {
+                let (role, remainder) = String::from_bytes(remainder)?;
+                let (account, remainder) = Key::from_bytes(remainder)?;
+                Ok((BridgeEvent::RoleGranted { role, account }, remainder))
+            }
+            BRIDGE_EVENT_ROLE_REVOKED => {
+                let (role, remainder) = String::from_bytes(remainder)?;
+                let (account, remainder) = Key::from_bytes(remainder)?;
+                Ok((BridgeEvent::RoleRevoked { role, account }, remainder))
+            }
+            BRIDGE_EVENT_LIQUIDITY_MOVED => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (amount, remainder) = U256::from_bytes(remainder)?;
+                let (from, remainder) = Key::from_bytes(remainder)?;
+                let (to, remainder) = Key::from_bytes(remainder)?;
+                Ok((
+                    BridgeEvent::LiquidityMoved {
+                        token_contract,
+                        amount,
+                        from,
+                        to,
                     },
                     remainder,
                 ))
             }
+            BRIDGE_EVENT_CHANGE_PROPOSED => {
+                let (id, remainder) = u64::from_bytes(remainder)?;
+                let (change, remainder) = Change::from_bytes(remainder)?;
+                let (eta, remainder) = u64::from_bytes(remainder)?;
+                Ok((BridgeEvent::ChangeProposed { id, change, eta }, remainder))
+            }
+            BRIDGE_EVENT_CHANGE_EXECUTED => {
+                let (id, remainder) = u64::from_bytes(remainder)?;
+                Ok((BridgeEvent::ChangeExecuted { id }, remainder))
+            }
+            BRIDGE_EVENT_CHANGE_CANCELLED => {
+                let (id, remainder) = u64::from_bytes(remainder)?;
+                Ok((BridgeEvent::ChangeCancelled { id }, remainder))
+            }
+            BRIDGE_EVENT_SIGNER_CHANGED => {
+                let (old, remainder) = String::from_bytes(remainder)?;
+                let (new, remainder) = String::from_bytes(remainder)?;
+                Ok((BridgeEvent::SignerChanged { old, new }, remainder))
+            }
+            BRIDGE_EVENT_STABLE_COMMISSION_CHANGED => {
+                let (old, remainder) = U256::from_bytes(remainder)?;
+                let (new, remainder) = U256::from_bytes(remainder)?;
+                Ok((BridgeEvent::StableCommissionChanged { old, new }, remainder))
+            }
+            BRIDGE_EVENT_TOKEN_STABLE_COMMISSION_CHANGED => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (old, remainder) = Option::<U256>::from_bytes(remainder)?;
+                let (new, remainder) = Option::<U256>::from_bytes(remainder)?;
+                Ok((
+                    BridgeEvent::TokenStableCommissionChanged {
+                        token_contract,
+                        old,
+                        new,
+                    },
+                    remainder,
+                ))
+            }
+            BRIDGE_EVENT_TOKEN_CONFIG_CHANGED => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (enabled, remainder) = bool::from_bytes(remainder)?;
+                let (mode, remainder) = u8::from_bytes(remainder)?;
+                Ok((
+                    BridgeEvent::TokenConfigChanged {
+                        token_contract,
+                        enabled,
+                        mode,
+                    },
+                    remainder,
+                ))
+            }
+            BRIDGE_EVENT_TOKEN_LIMITS_CHANGED => {
+                let (token_contract, remainder) = ContractPackageHash::from_bytes(remainder)?;
+                let (min_bridge_in, remainder) = U256::from_bytes(remainder)?;
+                let (max_bridge_in, remainder) = U256::from_bytes(remainder)?;
+                let (max_bridge_out, remainder) = U256::from_bytes(remainder)?;
+                let (outflow_cap, remainder) = U256::from_bytes(remainder)?;
+                let (outflow_window, remainder) = u64::from_bytes(remainder)?;
+                Ok((
+                    BridgeEvent::TokenLimitsChanged {
+                        token_contract,
+                        min_bridge_in,
+                        max_bridge_in,
+                        max_bridge_out,
+                        outflow_cap,
+                        outflow_window,
+                    },
+                    remainder,
+                ))
+            }
+            BRIDGE_EVENT_TIMELOCK_DELAY_CHANGED => {
+                let (old, remainder) = u64::from_bytes(remainder)?;
+                let (new, remainder) = u64::from_bytes(remainder)?;
+                Ok((BridgeEvent::TimelockDelayChanged { old, new }, remainder))
+            }
+            BRIDGE_EVENT_MAX_DEADLINE_HORIZON_CHANGED => {
+                let (old, remainder) = u64::from_bytes(remainder)?;
+                let (new, remainder) = u64::from_bytes(remainder)?;
+                Ok((
+                    BridgeEvent::MaxDeadlineHorizonChanged { old, new },
+                    remainder,
+                ))
+            }
+            BRIDGE_EVENT_TREASURY_CHANGED => {
+                let (old, remainder) = Option::<Key>::from_bytes(remainder)?;
+                let (new, remainder) = Key::from_bytes(remainder)?;
+                Ok((BridgeEvent::TreasuryChanged { old, new }, remainder))
+            }
             _ => Err(bytesrepr::Error::Formatting),
         }
     }
 }
+
+impl ContractEvent for BridgeEvent {
+    fn name(&self) -> &'static str {
+        match self {
+            BridgeEvent::FundsIn { .. } => "FundsIn",
+            BridgeEvent::FundsOut { .. } => "FundsOut",
+            BridgeEvent::TransferOut { .. } => "TransferOut",
+            BridgeEvent::WithdrawCommission { .. } => "WithdrawCommission",
+            BridgeEvent::Paused => "Paused",
+            BridgeEvent::Unpaused => "Unpaused",
+            BridgeEvent::SignerSetUpdated { .. } => "SignerSetUpdated",
+            BridgeEvent::RoleGranted { .. } => "RoleGranted",
+            BridgeEvent::RoleRevoked { .. } => "RoleRevoked",
+            BridgeEvent::LiquidityMoved { .. } => "LiquidityMoved",
+            BridgeEvent::ChangeProposed { .. } => "ChangeProposed",
+            BridgeEvent::ChangeExecuted { .. } => "ChangeExecuted",
+            BridgeEvent::ChangeCancelled { .. } => "ChangeCancelled",
+            BridgeEvent::SignerChanged { .. } => "SignerChanged",
+            BridgeEvent::StableCommissionChanged { .. } => "StableCommissionChanged",
+            BridgeEvent::TokenStableCommissionChanged { .. } => "TokenStableCommissionChanged",
+            BridgeEvent::TokenConfigChanged { .. } => "TokenConfigChanged",
+            BridgeEvent::TokenLimitsChanged { .. } => "TokenLimitsChanged",
+            BridgeEvent::TimelockDelayChanged { .. } => "TimelockDelayChanged",
+            BridgeEvent::MaxDeadlineHorizonChanged { .. } => "MaxDeadlineHorizonChanged",
+            BridgeEvent::TreasuryChanged { .. } => "TreasuryChanged",
+            BridgeEvent::TransferOutV1 { .. } => "TransferOut",
+            BridgeEvent::WithdrawCommissionV1 { .. } => "WithdrawCommission",
+        }
+    }
+
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = Vec::with_capacity(self.fields_serialized_length());
+
+        match self {
+            BridgeEvent::FundsIn {
+                token_contract,
+                destination_chain,
+                destination_address,
+                amount,
+                gas_commission,
+                stable_commission_percent,
+                nonce,
+                transaction_id,
+                sender,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(destination_chain.to_bytes()?);
+                buffer.extend(destination_address.to_bytes()?);
+                buffer.extend(amount.to_bytes()?);
+                buffer.extend(gas_commission.to_bytes()?);
+                buffer.extend(stable_commission_percent.to_bytes()?);
+                buffer.extend(nonce.to_bytes()?);
+                buffer.extend(transaction_id.to_bytes()?);
+                buffer.extend(sender.to_bytes()?);
+            }
+            BridgeEvent::FundsOut {
+                token_contract,
+                source_chain,
+                source_address,
+                amount,
+                transaction_id,
+                recipient,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(source_chain.to_bytes()?);
+                buffer.extend(source_address.to_bytes()?);
+                buffer.extend(amount.to_bytes()?);
+                buffer.extend(transaction_id.to_bytes()?);
+                buffer.extend(recipient.to_bytes()?);
+            }
+            BridgeEvent::TransferOut {
+                token_contract,
+                total_sum_for_transfer,
+                nonce,
+                recipient,
+                amount,
+                commission,
+                transaction_id,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(total_sum_for_transfer.to_bytes()?);
+                buffer.extend(nonce.to_bytes()?);
+                buffer.extend(recipient.to_bytes()?);
+                buffer.extend(amount.to_bytes()?);
+                buffer.extend(commission.to_bytes()?);
+                buffer.extend(transaction_id.to_bytes()?);
+            }
+            BridgeEvent::WithdrawCommission {
+                token_contract,
+                amount,
+                recipient,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(amount.to_bytes()?);
+                buffer.extend(recipient.to_bytes()?);
+            }
+            BridgeEvent::Paused | BridgeEvent::Unpaused => {}
+            BridgeEvent::SignerSetUpdated { signers, threshold } => {
+                buffer.extend(signers.to_bytes()?);
+                buffer.extend(threshold.to_bytes()?);
+            }
+            BridgeEvent::RoleGranted { role, account } => {
+                buffer.extend(role.to_bytes()?);
+                buffer.extend(account.to_bytes()?);
+            }
+            BridgeEvent::RoleRevoked { role, account } => {
+                buffer.extend(role.to_bytes()?);
+                buffer.extend(account.to_bytes()?);
+            }
+            BridgeEvent::LiquidityMoved {
+                token_contract,
+                amount,
+                from,
+                to,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(amount.to_bytes()?);
+                buffer.extend(from.to_bytes()?);
+                buffer.extend(to.to_bytes()?);
+            }
+            BridgeEvent::ChangeProposed { id, change, eta } => {
+                buffer.extend(id.to_bytes()?);
+                buffer.extend(change.to_bytes()?);
+                buffer.extend(eta.to_bytes()?);
+            }
+            BridgeEvent::ChangeExecuted { id } => {
+                buffer.extend(id.to_bytes()?);
+            }
+            BridgeEvent::ChangeCancelled { id } => {
+                buffer.extend(id.to_bytes()?);
+            }
+            BridgeEvent::SignerChanged { old, new } => {
+                buffer.extend(old.to_bytes()?);
+                buffer.extend(new.to_bytes()?);
+            }
+            BridgeEvent::StableCommissionChanged { old, new } => {
+                buffer.extend(old.to_bytes()?);
+                buffer.extend(new.to_bytes()?);
+            }
+            BridgeEvent::TokenStableCommissionChanged {
+                token_contract,
+                old,
+                new,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(old.to_bytes()?);
+                buffer.extend(new.to_bytes()?);
+            }
+            BridgeEvent::TokenConfigChanged {
+                token_contract,
+                enabled,
+                mode,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(enabled.to_bytes()?);
+                buffer.extend(mode.to_bytes()?);
+            }
+            BridgeEvent::TokenLimitsChanged {
+                token_contract,
+                min_bridge_in,
+                max_bridge_in,
+                max_bridge_out,
+                outflow_cap,
+                outflow_window,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(min_bridge_in.to_bytes()?);
+                buffer.extend(max_bridge_in.to_bytes()?);
+                buffer.extend(max_bridge_out.to_bytes()?);
+                buffer.extend(outflow_cap.to_bytes()?);
+                buffer.extend(outflow_window.to_bytes()?);
+            }
+            BridgeEvent::TimelockDelayChanged { old, new } => {
+                buffer.extend(old.to_bytes()?);
+                buffer.extend(new.to_bytes()?);
+            }
+            BridgeEvent::MaxDeadlineHorizonChanged { old, new } => {
+                buffer.extend(old.to_bytes()?);
+                buffer.extend(new.to_bytes()?);
+            }
+            BridgeEvent::TreasuryChanged { old, new } => {
+                buffer.extend(old.to_bytes()?);
+                buffer.extend(new.to_bytes()?);
+            }
+            BridgeEvent::TransferOutV1 {
+                token_contract,
+                total_sum_for_transfer,
+                nonce,
+                recipient,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(total_sum_for_transfer.to_bytes()?);
+                buffer.extend(nonce.to_bytes()?);
+                buffer.extend(recipient.to_bytes()?);
+            }
+            BridgeEvent::WithdrawCommissionV1 {
+                token_contract,
+                amount,
+            } => {
+                buffer.extend(token_contract.to_bytes()?);
+                buffer.extend(amount.to_bytes()?);
+            }
+        }
+
+        Ok(buffer)
+    }
+}
+
+/// Serialized layout of a bridge event variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventLayout {
+    /// Tag byte the variant is serialized with.
+    pub tag: u8,
+    pub name: &'static str,
+    /// Fields in the order they follow the tag.
+    pub fields: Vec<(&'static str, CLType)>,
+}
+
+impl EventLayout {
+    fn new(tag: u8, name: &'static str, fields: Vec<(&'static str, CLType)>) -> Self {
+        Self { tag, name, fields }
+    }
+}
+
+/// CES schemas of every bridge event variant.
+pub fn event_schemas() -> Schemas {
+    event_layouts()
+        .into_iter()
+        .fold(Schemas::new(), |schemas, layout| {
+            schemas.with(layout.name, layout.fields)
+        })
+}
+
+/// Decodes a bridge event stored in the CES `__events` dictionary.
+pub fn decode_ces_event(bytes: &[u8]) -> Result<BridgeEvent, bytesrepr::Error> {
+    let (name, fields) = String::from_bytes(bytes)?;
+    let name = name
+        .strip_prefix(CES_EVENT_PREFIX)
+        .ok_or(bytesrepr::Error::Formatting)?;
+    let layout = event_layouts()
+        .into_iter()
+        .find(|layout| layout.name == name)
+        .ok_or(bytesrepr::Error::Formatting)?;
+
+    let (event, _) = BridgeEvent::fields_from_bytes(layout.tag, fields, true)?;
+    Ok(event)
+}
+
+/// Reads the bridge event at `index` of the CES `__events` dictionary.
+///
+/// `get_item` looks up the raw bytes stored under a dictionary item key, returning `None` when the
+/// item does not exist, e.g. because no event has been emitted at `index` yet.
+pub fn read_ces_event<F>(index: u32, get_item: F) -> Result<Option<BridgeEvent>, bytesrepr::Error>
+where
+    F: FnOnce(&str) -> Option<Vec<u8>>,
+{
+    get_item(&ces_item_key(index))
+        .map(|bytes| decode_ces_event(&bytes))
+        .transpose()
+}
+
+/// Layouts of every bridge event variant, ordered by tag.
+pub fn event_layouts() -> Vec<EventLayout> {
+    vec![
+        EventLayout::new(
+            BRIDGE_EVENT_FUNDS_IN_TAG,
+            "FundsIn",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("destination_chain", String::cl_type()),
+                ("destination_address", String::cl_type()),
+                ("amount", U256::cl_type()),
+                ("gas_commission", U256::cl_type()),
+                ("stable_commission_percent", U256::cl_type()),
+                ("nonce", U128::cl_type()),
+                ("transaction_id", U256::cl_type()),
+                ("sender", Key::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_FUNDS_OUT_TAG,
+            "FundsOut",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("source_chain", String::cl_type()),
+                ("source_address", String::cl_type()),
+                ("amount", U256::cl_type()),
+                ("transaction_id", U256::cl_type()),
+                ("recipient", Key::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_TRANSFER_OUT,
+            "TransferOut",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("total_sum_for_transfer", U256::cl_type()),
+                ("nonce", U128::cl_type()),
+                ("recipient", Key::cl_type()),
+                ("amount", U256::cl_type()),
+                ("commission", U256::cl_type()),
+                ("transaction_id", U256::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_WITHDRAW_COMMISSION,
+            "WithdrawCommission",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("amount", U256::cl_type()),
+                ("recipient", Key::cl_type()),
+            ],
+        ),
+        EventLayout::new(BRIDGE_EVENT_PAUSED, "Paused", vec![]),
+        EventLayout::new(BRIDGE_EVENT_UNPAUSED, "Unpaused", vec![]),
+        EventLayout::new(
+            BRIDGE_EVENT_SIGNER_SET_UPDATED,
+            "SignerSetUpdated",
+            vec![
+                ("signers", Vec::<String>::cl_type()),
+                ("threshold", u8::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_ROLE_GRANTED,
+            "RoleGranted",
+            vec![("role", String::cl_type()), ("account", Key::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_ROLE_REVOKED,
+            "RoleRevoked",
+            vec![("role", String::cl_type()), ("account", Key::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_LIQUIDITY_MOVED,
+            "LiquidityMoved",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("amount", U256::cl_type()),
+                ("from", Key::cl_type()),
+                ("to", Key::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_CHANGE_PROPOSED,
+            "ChangeProposed",
+            vec![
+                ("id", u64::cl_type()),
+                ("change", Change::cl_type()),
+                ("eta", u64::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_CHANGE_EXECUTED,
+            "ChangeExecuted",
+            vec![("id", u64::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_CHANGE_CANCELLED,
+            "ChangeCancelled",
+            vec![("id", u64::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_SIGNER_CHANGED,
+            "SignerChanged",
+            vec![("old", String::cl_type()), ("new", String::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_STABLE_COMMISSION_CHANGED,
+            "StableCommissionChanged",
+            vec![("old", U256::cl_type()), ("new", U256::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_TOKEN_STABLE_COMMISSION_CHANGED,
+            "TokenStableCommissionChanged",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("old", Option::<U256>::cl_type()),
+                ("new", Option::<U256>::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_TOKEN_CONFIG_CHANGED,
+            "TokenConfigChanged",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("enabled", bool::cl_type()),
+                ("mode", u8::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_TOKEN_LIMITS_CHANGED,
+            "TokenLimitsChanged",
+            vec![
+                ("token_contract", ContractPackageHash::cl_type()),
+                ("min_bridge_in", U256::cl_type()),
+                ("max_bridge_in", U256::cl_type()),
+                ("max_bridge_out", U256::cl_type()),
+                ("outflow_cap", U256::cl_type()),
+                ("outflow_window", u64::cl_type()),
+            ],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_TIMELOCK_DELAY_CHANGED,
+            "TimelockDelayChanged",
+            vec![("old", u64::cl_type()), ("new", u64::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_MAX_DEADLINE_HORIZON_CHANGED,
+            "MaxDeadlineHorizonChanged",
+            vec![("old", u64::cl_type()), ("new", u64::cl_type())],
+        ),
+        EventLayout::new(
+            BRIDGE_EVENT_TREASURY_CHANGED,
+            "TreasuryChanged",
+            vec![("old", Option::<Key>::cl_type()), ("new", Key::cl_type())],
+        ),
+    ]
+}
+
+impl ToBytes for BridgeEvent {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.push(EVENT_ENVELOPE_MAGIC);
+        buffer.push(EVENT_ENCODING_VERSION);
+        buffer.push(self.tag());
+        buffer.extend(Bytes::from(self.fields_to_bytes()?).to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        EVENT_ENVELOPE_HEADER_LENGTH + self.fields_serialized_length()
+    }
+}
+
+/// Decodes an encoded event, skipping what this version doesn't know about.
+///
+/// Returns `None` for variants introduced after this version, along with the bytes following the
+/// event. Fields appended to a known variant are ignored. Events emitted before fields were
+/// appended to their variant decode into the `*V1` variants, as do events emitted before the
+/// envelope.
+pub fn decode_event(bytes: &[u8]) -> Result<(Option<BridgeEvent>, &[u8]), bytesrepr::Error> {
+    if bytes.first() != Some(&EVENT_ENVELOPE_MAGIC) {
+        let (event, remainder) = decode_legacy_event(bytes)?;
+        return Ok((Some(event), remainder));
+    }
+
+    let (envelope, remainder) = EventEnvelope::from_bytes(bytes)?;
+    let known = event_layouts()
+        .iter()
+        .any(|layout| layout.tag == envelope.tag);
+    if !known {
+        return Ok((None, remainder));
+    }
+
+    let (event, _) = BridgeEvent::fields_from_bytes(envelope.tag, &envelope.payload, true)?;
+    Ok((Some(event), remainder))
+}
+
+/// Decodes an event emitted before the envelope: a bare tag followed by the fields in their
+/// original layout.
+///
+/// Without a payload length there is nothing to skip, so only the legacy tags are accepted.
+fn decode_legacy_event(bytes: &[u8]) -> Result<(BridgeEvent, &[u8]), bytesrepr::Error> {
+    let (tag, remainder) = u8::from_bytes(bytes)?;
+    if tag > LEGACY_EVENT_MAX_TAG {
+        return Err(bytesrepr::Error::Formatting);
+    }
+    BridgeEvent::fields_from_bytes(tag, remainder, false)
+}
+
+impl FromBytes for BridgeEvent {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        match decode_event(bytes)? {
+            (Some(event), remainder) => Ok((event, remainder)),
+            (None, _) => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
@@ -2,4 +2,5 @@
 
 extern crate alloc;
 
+pub mod change;
 pub mod event;
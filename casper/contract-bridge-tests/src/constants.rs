@@ -8,7 +8,7 @@ pub(crate) fn TEST_GAS_COMMISSION() -> U256 {
     U256::one() * 1000
 }
 pub(crate) fn TEST_STABLE_COMMISSION_PERCENT() -> U256 {
-    U256::one() * 3
+    U256::one() * 400
 }
 pub(crate) fn TEST_NONCE() -> U128 {
     U128::one() * 555
@@ -17,7 +17,7 @@ pub(crate) fn TEST_TRANSACTION_ID() -> U256 {
     U256::one() * 111
 }
 pub(crate) fn TEST_COMMISSION_PERCENT() -> U256 {
-    U256::one() * 34
+    U256::one() * 25
 }
 pub(crate) fn TEST_CORRECT_DEADLINE() -> U256 {
     U256::one() * 1672943628
@@ -25,6 +25,7 @@ pub(crate) fn TEST_CORRECT_DEADLINE() -> U256 {
 pub(crate) fn TEST_EXPIRED_DEADLINE() -> U256 {
     U256::one() * 1672051121
 }
+pub(crate) const TEST_CHAIN_NAME: &str = "casper-test";
 pub(crate) fn TEST_DESTINATION_CHAIN() -> String {
     "DEST".to_string()
 }
@@ -35,6 +36,7 @@ pub(crate) fn TEST_DESTINATION_ADDRESS() -> String {
 pub(crate) const TEST_ACCOUNT_BALANCE: u64 = 10_000_000_000_000u64;
 
 pub(crate) const ERC20_INSUFFIENT_BALANCE_ERROR_CODE: u16 = u16::MAX - 1;
+pub(crate) const ERC20_INSUFFICIENT_ALLOWANCE_ERROR_CODE: u16 = u16::MAX - 2;
 pub(crate) const TEST_ACCOUNT: [u8; 32] = [255u8; 32];
 
 pub(crate) const TEST_PREFIX_BRIDGE_IN: &str = "TRICORN_BRIDGE_IN";
@@ -0,0 +1,442 @@
+//! Locks the encoding of every `BridgeEvent` variant.
+//!
+//! A failing golden test means deployed decoders would read events differently. Only fields
+//! appended to the end of a variant may change its golden bytes; anything else needs a new variant
+//! or a bump of `EVENT_ENCODING_VERSION`, with the previous layout still decodable and locked.
+//! Events emitted before the envelope are locked by the legacy tests, which only decode.
+use casper_common::{
+    change::Change,
+    event::{decode_ces_event, decode_event, BridgeEvent, EventEnvelope, EVENT_ENCODING_VERSION},
+};
+use casper_types::{
+    account::AccountHash,
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
+    ContractPackageHash, Key, U128, U256,
+};
+use contract_util::event::to_ces_bytes;
+
+fn token_contract() -> ContractPackageHash {
+    ContractPackageHash::new([1u8; 32])
+}
+
+fn account() -> Key {
+    Key::Account(AccountHash::new([2u8; 32]))
+}
+
+fn contract() -> Key {
+    Key::Hash([3u8; 32])
+}
+
+/// Asserts that `event` encodes to `golden` and decodes back to itself.
+fn assert_event_format(event: BridgeEvent, golden: &str) {
+    let bytes = event.to_bytes().unwrap();
+    let hex: String = bytes.iter().map(|byte| format!("{:02x}", byte)).collect();
+    assert_eq!(hex, golden);
+    assert_eq!(event.serialized_length(), bytes.len());
+
+    let decoded: BridgeEvent = bytesrepr::deserialize(bytes).unwrap();
+    assert_eq!(decoded, event);
+}
+
+/// Asserts that `golden`, encoded before the envelope, still decodes into `event`.
+fn assert_legacy_event_format(event: BridgeEvent, golden: &str) {
+    let bytes: Vec<u8> = (0..golden.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&golden[index..index + 2], 16).unwrap())
+        .collect();
+
+    let (decoded, remainder) = decode_event(&bytes).unwrap();
+    assert_eq!(decoded, Some(event));
+    assert!(remainder.is_empty());
+}
+
+#[test]
+fn funds_in_format_lock() {
+    assert_event_format(
+        BridgeEvent::FundsIn {
+            token_contract: token_contract(),
+            destination_chain: "DEST".into(),
+            destination_address: "DESTADDR".into(),
+            amount: U256::from(1_000_000),
+            gas_commission: U256::from(1000),
+            stable_commission_percent: U256::from(400),
+            nonce: U128::from(555),
+            transaction_id: U256::from(111),
+            sender: account(),
+        },
+        "ee010064000000010101010101010101010101010101010101010101010101010101010101010104000000444553540800000044455354414444520340420f02e803029001022b02016f000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn funds_out_format_lock() {
+    assert_event_format(
+        BridgeEvent::FundsOut {
+            token_contract: token_contract(),
+            source_chain: "SRC".into(),
+            source_address: "SRCADDR".into(),
+            amount: U256::from(1_000_000),
+            transaction_id: U256::from(111),
+            recipient: contract(),
+        },
+        "ee01015900000001010101010101010101010101010101010101010101010101010101010101010300000053524307000000535243414444520340420f016f010303030303030303030303030303030303030303030303030303030303030303",
+    );
+}
+
+#[test]
+fn transfer_out_format_lock() {
+    assert_event_format(
+        BridgeEvent::TransferOut {
+            token_contract: token_contract(),
+            total_sum_for_transfer: U256::from(999_000),
+            nonce: U128::from(555),
+            recipient: account(),
+            amount: U256::from(998_000),
+            commission: U256::from(1000),
+            transaction_id: U256::from(111),
+        },
+        "ee010251000000010101010101010101010101010101010101010101010101010101010101010103583e0f022b0200020202020202020202020202020202020202020202020202020202020202020203703a0f02e803016f",
+    );
+}
+
+#[test]
+fn withdraw_commission_format_lock() {
+    assert_event_format(
+        BridgeEvent::WithdrawCommission {
+            token_contract: token_contract(),
+            amount: U256::from(1000),
+            recipient: account(),
+        },
+        "ee010344000000010101010101010101010101010101010101010101010101010101010101010102e803000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn paused_format_lock() {
+    assert_event_format(BridgeEvent::Paused, "ee010400000000");
+}
+
+#[test]
+fn unpaused_format_lock() {
+    assert_event_format(BridgeEvent::Unpaused, "ee010500000000");
+}
+
+#[test]
+fn signer_set_updated_format_lock() {
+    assert_event_format(
+        BridgeEvent::SignerSetUpdated {
+            signers: vec!["signer-1".into(), "signer-2".into()],
+            threshold: 2,
+        },
+        "ee01061d00000002000000080000007369676e65722d31080000007369676e65722d3202",
+    );
+}
+
+#[test]
+fn role_granted_format_lock() {
+    assert_event_format(
+        BridgeEvent::RoleGranted {
+            role: "admin".into(),
+            account: account(),
+        },
+        "ee01072a0000000500000061646d696e000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn role_revoked_format_lock() {
+    assert_event_format(
+        BridgeEvent::RoleRevoked {
+            role: "admin".into(),
+            account: account(),
+        },
+        "ee01082a0000000500000061646d696e000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn liquidity_moved_format_lock() {
+    assert_event_format(
+        BridgeEvent::LiquidityMoved {
+            token_contract: token_contract(),
+            amount: U256::from(1_000_000),
+            from: contract(),
+            to: account(),
+        },
+        "ee01096600000001010101010101010101010101010101010101010101010101010101010101010340420f010303030303030303030303030303030303030303030303030303030303030303000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn change_proposed_format_lock() {
+    assert_event_format(
+        BridgeEvent::ChangeProposed {
+            id: 7,
+            change: Change::SetTimelockDelay { delay: 3600 },
+            eta: 1672071121,
+        },
+        "ee010a19000000070000000000000005100e000000000000d1c7a96300000000",
+    );
+}
+
+#[test]
+fn change_executed_format_lock() {
+    assert_event_format(
+        BridgeEvent::ChangeExecuted { id: 7 },
+        "ee010b080000000700000000000000",
+    );
+}
+
+#[test]
+fn change_cancelled_format_lock() {
+    assert_event_format(
+        BridgeEvent::ChangeCancelled { id: 8 },
+        "ee010c080000000800000000000000",
+    );
+}
+
+#[test]
+fn signer_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::SignerChanged {
+            old: "old-signer".into(),
+            new: "new-signer".into(),
+        },
+        "ee010d1c0000000a0000006f6c642d7369676e65720a0000006e65772d7369676e6572",
+    );
+}
+
+#[test]
+fn stable_commission_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::StableCommissionChanged {
+            old: U256::from(400),
+            new: U256::from(25),
+        },
+        "ee010e050000000290010119",
+    );
+}
+
+#[test]
+fn token_stable_commission_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::TokenStableCommissionChanged {
+            token_contract: token_contract(),
+            old: None,
+            new: Some(U256::from(250)),
+        },
+        "ee010f240000000101010101010101010101010101010101010101010101010101010101010101000101fa",
+    );
+}
+
+#[test]
+fn token_config_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::TokenConfigChanged {
+            token_contract: token_contract(),
+            enabled: true,
+            mode: 1,
+        },
+        "ee01102200000001010101010101010101010101010101010101010101010101010101010101010101",
+    );
+}
+
+#[test]
+fn token_limits_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::TokenLimitsChanged {
+            token_contract: token_contract(),
+            min_bridge_in: U256::from(10),
+            max_bridge_in: U256::from(1_000_000),
+            max_bridge_out: U256::from(500_000),
+            outflow_cap: U256::from(2_000_000),
+            outflow_window: 86_400_000,
+        },
+        "ee0111360000000101010101010101010101010101010101010101010101010101010101010101010a0340420f0320a1070380841e005c260500000000",
+    );
+}
+
+#[test]
+fn timelock_delay_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::TimelockDelayChanged {
+            old: 0,
+            new: 172_800_000,
+        },
+        "ee011210000000000000000000000000b84c0a00000000",
+    );
+}
+
+#[test]
+fn max_deadline_horizon_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::MaxDeadlineHorizonChanged {
+            old: 2_592_000_000,
+            new: 604_800_000,
+        },
+        "ee01131000000000c87e9a0000000000840c2400000000",
+    );
+}
+
+#[test]
+fn treasury_changed_format_lock() {
+    assert_event_format(
+        BridgeEvent::TreasuryChanged {
+            old: None,
+            new: contract(),
+        },
+        "ee01142200000000010303030303030303030303030303030303030303030303030303030303030303",
+    );
+}
+
+#[test]
+fn transfer_out_v1_format_lock() {
+    assert_event_format(
+        BridgeEvent::TransferOutV1 {
+            token_contract: token_contract(),
+            total_sum_for_transfer: U256::from(999_000),
+            nonce: U128::from(555),
+            recipient: account(),
+        },
+        "ee010248000000010101010101010101010101010101010101010101010101010101010101010103583e0f022b02000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn withdraw_commission_v1_format_lock() {
+    assert_event_format(
+        BridgeEvent::WithdrawCommissionV1 {
+            token_contract: token_contract(),
+            amount: U256::from(1000),
+        },
+        "ee010323000000010101010101010101010101010101010101010101010101010101010101010102e803",
+    );
+}
+
+#[test]
+fn ces_decoder_reads_events_without_appended_fields() {
+    let events = [
+        BridgeEvent::TransferOutV1 {
+            token_contract: token_contract(),
+            total_sum_for_transfer: U256::from(999_000),
+            nonce: U128::from(555),
+            recipient: account(),
+        },
+        BridgeEvent::WithdrawCommissionV1 {
+            token_contract: token_contract(),
+            amount: U256::from(1000),
+        },
+    ];
+
+    for event in events {
+        let bytes = to_ces_bytes(&event).unwrap();
+        assert_eq!(decode_ces_event(&bytes).unwrap(), event);
+    }
+}
+
+#[test]
+fn funds_in_legacy_format_lock() {
+    assert_legacy_event_format(
+        BridgeEvent::FundsIn {
+            token_contract: token_contract(),
+            destination_chain: "DEST".into(),
+            destination_address: "DESTADDR".into(),
+            amount: U256::from(1_000_000),
+            gas_commission: U256::from(1000),
+            stable_commission_percent: U256::from(400),
+            nonce: U128::from(555),
+            transaction_id: U256::from(111),
+            sender: account(),
+        },
+        "00010101010101010101010101010101010101010101010101010101010101010104000000444553540800000044455354414444520340420f02e803029001022b02016f000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn funds_out_legacy_format_lock() {
+    assert_legacy_event_format(
+        BridgeEvent::FundsOut {
+            token_contract: token_contract(),
+            source_chain: "SRC".into(),
+            source_address: "SRCADDR".into(),
+            amount: U256::from(1_000_000),
+            transaction_id: U256::from(111),
+            recipient: contract(),
+        },
+        "0101010101010101010101010101010101010101010101010101010101010101010300000053524307000000535243414444520340420f016f010303030303030303030303030303030303030303030303030303030303030303",
+    );
+}
+
+#[test]
+fn transfer_out_legacy_format_lock() {
+    assert_legacy_event_format(
+        BridgeEvent::TransferOutV1 {
+            token_contract: token_contract(),
+            total_sum_for_transfer: U256::from(999_000),
+            nonce: U128::from(555),
+            recipient: account(),
+        },
+        "02010101010101010101010101010101010101010101010101010101010101010103583e0f022b02000202020202020202020202020202020202020202020202020202020202020202",
+    );
+}
+
+#[test]
+fn withdraw_commission_legacy_format_lock() {
+    assert_legacy_event_format(
+        BridgeEvent::WithdrawCommissionV1 {
+            token_contract: token_contract(),
+            amount: U256::from(1000),
+        },
+        "03010101010101010101010101010101010101010101010101010101010101010102e803",
+    );
+}
+
+#[test]
+fn decoder_rejects_unknown_legacy_tags() {
+    let mut bytes = vec![BridgeEvent::Paused.tag()];
+    bytes.extend(token_contract().to_bytes().unwrap());
+
+    assert!(decode_event(&bytes).is_err());
+}
+
+#[test]
+fn decoder_skips_unknown_variants() {
+    let unknown = EventEnvelope {
+        version: EVENT_ENCODING_VERSION,
+        tag: u8::MAX,
+        payload: Bytes::from(vec![1u8, 2, 3]),
+    };
+    let mut bytes = unknown.to_bytes().unwrap();
+    bytes.extend(BridgeEvent::Paused.to_bytes().unwrap());
+
+    let (event, remainder) = decode_event(&bytes).unwrap();
+    assert_eq!(event, None);
+    let (event, remainder) = decode_event(remainder).unwrap();
+    assert_eq!(event, Some(BridgeEvent::Paused));
+    assert!(remainder.is_empty());
+
+    assert!(BridgeEvent::from_bytes(&bytes).is_err());
+}
+
+#[test]
+fn decoder_ignores_appended_fields() {
+    let event = BridgeEvent::ChangeExecuted { id: 7 };
+    let mut payload = 7u64.to_bytes().unwrap();
+    payload.extend(U256::from(42).to_bytes().unwrap());
+    let extended = EventEnvelope {
+        version: EVENT_ENCODING_VERSION,
+        tag: event.tag(),
+        payload: Bytes::from(payload),
+    };
+
+    let decoded: BridgeEvent = bytesrepr::deserialize(extended.to_bytes().unwrap()).unwrap();
+    assert_eq!(decoded, event);
+}
+
+#[test]
+fn decoder_rejects_unknown_versions() {
+    let mut bytes = BridgeEvent::Paused.to_bytes().unwrap();
+    bytes[1] = EVENT_ENCODING_VERSION + 1;
+
+    assert!(decode_event(&bytes).is_err());
+}
@@ -1,44 +1,109 @@
 pub mod constants;
 pub mod utils;
 
+#[cfg(test)]
+mod event_format;
+
 #[cfg(test)]
 mod tests {
     use crate::constants::{
-        ERC20_INSUFFIENT_BALANCE_ERROR_CODE, TEST_ACCOUNT_BALANCE, TEST_AMOUNT, TEST_BLOCK_TIME,
+        ERC20_INSUFFICIENT_ALLOWANCE_ERROR_CODE, ERC20_INSUFFIENT_BALANCE_ERROR_CODE,
+        TEST_ACCOUNT_BALANCE, TEST_AMOUNT, TEST_BLOCK_TIME, TEST_CHAIN_NAME,
         TEST_COMMISSION_PERCENT, TEST_CORRECT_DEADLINE, TEST_DESTINATION_ADDRESS,
         TEST_DESTINATION_CHAIN, TEST_EXPIRED_DEADLINE, TEST_GAS_COMMISSION, TEST_NONCE,
         TEST_STABLE_COMMISSION_PERCENT, TEST_TRANSACTION_ID,
     };
     use crate::utils::{
-        arbitrary_user, arbitrary_user_key, bridge_in, bridge_out, deploy_bridge,
-        deploy_bridge_and_erc20, deploy_erc20, execution_context, execution_error,
-        fill_purse_on_token_contract, get_context, query_balance, query_commission_pool,
-        read_contract_event, set_test_signer, setup_context, simple_deploy_builder,
-        test_public_key, transfer_out, withdraw_commission, UserAccount,
+        add_token, approve, arbitrary_user, arbitrary_user_key, bridge_in, bridge_in_from,
+        bridge_in_native, bridge_in_signed_by, bridge_in_signed_by_ed25519,
+        bridge_in_with_signature_mode, bridge_out, bridge_out_for_transfer, bridge_out_native,
+        cancel_change, claim_role, clear_token_stable_commission_percent, deploy_bridge,
+        deploy_bridge_and_erc20, deploy_bridge_with_event_mode, deploy_bridge_with_signature_mode,
+        deploy_bridge_with_timelock_delay, deploy_erc20, deposit_liquidity, execute_change,
+        execution_context, execution_context_at, execution_error, fill_purse_on_token_contract,
+        get_context, grant_role, pause, propose_change, query_balance, query_commission_pool,
+        query_entry_point, query_main_purse_balance, query_native_commission_pool,
+        query_native_purse_balance, query_token_config, query_token_limits, read_ces_event,
+        read_contract_event, rebalance_liquidity, remove_token, revoke_role, set_bridge_in_limits,
+        set_bridge_out_limits, set_max_deadline_horizon, set_signer_set, set_test_signer,
+        set_timelock_delay, set_token_mode, set_token_stable_commission_percent, set_treasury,
+        setup_context, signer_hex, simple_deploy_builder, stored_signers,
+        test_ed25519_signer_public_key, test_ed25519_signer_secret_key, test_public_key,
+        test_public_keys, test_signer_address, test_signer_compressed_key, test_signer_secret_keys,
+        transfer_out, unpause, upgrade_bridge, withdraw_commission, withdraw_native_commission,
+        TestContext, UserAccount,
     };
     use casper_engine_test_support::ExecuteRequestBuilder;
     use casper_execution_engine::core::{engine_state, execution};
     use casper_types::bytesrepr::Bytes;
-    use casper_types::{runtime_args, RuntimeArgs, U128, U256};
-    use casper_types::{ApiError, Key};
-    use contract_bridge::entry_points::{EP_CHECK_PARAMS, PARAM_BYTES, PARAM_SIGNATURE, PARAM_TRANSACTION_ID};
-    use contract_util::signatures::cook_msg_transfer_out;
+    use casper_types::{
+        runtime_args, ContractHash, ContractPackageHash, Parameter, RuntimeArgs, U128, U256, U512,
+    };
+    use casper_types::{ApiError, Group, Key};
+    use contract_bridge::entry_points::{
+        EP_CHECK_PARAMS, PARAM_BYTES, PARAM_SIGNATURES, PARAM_TRANSACTION_ID,
+    };
+    use contract_util::signatures::{cook_msg_transfer_out, parse_signer, SignatureMode};
     use contract_util::{error::Error::Contract as ContractError, signatures::cook_msg_bridge_in};
 
-    use casper_common::event::BridgeEvent;
+    use casper_common::{
+        change::Change,
+        event::{event_schemas, BridgeEvent, NATIVE_TOKEN_CONTRACT},
+    };
     use contract_bridge::{
+        abi::{abi, EntryPointAbi},
+        args::{
+            AddTokenArgs, BridgeInArgs, BridgeInConfirmArgs, BridgeInFromArgs, BridgeInNativeArgs,
+            BridgeOutArgs, BridgeOutNativeArgs, CancelChangeArgs, CheckParamsArgs, ClaimRoleArgs,
+            ClearTokenStableCommissionPercentArgs, DepositLiquidityArgs, ExecuteChangeArgs,
+            GetAvailableLiquidityArgs, GetCommissionPoolArgs, GetOutflowVolumeArgs,
+            GetProposalArgs, GetTokenConfigArgs, GetTokenLimitsArgs,
+            GetTokenStableCommissionPercentArgs, GetTotalCommissionArgs, GetWrappedSupplyArgs,
+            GrantRoleArgs, HasRoleArgs, IsNonceUsedArgs, IsTransferProcessedArgs, MigrateArgs,
+            ProposeChangeArgs, RebalanceLiquidityArgs, RemoveTokenArgs, RevokeRoleArgs,
+            SetBridgeInLimitsArgs, SetBridgeOutLimitsArgs, SetMaxDeadlineHorizonArgs,
+            SetSignerArgs, SetSignerSetArgs, SetStableCommissionPercentArgs, SetTimelockDelayArgs,
+            SetTokenModeArgs, SetTokenStableCommissionPercentArgs, TakeRoleAccessArgs,
+            TransferOutArgs, WithdrawCommissionArgs, WithdrawNativeCommissionArgs,
+        },
+        constants::{
+            DEFAULT_MAX_DEADLINE_HORIZON, DEFAULT_STABLE_COMMISSION_BPS, DEFAULT_TIMELOCK_DELAY,
+            GROUP_OPERATOR, HUNDRED_PERCENT_BPS, MAX_STABLE_COMMISSION_BPS, ROLES, ROLE_ADMIN,
+            ROLE_RELAYER,
+        },
+        contract::role_access_key_name,
         entry_points::{
-            EP_BRIDGE_IN, EP_BRIDGE_IN_CONFIRM, EP_BRIDGE_OUT, EP_GET_SIGNER,
-            EP_GET_STABLE_COMMISSION_PERCENT, EP_SET_SIGNER, EP_SET_STABLE_COMMISSION_PERCENT,
-            EP_TRANSFER_OUT, EP_WITHDRAW_COMMISSION, PARAM_AMOUNT, PARAM_DESTINATION_ADDRESS,
-            PARAM_DESTINATION_CHAIN, PARAM_GAS_COMMISSION, PARAM_NONCE, PARAM_RECIPIENT,
-            PARAM_SENDER, PARAM_SIGNER, PARAM_STABLE_COMMISSION_PERCENT, PARAM_TOKEN_CONTRACT,
+            EP_ADD_TOKEN, EP_BRIDGE_IN, EP_BRIDGE_IN_CONFIRM, EP_BRIDGE_IN_FROM,
+            EP_BRIDGE_IN_NATIVE, EP_BRIDGE_OUT, EP_BRIDGE_OUT_NATIVE, EP_CANCEL_CHANGE,
+            EP_CLAIM_ROLE, EP_CLEAR_TOKEN_STABLE_COMMISSION_PERCENT, EP_DEPOSIT_LIQUIDITY,
+            EP_EXECUTE_CHANGE, EP_GET_AVAILABLE_LIQUIDITY, EP_GET_COMMISSION_POOL,
+            EP_GET_MAX_DEADLINE_HORIZON, EP_GET_NATIVE_COMMISSION, EP_GET_OUTFLOW_VOLUME,
+            EP_GET_PROPOSAL, EP_GET_SIGNER, EP_GET_SIGNER_SET, EP_GET_STABLE_COMMISSION_PERCENT,
+            EP_GET_TIMELOCK_DELAY, EP_GET_TOKEN_CONFIG, EP_GET_TOKEN_LIMITS,
+            EP_GET_TOKEN_STABLE_COMMISSION_PERCENT, EP_GET_TOTAL_COMMISSION, EP_GET_WRAPPED_SUPPLY,
+            EP_GRANT_ROLE, EP_HAS_ROLE, EP_IS_NONCE_USED, EP_IS_TRANSFER_PROCESSED, EP_MIGRATE,
+            EP_MIGRATE_STABLE_COMMISSION_TO_BPS, EP_PAUSE, EP_PROPOSE_CHANGE,
+            EP_REBALANCE_LIQUIDITY, EP_REMOVE_TOKEN, EP_REVOKE_ROLE, EP_SET_BRIDGE_IN_LIMITS,
+            EP_SET_BRIDGE_OUT_LIMITS, EP_SET_MAX_DEADLINE_HORIZON, EP_SET_SIGNER,
+            EP_SET_SIGNER_SET, EP_SET_STABLE_COMMISSION_PERCENT, EP_SET_TIMELOCK_DELAY,
+            EP_SET_TOKEN_MODE, EP_SET_TOKEN_STABLE_COMMISSION_PERCENT, EP_TAKE_ROLE_ACCESS,
+            EP_TRANSFER_OUT, EP_UNPAUSE, EP_WITHDRAW_COMMISSION, EP_WITHDRAW_NATIVE_COMMISSION,
+            PARAM_AMOUNT, PARAM_DESTINATION_ADDRESS, PARAM_DESTINATION_CHAIN, PARAM_GAS_COMMISSION,
+            PARAM_NONCE, PARAM_RECIPIENT, PARAM_SENDER, PARAM_SIGNER, PARAM_SOURCE_CHAIN,
+            PARAM_STABLE_COMMISSION_PERCENT, PARAM_TOKEN_CONTRACT,
         },
         error::BridgeError,
+        signers::SignerSet,
+        token_registry::TokenMode,
+    };
+    use contract_util::event::{
+        EventMode, Schemas, CES_VERSION, EVENTS_CES_VERSION_UREF_NAME, EVENTS_LENGTH_UREF_NAME,
+        EVENTS_SCHEMA_UREF_NAME,
     };
 
     fn expected_total_commission() -> U256 {
-        TEST_AMOUNT() * TEST_STABLE_COMMISSION_PERCENT() / 100 + TEST_GAS_COMMISSION()
+        TEST_AMOUNT() * TEST_STABLE_COMMISSION_PERCENT() / HUNDRED_PERCENT_BPS
+            + TEST_GAS_COMMISSION()
     }
 
     #[test]
@@ -71,6 +136,49 @@ mod tests {
             EP_GET_STABLE_COMMISSION_PERCENT,
             EP_SET_SIGNER,
             EP_GET_SIGNER,
+            EP_SET_SIGNER_SET,
+            EP_GET_SIGNER_SET,
+            EP_PAUSE,
+            EP_UNPAUSE,
+            EP_ADD_TOKEN,
+            EP_REMOVE_TOKEN,
+            EP_GET_TOKEN_CONFIG,
+            EP_SET_BRIDGE_IN_LIMITS,
+            EP_SET_BRIDGE_OUT_LIMITS,
+            EP_GET_TOKEN_LIMITS,
+            EP_GET_OUTFLOW_VOLUME,
+            EP_MIGRATE_STABLE_COMMISSION_TO_BPS,
+            EP_SET_TOKEN_STABLE_COMMISSION_PERCENT,
+            EP_CLEAR_TOKEN_STABLE_COMMISSION_PERCENT,
+            EP_GET_TOKEN_STABLE_COMMISSION_PERCENT,
+            EP_GRANT_ROLE,
+            EP_REVOKE_ROLE,
+            EP_CLAIM_ROLE,
+            EP_TAKE_ROLE_ACCESS,
+            EP_HAS_ROLE,
+            EP_MIGRATE,
+            EP_IS_NONCE_USED,
+            EP_IS_TRANSFER_PROCESSED,
+            EP_BRIDGE_IN_NATIVE,
+            EP_BRIDGE_OUT_NATIVE,
+            EP_WITHDRAW_NATIVE_COMMISSION,
+            EP_GET_NATIVE_COMMISSION,
+            EP_SET_TOKEN_MODE,
+            EP_GET_WRAPPED_SUPPLY,
+            EP_BRIDGE_IN_FROM,
+            EP_REBALANCE_LIQUIDITY,
+            EP_DEPOSIT_LIQUIDITY,
+            EP_PROPOSE_CHANGE,
+            EP_EXECUTE_CHANGE,
+            EP_CANCEL_CHANGE,
+            EP_GET_PROPOSAL,
+            EP_SET_TIMELOCK_DELAY,
+            EP_GET_TIMELOCK_DELAY,
+            EP_SET_MAX_DEADLINE_HORIZON,
+            EP_GET_MAX_DEADLINE_HORIZON,
+            EP_GET_COMMISSION_POOL,
+            EP_GET_AVAILABLE_LIQUIDITY,
+            EP_GET_TOTAL_COMMISSION,
         ];
 
         let mut count = 0;
@@ -81,6 +189,217 @@ mod tests {
         assert_eq!(count, expected_entries.len());
     }
 
+    #[test]
+    fn verify_entry_point_parameters() {
+        let mut context = setup_context();
+
+        let (bridge_address, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let contract = context.builder.get_contract(bridge_address).unwrap();
+
+        /* Scenario:
+           1. Every installed entry point declares each parameter name once
+           2. Every parameterised entry point is installed with the parameters of its args struct
+           3. Args built from the structs carry exactly the declared names and types
+        */
+        for entry_point in contract.entry_points().values() {
+            let mut names: Vec<&str> = entry_point
+                .args()
+                .iter()
+                .map(|parameter| parameter.name())
+                .collect();
+            names.sort_unstable();
+            names.dedup();
+            assert_eq!(
+                names.len(),
+                entry_point.args().len(),
+                "duplicate parameter in {}",
+                entry_point.name()
+            );
+        }
+
+        let declared = vec![
+            (EP_BRIDGE_IN, BridgeInArgs::parameters()),
+            (EP_BRIDGE_IN_CONFIRM, BridgeInConfirmArgs::parameters()),
+            (EP_CHECK_PARAMS, CheckParamsArgs::parameters()),
+            (EP_BRIDGE_OUT, BridgeOutArgs::parameters()),
+            (EP_TRANSFER_OUT, TransferOutArgs::parameters()),
+            (EP_WITHDRAW_COMMISSION, WithdrawCommissionArgs::parameters()),
+            (
+                EP_SET_STABLE_COMMISSION_PERCENT,
+                SetStableCommissionPercentArgs::parameters(),
+            ),
+            (
+                EP_SET_TOKEN_STABLE_COMMISSION_PERCENT,
+                SetTokenStableCommissionPercentArgs::parameters(),
+            ),
+            (
+                EP_CLEAR_TOKEN_STABLE_COMMISSION_PERCENT,
+                ClearTokenStableCommissionPercentArgs::parameters(),
+            ),
+            (
+                EP_GET_TOKEN_STABLE_COMMISSION_PERCENT,
+                GetTokenStableCommissionPercentArgs::parameters(),
+            ),
+            (EP_SET_SIGNER, SetSignerArgs::parameters()),
+            (EP_SET_SIGNER_SET, SetSignerSetArgs::parameters()),
+            (EP_ADD_TOKEN, AddTokenArgs::parameters()),
+            (EP_REMOVE_TOKEN, RemoveTokenArgs::parameters()),
+            (EP_GET_TOKEN_CONFIG, GetTokenConfigArgs::parameters()),
+            (EP_SET_BRIDGE_IN_LIMITS, SetBridgeInLimitsArgs::parameters()),
+            (
+                EP_SET_BRIDGE_OUT_LIMITS,
+                SetBridgeOutLimitsArgs::parameters(),
+            ),
+            (EP_GET_TOKEN_LIMITS, GetTokenLimitsArgs::parameters()),
+            (EP_GET_OUTFLOW_VOLUME, GetOutflowVolumeArgs::parameters()),
+            (EP_GRANT_ROLE, GrantRoleArgs::parameters()),
+            (EP_REVOKE_ROLE, RevokeRoleArgs::parameters()),
+            (EP_CLAIM_ROLE, ClaimRoleArgs::parameters()),
+            (EP_TAKE_ROLE_ACCESS, TakeRoleAccessArgs::parameters()),
+            (EP_HAS_ROLE, HasRoleArgs::parameters()),
+            (EP_MIGRATE, MigrateArgs::parameters()),
+            (EP_IS_NONCE_USED, IsNonceUsedArgs::parameters()),
+            (
+                EP_IS_TRANSFER_PROCESSED,
+                IsTransferProcessedArgs::parameters(),
+            ),
+            (EP_BRIDGE_IN_NATIVE, BridgeInNativeArgs::parameters()),
+            (EP_BRIDGE_OUT_NATIVE, BridgeOutNativeArgs::parameters()),
+            (
+                EP_WITHDRAW_NATIVE_COMMISSION,
+                WithdrawNativeCommissionArgs::parameters(),
+            ),
+            (EP_SET_TOKEN_MODE, SetTokenModeArgs::parameters()),
+            (EP_GET_WRAPPED_SUPPLY, GetWrappedSupplyArgs::parameters()),
+            (EP_BRIDGE_IN_FROM, BridgeInFromArgs::parameters()),
+            (EP_REBALANCE_LIQUIDITY, RebalanceLiquidityArgs::parameters()),
+            (EP_DEPOSIT_LIQUIDITY, DepositLiquidityArgs::parameters()),
+            (EP_PROPOSE_CHANGE, ProposeChangeArgs::parameters()),
+            (EP_EXECUTE_CHANGE, ExecuteChangeArgs::parameters()),
+            (EP_CANCEL_CHANGE, CancelChangeArgs::parameters()),
+            (EP_GET_PROPOSAL, GetProposalArgs::parameters()),
+            (EP_SET_TIMELOCK_DELAY, SetTimelockDelayArgs::parameters()),
+            (
+                EP_SET_MAX_DEADLINE_HORIZON,
+                SetMaxDeadlineHorizonArgs::parameters(),
+            ),
+            (EP_GET_COMMISSION_POOL, GetCommissionPoolArgs::parameters()),
+            (
+                EP_GET_AVAILABLE_LIQUIDITY,
+                GetAvailableLiquidityArgs::parameters(),
+            ),
+            (
+                EP_GET_TOTAL_COMMISSION,
+                GetTotalCommissionArgs::parameters(),
+            ),
+        ];
+        for (name, parameters) in &declared {
+            let entry_point = contract.entry_points().get(*name).unwrap();
+            assert_eq!(entry_point.args(), parameters.as_slice(), "{}", name);
+        }
+
+        let token_contract = ContractPackageHash::new([1u8; 32]);
+        let sender = arbitrary_user_key(&mut context);
+        let encoded = vec![
+            (
+                BridgeInConfirmArgs::parameters(),
+                BridgeInConfirmArgs {
+                    token_contract,
+                    amount: TEST_AMOUNT(),
+                    gas_commission: TEST_GAS_COMMISSION(),
+                    nonce: TEST_NONCE(),
+                    transaction_id: TEST_TRANSACTION_ID(),
+                    destination_chain: TEST_DESTINATION_CHAIN.into(),
+                    destination_address: TEST_DESTINATION_ADDRESS.into(),
+                    sender,
+                }
+                .into_runtime_args()
+                .unwrap(),
+            ),
+            (
+                CheckParamsArgs::parameters(),
+                CheckParamsArgs {
+                    bytes: Bytes::from(vec![1u8, 2, 3]),
+                    signatures: vec![Bytes::from(vec![0u8; 64])],
+                    nonce: TEST_NONCE(),
+                }
+                .into_runtime_args()
+                .unwrap(),
+            ),
+            (
+                BridgeOutArgs::parameters(),
+                BridgeOutArgs {
+                    token_contract,
+                    amount: TEST_AMOUNT(),
+                    transaction_id: TEST_TRANSACTION_ID(),
+                    source_chain: TEST_DESTINATION_CHAIN.into(),
+                    source_address: TEST_DESTINATION_ADDRESS.into(),
+                    recipient: sender,
+                }
+                .into_runtime_args()
+                .unwrap(),
+            ),
+            (
+                WithdrawCommissionArgs::parameters(),
+                WithdrawCommissionArgs {
+                    token_contract,
+                    amount: TEST_AMOUNT(),
+                    recipient: sender,
+                }
+                .into_runtime_args()
+                .unwrap(),
+            ),
+        ];
+        for (parameters, args) in encoded {
+            let decoded: Vec<Parameter> = args
+                .named_args()
+                .map(|arg| Parameter::new(arg.name(), arg.cl_value().cl_type().clone()))
+                .collect();
+            assert_eq!(decoded, parameters);
+        }
+    }
+
+    #[test]
+    fn verify_abi_matches_entry_points() {
+        let mut context = setup_context();
+
+        let (bridge_address, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let contract = context.builder.get_contract(bridge_address).unwrap();
+        let abi = abi();
+
+        /* Scenario:
+           1. The ABI describes exactly the installed entry points
+           2. ABI error codes match the codes the contract reverts with
+           3. ABI events are ordered by their unique tags
+        */
+        assert_eq!(abi.entry_points.len(), contract.entry_points().len());
+        for entry_point in contract.entry_points().values() {
+            let described = abi
+                .entry_points
+                .iter()
+                .find(|described| described.name == entry_point.name())
+                .unwrap();
+            assert_eq!(described, &EntryPointAbi::from(entry_point));
+        }
+
+        let timelock_not_elapsed = abi
+            .errors
+            .iter()
+            .find(|error| error.name == "TimelockNotElapsed")
+            .unwrap();
+        let api_error: ApiError = ContractError(BridgeError::TimelockNotElapsed).into();
+        assert_eq!(api_error, ApiError::User(timelock_not_elapsed.user_error));
+        assert_eq!(
+            abi.errors.last().unwrap().code,
+            u16::from(BridgeError::Unknown)
+        );
+
+        let tags: Vec<u8> = abi.events.iter().map(|event| event.tag).collect();
+        assert_eq!(tags, (0..abi.events.len() as u8).collect::<Vec<u8>>());
+    }
+
     #[test]
     fn bridge_in_happy_path() {
         /*
@@ -153,6 +472,127 @@ mod tests {
         assert_eq!(bridge_balance, TEST_AMOUNT());
     }
 
+    #[test]
+    fn bridge_in_from_happy_path() {
+        /*
+            Scenario:
+
+            1. Approve the bridge contract package to spend the tokens of the caller
+            2. Call "bridge_in_from" entrypoint in bridge contract with the specified token
+            3. Assert that bridge contract received the expected amount of tokens
+            4. Assert that commission added in the pool
+            5. Verify expected event
+            6. Call "bridge_in_from" again and assert fail as the allowance is spent
+        */
+
+        let mut context = setup_context();
+
+        let (token_hash, token_package_hash, bridge_hash, bridge_package_hash) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = approve(
+            token_hash,
+            context.account.address,
+            Key::from(bridge_package_hash),
+            TEST_AMOUNT(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_in_from(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let funds_in_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+
+        if let BridgeEvent::FundsIn {
+            token_contract,
+            amount,
+            nonce,
+            sender,
+            ..
+        } = funds_in_event
+        {
+            assert_eq!(token_contract, token_package_hash);
+            assert_eq!(amount, TEST_AMOUNT());
+            assert_eq!(nonce, TEST_NONCE());
+            assert_eq!(sender, Key::Account(context.account.address));
+        } else {
+            panic!("Expected BridgeEvent::FundsIn but got {funds_in_event:?}");
+        }
+
+        let bridge_balance = query_balance(
+            &mut context.builder,
+            token_hash,
+            &Key::from(bridge_package_hash),
+        );
+        let commission =
+            query_commission_pool(&mut context.builder, bridge_hash, token_package_hash);
+
+        assert_eq!(bridge_balance, TEST_AMOUNT());
+        assert_eq!(commission, expected_total_commission());
+
+        let deploy_item = bridge_in_from(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::Revert(ApiError::User(
+            ERC20_INSUFFICIENT_ALLOWANCE_ERROR_CODE,
+        )));
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn bridge_in_from_without_allowance() {
+        /*
+            Scenario:
+
+            1. Call "bridge_in_from" entrypoint without approving the bridge contract package
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in_from(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::Revert(ApiError::User(
+            ERC20_INSUFFICIENT_ALLOWANCE_ERROR_CODE,
+        )));
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
     #[test]
     fn bridge_out_happy_path() {
         /*
@@ -381,143 +821,286 @@ mod tests {
     }
 
     #[test]
-    fn bridge_in_incorrect_values_nonce_already_used() {
+    fn bridge_in_deadline_too_far() {
         /*
             Scenario:
-            1. Call "bridge_in" entrypoint in bridge contract with incorrect nonce
-            2. Assert that all calls failed
+            1. Call "bridge_in" entrypoint with a deadline beyond the default deadline horizon
+            2. Assert fail
         */
 
         let mut context = setup_context();
 
-        // Deploy the bridge contract and the token contract
         let (_, token_package_hash, bridge_hash, _) =
             deploy_bridge_and_erc20(&mut context.builder, context.account.address);
 
-        let deploy_item_correct = bridge_in(
-            bridge_hash,
-            token_package_hash,
-            context.account.address,
-            U256::one() * TEST_ACCOUNT_BALANCE,
-            TEST_CORRECT_DEADLINE(),
-            TEST_NONCE(),
-            TEST_TRANSACTION_ID(),
-            TEST_GAS_COMMISSION(),
-            Vec::new(),
-        );
-        get_context(&mut context, deploy_item_correct).expect_success();
-
-        let deploy_item_incorrect = bridge_in(
+        let deploy_item = bridge_in(
             bridge_hash,
             token_package_hash,
             context.account.address,
-            U256::one() * TEST_ACCOUNT_BALANCE,
-            TEST_CORRECT_DEADLINE(),
+            TEST_AMOUNT(),
+            U256::from(TEST_BLOCK_TIME + DEFAULT_MAX_DEADLINE_HORIZON + 1),
             TEST_NONCE(),
             TEST_TRANSACTION_ID(),
             TEST_GAS_COMMISSION(),
             Vec::new(),
         );
 
-        // Verify that the transaction fails
-        let error = execution_error(&mut context, deploy_item_incorrect);
-
-        let expected_error: ApiError = ContractError(BridgeError::AlreadyUsedSignature).into();
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::DeadlineTooFar).into();
         assert_eq!(error.to_string(), expected_error.to_string());
     }
 
     #[test]
-    fn bridge_in_commission_bigger_than_transferred_amount() {
+    fn set_max_deadline_horizon_happy_path() {
         /*
             Scenario:
-            1. Call "bridge_in" entrypoint in bridge contract with incorrect gas commission
-            2. Assert that all calls failed
+            1. Call "set_max_deadline_horizon" entrypoint from another account and assert fail
+            2. Call "set_max_deadline_horizon" entrypoint to shorten the horizon
+            3. Call "bridge_in" entrypoint with a deadline beyond the new horizon and assert fail
+            4. Call "bridge_in" entrypoint with a deadline within the new horizon and assert success
         */
 
         let mut context = setup_context();
 
-        // Deploy the bridge contract and the token contract
         let (_, token_package_hash, bridge_hash, _) =
             deploy_bridge_and_erc20(&mut context.builder, context.account.address);
 
+        let res: u64 = context
+            .builder
+            .get_value(bridge_hash, "max_deadline_horizon");
+        assert_eq!(res, DEFAULT_MAX_DEADLINE_HORIZON);
+
+        let user = arbitrary_user(&mut context);
+        let deploy_item = set_max_deadline_horizon(bridge_hash, user.address, 0);
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let horizon = 3600;
+        let deploy_item = set_max_deadline_horizon(bridge_hash, context.account.address, horizon);
+        let res: u64 = get_context(&mut context, deploy_item)
+            .expect_success()
+            .get_value(bridge_hash, "max_deadline_horizon");
+        assert_eq!(res, horizon);
+
         let deploy_item = bridge_in(
             bridge_hash,
             token_package_hash,
             context.account.address,
-            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_AMOUNT(),
             TEST_CORRECT_DEADLINE(),
             TEST_NONCE(),
             TEST_TRANSACTION_ID(),
-            TEST_GAS_COMMISSION() + TEST_ACCOUNT_BALANCE,
+            TEST_GAS_COMMISSION(),
             Vec::new(),
         );
-
-        // // Verify that the transaction fails
         let error = execution_error(&mut context, deploy_item);
-
-        let expected_error: ApiError =
-            ContractError(BridgeError::CommissionBiggerThanTransferredAmount).into();
+        let expected_error: ApiError = ContractError(BridgeError::DeadlineTooFar).into();
         assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            U256::from(TEST_BLOCK_TIME + horizon),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
     }
 
     #[test]
-    fn bridge_in_incorrect_values_signature() {
+    fn bridge_in_deadline_horizon_in_milliseconds() {
         /*
             Scenario:
-            1. Call "bridge_in" entrypoint in bridge contract with incorrect signature values
-            2. Assert that all calls failed
+            1. Call "bridge_in" entrypoint at a millisecond block time with a deadline beyond the
+               default deadline horizon and assert fail
+            2. Call "bridge_in" entrypoint with a deadline a week ahead and assert success
         */
 
         let mut context = setup_context();
-        let mut context2 = setup_context();
 
-        // Deploy the bridge contract and the token contract
         let (_, token_package_hash, bridge_hash, _) =
             deploy_bridge_and_erc20(&mut context.builder, context.account.address);
 
-        let (_, token_package_hash_incorrect, _, _) =
-            deploy_bridge_and_erc20(&mut context2.builder, context2.account.address);
+        let block_time = TEST_BLOCK_TIME * 1000;
 
-        // 1. Incorrect Token
-        let bytes_incorrect_deadline = cook_msg_bridge_in(
-            bridge_hash,
-            token_package_hash_incorrect,
-            context.account.address,
-            U256::one() * TEST_ACCOUNT_BALANCE,
-            TEST_GAS_COMMISSION(),
-            TEST_CORRECT_DEADLINE(),
-            TEST_NONCE() + 3,
-            TEST_TRANSACTION_ID(),
-            &TEST_DESTINATION_CHAIN(),
-            &TEST_DESTINATION_ADDRESS(),
-        );
         let deploy_item = bridge_in(
             bridge_hash,
             token_package_hash,
             context.account.address,
-            U256::one() * TEST_ACCOUNT_BALANCE,
-            TEST_CORRECT_DEADLINE(),
+            TEST_AMOUNT(),
+            U256::from(block_time + DEFAULT_MAX_DEADLINE_HORIZON + 1),
             TEST_NONCE(),
             TEST_TRANSACTION_ID(),
             TEST_GAS_COMMISSION(),
-            bytes_incorrect_deadline,
+            Vec::new(),
         );
-        let error = execution_error(&mut context, deploy_item);
-        let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
+        let error = execution_context_at(&mut context, deploy_item, block_time)
+            .expect_failure()
+            .get_error()
+            .unwrap();
+        let expected_error: ApiError = ContractError(BridgeError::DeadlineTooFar).into();
         assert_eq!(error.to_string(), expected_error.to_string());
 
-        // 2. Incorrect Amount
-        let bytes_incorrect_amount = cook_msg_bridge_in(
+        let week = 7 * 24 * 60 * 60 * 1000;
+        let deploy_item = bridge_in(
             bridge_hash,
             token_package_hash,
             context.account.address,
-            U256::one() * (TEST_ACCOUNT_BALANCE + 1),
-            TEST_GAS_COMMISSION(),
-            TEST_CORRECT_DEADLINE(),
+            TEST_AMOUNT(),
+            U256::from(block_time + week),
             TEST_NONCE(),
             TEST_TRANSACTION_ID(),
-            &TEST_DESTINATION_CHAIN(),
-            &TEST_DESTINATION_ADDRESS(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        execution_context_at(&mut context, deploy_item, block_time).expect_success();
+    }
+
+    #[test]
+    fn bridge_in_incorrect_values_nonce_already_used() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint in bridge contract with incorrect nonce
+            2. Assert that all calls failed
+        */
+
+        let mut context = setup_context();
+
+        // Deploy the bridge contract and the token contract
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item_correct = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item_correct).expect_success();
+
+        let deploy_item_incorrect = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+
+        // Verify that the transaction fails
+        let error = execution_error(&mut context, deploy_item_incorrect);
+
+        let expected_error: ApiError = ContractError(BridgeError::AlreadyUsedSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn bridge_in_commission_bigger_than_transferred_amount() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint in bridge contract with incorrect gas commission
+            2. Assert that all calls failed
+        */
+
+        let mut context = setup_context();
+
+        // Deploy the bridge contract and the token contract
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION() + TEST_ACCOUNT_BALANCE,
+            Vec::new(),
+        );
+
+        // // Verify that the transaction fails
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError =
+            ContractError(BridgeError::CommissionBiggerThanTransferredAmount).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn bridge_in_incorrect_values_signature() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint in bridge contract with incorrect signature values
+            2. Assert that all calls failed
+        */
+
+        let mut context = setup_context();
+        let mut context2 = setup_context();
+
+        // Deploy the bridge contract and the token contract
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let (_, token_package_hash_incorrect, _, _) =
+            deploy_bridge_and_erc20(&mut context2.builder, context2.account.address);
+
+        // 1. Incorrect Token
+        let bytes_incorrect_deadline = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
+            bridge_hash,
+            token_package_hash_incorrect,
+            context.account.address,
+            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_GAS_COMMISSION(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + 3,
+            TEST_TRANSACTION_ID(),
+            &TEST_DESTINATION_CHAIN(),
+            &TEST_DESTINATION_ADDRESS(),
+        );
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            bytes_incorrect_deadline,
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        // 2. Incorrect Amount
+        let bytes_incorrect_amount = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * (TEST_ACCOUNT_BALANCE + 1),
+            TEST_GAS_COMMISSION(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            &TEST_DESTINATION_CHAIN(),
+            &TEST_DESTINATION_ADDRESS(),
         );
         let deploy_item = bridge_in(
             bridge_hash,
@@ -536,6 +1119,7 @@ mod tests {
 
         // 3. Incorrect Deadline
         let bytes_incorrect_deadline = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             context.account.address,
@@ -564,6 +1148,7 @@ mod tests {
 
         // 4. Incorrect Nonce
         let bytes_incorrect_deadline = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             context.account.address,
@@ -592,6 +1177,7 @@ mod tests {
 
         // 4_1. Incorrect Transaction id
         let bytes_incorrect_deadline = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             context.account.address,
@@ -621,6 +1207,7 @@ mod tests {
         // 5. Incorrect User
         let sender = arbitrary_user(&mut context);
         let bytes_incorrect_deadline = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             sender.address,
@@ -650,6 +1237,7 @@ mod tests {
 
         // 6. Incorrect Destination Chain
         let bytes_incorrect_deadline = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             context.account.address,
@@ -678,6 +1266,7 @@ mod tests {
 
         // 6. Incorrect Destination Address
         let bytes_incorrect_deadline = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             context.account.address,
@@ -704,7 +1293,117 @@ mod tests {
         let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
         assert_eq!(error.to_string(), expected_error.to_string());
 
+        // 7. Incorrect Chain Name
+        let bytes_incorrect_chain_name = cook_msg_bridge_in(
+            "casper",
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_GAS_COMMISSION(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            &TEST_DESTINATION_CHAIN(),
+            &TEST_DESTINATION_ADDRESS(),
+        );
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * TEST_ACCOUNT_BALANCE,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            bytes_incorrect_chain_name,
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+
+    }
+
+    #[test]
+    fn is_nonce_used_reports_consumed_nonces() {
+        /*
+            Scenario:
+            1. Call "is_nonce_used" entrypoint and assert the nonce is unused
+            2. Call "bridge_in" entrypoint signed with that nonce
+            3. Call "is_nonce_used" entrypoint and assert the nonce is used
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let args = runtime_args! { PARAM_NONCE => TEST_NONCE() };
+        let used: bool = query_entry_point(&mut context, bridge_hash, EP_IS_NONCE_USED, args);
+        assert!(!used);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let args = runtime_args! { PARAM_NONCE => TEST_NONCE() };
+        let used: bool = query_entry_point(&mut context, bridge_hash, EP_IS_NONCE_USED, args);
+        assert!(used);
+
+        let args = runtime_args! { PARAM_NONCE => TEST_NONCE() + U128::one() };
+        let used: bool = query_entry_point(&mut context, bridge_hash, EP_IS_NONCE_USED, args);
+        assert!(!used);
+    }
+
+    #[test]
+    fn transfer_out_incorrect_values_deadline() {
+        /*
+            Scenario:
+            1. Call "transfer_out" entrypoint with an expired deadline and assert fail
+            2. Call "transfer_out" entrypoint with a deadline beyond the maximal deadline horizon
+               and assert fail
+        */
+
+        let mut context = setup_context();
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
 
+        for (deadline, error) in [
+            (TEST_EXPIRED_DEADLINE(), BridgeError::ExpiredSignature),
+            (
+                U256::from(TEST_BLOCK_TIME + DEFAULT_MAX_DEADLINE_HORIZON + 1),
+                BridgeError::DeadlineTooFar,
+            ),
+        ] {
+            let deploy_item = transfer_out(
+                bridge_hash,
+                token_package_hash,
+                context.account.address,
+                recipient_key,
+                TEST_AMOUNT() - expected_total_commission(),
+                expected_total_commission(),
+                deadline,
+                TEST_NONCE(),
+                TEST_TRANSACTION_ID(),
+                Vec::new(),
+            );
+
+            let actual_error = execution_error(&mut context, deploy_item);
+            let expected_error: ApiError = ContractError(error).into();
+            assert_eq!(actual_error.to_string(), expected_error.to_string());
+        }
     }
 
     #[test]
@@ -737,13 +1436,15 @@ mod tests {
         get_context(&mut context, deploy_item).expect_success();
 
         let bytes = cook_msg_transfer_out(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             context.account.address,
             recipient_key,
             (TEST_AMOUNT()) - expected_total_commission(),
             expected_total_commission(),
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             TEST_TRANSACTION_ID(),
         );
 
@@ -755,7 +1456,8 @@ mod tests {
             recipient_key,
             (TEST_AMOUNT()) - expected_total_commission(),
             expected_total_commission(),
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             TEST_TRANSACTION_ID(),
             bytes.clone(),
         );
@@ -772,7 +1474,8 @@ mod tests {
             recipient_key,
             (TEST_AMOUNT()) - expected_total_commission() + 1,
             expected_total_commission(),
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             TEST_TRANSACTION_ID(),
             bytes.clone(),
         );
@@ -789,7 +1492,8 @@ mod tests {
             recipient_key,
             (TEST_AMOUNT()) - expected_total_commission(),
             expected_total_commission() - 10,
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             TEST_TRANSACTION_ID(),
             bytes.clone(),
         );
@@ -805,7 +1509,8 @@ mod tests {
             recipient_key,
             (TEST_AMOUNT()) - expected_total_commission(),
             expected_total_commission(),
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             U256::one() * 11,
             bytes.clone(),
         );
@@ -824,7 +1529,8 @@ mod tests {
             recipient_key_invalid,
             (TEST_AMOUNT()) - expected_total_commission(),
             expected_total_commission(),
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             TEST_TRANSACTION_ID(),
             bytes.clone(),
         );
@@ -832,6 +1538,35 @@ mod tests {
         let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
         assert_eq!(error.to_string(), expected_error.to_string());
 
+        // 5. Incorrect Chain Name
+        let bytes_incorrect_chain_name = cook_msg_transfer_out(
+            "casper",
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            (TEST_AMOUNT()) - expected_total_commission(),
+            expected_total_commission(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
+            TEST_TRANSACTION_ID(),
+        );
+        let deploy_item = transfer_out(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            (TEST_AMOUNT()) - expected_total_commission(),
+            expected_total_commission(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
+            TEST_TRANSACTION_ID(),
+            bytes_incorrect_chain_name,
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
     }
 
     #[test]
@@ -1003,7 +1738,7 @@ mod tests {
         for user in &[&context.account, &test_subj] {
             let args = runtime_args! {
                 PARAM_BYTES => Bytes::new(),
-                PARAM_SIGNATURE => [0; 64],
+                PARAM_SIGNATURES => vec![Bytes::from(vec![0u8; 64])],
                 PARAM_SIGNER => test_public_key(),
                 PARAM_NONCE => U128::one(),
             };
@@ -1142,7 +1877,8 @@ mod tests {
             recipient_key,
             (TEST_AMOUNT()) - expected_total_commission(),
             expected_total_commission(),
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             TEST_TRANSACTION_ID(),
             Vec::new(),
         );
@@ -1158,12 +1894,18 @@ mod tests {
             total_sum_for_transfer,
             nonce,
             recipient,
+            amount,
+            commission,
+            transaction_id,
         } = transfer_out_event
         {
             assert_eq!(token_contract, token_package_hash);
             assert_eq!(recipient, recipient_key);
-            assert_eq!(total_sum_for_transfer, TEST_AMOUNT(), "HEH");
+            assert_eq!(total_sum_for_transfer, TEST_AMOUNT());
+            assert_eq!(amount, TEST_AMOUNT() - expected_total_commission());
+            assert_eq!(commission, expected_total_commission());
             assert_eq!(nonce, TEST_NONCE() + 1);
+            assert_eq!(transaction_id, TEST_TRANSACTION_ID());
         } else {
             panic!("Expected bridge out event, but got {transfer_out_event:?}");
         }
@@ -1219,7 +1961,8 @@ mod tests {
             recipient_key,
             TEST_AMOUNT() * 2,
             expected_total_commission(),
-            TEST_NONCE() + 1,
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
             TEST_TRANSACTION_ID(),
             Vec::new(),
         );
@@ -1249,6 +1992,7 @@ mod tests {
             Scenario:
             1. Call "set_stable_commission_percent" entrypoint in set percent
             2. Assert that the percent is established
+            3. Verify expected event
         */
 
         let mut context = setup_context();
@@ -1273,28 +2017,88 @@ mod tests {
             .get_value(bridge_hash, PARAM_STABLE_COMMISSION_PERCENT);
 
         assert_eq!(res, stable_commission_percent);
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(
+            event,
+            BridgeEvent::StableCommissionChanged {
+                old: U256::from(DEFAULT_STABLE_COMMISSION_BPS),
+                new: stable_commission_percent,
+            }
+        );
     }
 
     #[test]
-    fn set_signer_happy_path() {
+    fn migrate_stable_commission_to_bps_keeps_bps_value() {
         /*
             Scenario:
-            1. Call "set_signer" entrypoint in set signer
-            2. Assert that the signer is established
+            1. Call "migrate_stable_commission_to_bps" entrypoint on a freshly installed contract
+            2. Assert that the percent, already denominated in BPS, is left untouched
         */
 
         let mut context = setup_context();
 
-        // Deploy the bridge contract and the token contract
         let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
 
-        // Try to transfer token in bridge from account that doesn't have enough tokens
+        let deploy_item = simple_deploy_builder(context.account.address)
+            .with_stored_session_hash(
+                bridge_hash,
+                EP_MIGRATE_STABLE_COMMISSION_TO_BPS,
+                runtime_args! {},
+            )
+            .build();
+
+        let res: U256 = get_context(&mut context, deploy_item)
+            .expect_success()
+            .get_value(bridge_hash, PARAM_STABLE_COMMISSION_PERCENT);
+
+        assert_eq!(res, TEST_STABLE_COMMISSION_PERCENT());
+
+        let in_bps: bool = context
+            .builder
+            .get_value(bridge_hash, "stable_commission_in_bps");
+        assert!(in_bps);
+    }
+
+    #[test]
+    fn set_signer_happy_path() {
+        /*
+            Scenario:
+            1. Call "set_signer" entrypoint in set signer
+            2. Assert that the signer is established
+            3. Verify expected event
+        */
+
+        let mut context = setup_context();
+
+        // Deploy the bridge contract and the token contract
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        // Try to transfer token in bridge from account that doesn't have enough tokens
         let deploy_item = set_test_signer(bridge_hash, context.account.address, test_public_key());
-        let res: String = get_context(&mut context, deploy_item)
+        let res: SignerSet = get_context(&mut context, deploy_item)
             .expect_success()
-            .get_value(bridge_hash, PARAM_SIGNER);
+            .get_value(bridge_hash, "signer_keys");
 
-        assert_eq!(res, test_public_key());
+        assert_eq!(res.signers, stored_signers(&[test_public_key()]));
+        assert_eq!(res.threshold, 1);
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(
+            event,
+            BridgeEvent::SignerChanged {
+                old: signer_hex(test_public_key()),
+                new: signer_hex(test_public_key()),
+            }
+        );
     }
 
     #[test]
@@ -1324,6 +2128,116 @@ mod tests {
         execution_context(&mut context, deploy_item).expect_failure();
     }
 
+    #[test]
+    fn set_signer_ed25519() {
+        /*
+            Scenario:
+            1. Call "set_signer" entrypoint with a hex encoded Ed25519 public key
+            2. Call "bridge_in" entrypoint signed by the old secp256k1 signer and assert it fails
+            3. Call "bridge_in" entrypoint signed by the Ed25519 signer and assert success
+        */
+
+        let mut context = setup_context();
+
+        let (token_hash, token_package_hash, bridge_hash, bridge_package_hash) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = set_test_signer(
+            bridge_hash,
+            context.account.address,
+            &test_ed25519_signer_public_key(),
+        );
+        let res: SignerSet = get_context(&mut context, deploy_item)
+            .expect_success()
+            .get_value(bridge_hash, "signer_keys");
+        assert_eq!(
+            res.signers,
+            stored_signers(&[test_ed25519_signer_public_key()])
+        );
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = bridge_in_signed_by_ed25519(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            &test_ed25519_signer_secret_key(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let bridge_balance = query_balance(
+            &mut context.builder,
+            token_hash,
+            &Key::Hash(bridge_package_hash.value()),
+        );
+        assert_eq!(bridge_balance, TEST_AMOUNT());
+    }
+
+    #[test]
+    fn set_signer_secp256k1_hex() {
+        /*
+            Scenario:
+            1. Call "set_signer" entrypoint with a hex encoded Casper secp256k1 public key
+            2. Call "bridge_in" entrypoint signed by the Ed25519 test signer and assert it fails
+            3. Call "bridge_in" entrypoint signed by the secp256k1 signer and assert success
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let signer = format!("02{}", test_signer_compressed_key());
+        let deploy_item = set_test_signer(bridge_hash, context.account.address, &signer);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_in_signed_by_ed25519(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            &test_ed25519_signer_secret_key(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+    }
+
     #[test]
     fn set_stable_commission_percent_invalid_value() {
         /*
@@ -1337,7 +2251,7 @@ mod tests {
         // Deploy the bridge contract
         let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
 
-        let stable_commission_percent = U256::one() * 101;
+        let stable_commission_percent = U256::from(MAX_STABLE_COMMISSION_BPS + 1);
         // Try to set percent
         let deploy_item = simple_deploy_builder(context.account.address)
             .with_stored_session_hash(
@@ -1457,10 +2371,12 @@ mod tests {
         if let BridgeEvent::WithdrawCommission {
             token_contract,
             amount,
+            recipient,
         } = withdraw_commission_event
         {
             assert_eq!(token_contract, token_package_hash);
             assert_eq!(amount, commission_in_pool_before);
+            assert_eq!(recipient, recipient_key);
         } else {
             panic!("Expected bridge out event, but got {withdraw_commission_event:?}");
         }
@@ -1545,4 +2461,2993 @@ mod tests {
         let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
         assert_eq!(error.to_string(), expected_error.to_string());
     }
+
+    #[test]
+    fn rebalance_liquidity_happy_path() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract.
+            2. Call "rebalance_liquidity" entrypoint before a treasury is set and assert fail
+            3. Call "propose_change" and "execute_change" entrypoints to set the treasury
+            4. Call "rebalance_liquidity" entrypoint to move the whole pool without commission to the treasury
+            5. Assert treasury and contract balances and that the commission pool is untouched
+            6. Assert event
+            7. Call "rebalance_liquidity" entrypoint again and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (token_hash, token_package_hash, bridge_hash, bridge_package_hash) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let pool = TEST_AMOUNT() - expected_total_commission();
+
+        let deploy_item = rebalance_liquidity(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            pool,
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::TreasuryNotSet).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let treasury_key = arbitrary_user_key(&mut context);
+        set_treasury(&mut context, bridge_hash, treasury_key);
+
+        let res: Option<Key> = context.builder.get_value(bridge_hash, "treasury");
+        assert_eq!(res, Some(treasury_key));
+
+        let deploy_item = rebalance_liquidity(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            pool,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let liquidity_moved_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+
+        let treasury_balance = query_balance(&mut context.builder, token_hash, &treasury_key);
+        let bridge_balance = query_balance(
+            &mut context.builder,
+            token_hash,
+            &Key::from(bridge_package_hash),
+        );
+        let commission =
+            query_commission_pool(&mut context.builder, bridge_hash, token_package_hash);
+
+        assert_eq!(treasury_balance, pool);
+        assert_eq!(bridge_balance, expected_total_commission());
+        assert_eq!(commission, expected_total_commission());
+
+        assert_eq!(
+            liquidity_moved_event,
+            BridgeEvent::LiquidityMoved {
+                token_contract: token_package_hash,
+                amount: pool,
+                from: Key::from(bridge_package_hash),
+                to: treasury_key,
+            }
+        );
+
+        let deploy_item = rebalance_liquidity(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::AmountExceedBridgePool).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn rebalance_liquidity_consumes_outflow_cap() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract.
+            2. Set the treasury and a windowed "bridge_out" cap
+            3. Call "rebalance_liquidity" entrypoint up to the cap
+            4. Assert "bridge_out" and "rebalance_liquidity" above the remaining cap fail
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let treasury_key = arbitrary_user_key(&mut context);
+        set_treasury(&mut context, bridge_hash, treasury_key);
+
+        let deploy_item = set_bridge_out_limits(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            U256::zero(),
+            U256::one() * 1_500,
+            100,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = rebalance_liquidity(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            U256::one() * 1_500,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        for deploy_item in [
+            bridge_out(
+                bridge_hash,
+                token_package_hash,
+                context.account.address,
+                recipient_key,
+                U256::one(),
+            ),
+            rebalance_liquidity(
+                bridge_hash,
+                token_package_hash,
+                context.account.address,
+                U256::one(),
+            ),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+            let expected_error: ApiError =
+                ContractError(BridgeError::AmountExceedOutflowCap).into();
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn rebalance_liquidity_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "rebalance_liquidity" and "deposit_liquidity" entrypoints from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        for deploy_item in [
+            rebalance_liquidity(bridge_hash, token_package_hash, user.address, U256::one()),
+            deposit_liquidity(bridge_hash, token_package_hash, user.address, U256::one()),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn deposit_liquidity_happy_path() {
+        /*
+            Scenario:
+            1. Approve the bridge contract package to spend the tokens of the caller
+            2. Call "deposit_liquidity" entrypoint
+            3. Assert that the contract received the tokens without any commission
+            4. Assert event
+        */
+
+        let mut context = setup_context();
+
+        let (token_hash, token_package_hash, bridge_hash, bridge_package_hash) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = approve(
+            token_hash,
+            context.account.address,
+            Key::from(bridge_package_hash),
+            TEST_AMOUNT(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = deposit_liquidity(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let liquidity_moved_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+
+        let bridge_balance = query_balance(
+            &mut context.builder,
+            token_hash,
+            &Key::from(bridge_package_hash),
+        );
+        let commission =
+            query_commission_pool(&mut context.builder, bridge_hash, token_package_hash);
+
+        assert_eq!(bridge_balance, TEST_AMOUNT());
+        assert_eq!(commission, U256::zero());
+
+        assert_eq!(
+            liquidity_moved_event,
+            BridgeEvent::LiquidityMoved {
+                token_contract: token_package_hash,
+                amount: TEST_AMOUNT(),
+                from: context.account.key(),
+                to: Key::from(bridge_package_hash),
+            }
+        );
+    }
+
+    #[test]
+    fn pause_blocks_bridge_in_and_out() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract.
+            2. Call "pause" entrypoint
+            3. Assert paused flag and event
+            4. Assert "bridge_in" and "transfer_out" fail with ContractPaused
+            5. Assert "bridge_out" and "bridge_out_native" fail with ContractPaused
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = pause(bridge_hash, context.account.address);
+        let paused: bool = get_context(&mut context, deploy_item)
+            .expect_success()
+            .get_value(bridge_hash, "paused");
+        assert!(paused);
+
+        let pause_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(pause_event, BridgeEvent::Paused);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::ContractPaused).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = transfer_out(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            (TEST_AMOUNT()) - expected_total_commission(),
+            expected_total_commission(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + 2,
+            TEST_TRANSACTION_ID(),
+            Vec::new(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::ContractPaused).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        for deploy_item in [
+            bridge_out(
+                bridge_hash,
+                token_package_hash,
+                context.account.address,
+                recipient_key,
+                U256::one() * 1_000,
+            ),
+            bridge_out_native(
+                bridge_hash,
+                context.account.address,
+                recipient_key,
+                U256::one() * 1_000,
+                TEST_TRANSACTION_ID(),
+            ),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+            let expected_error: ApiError = ContractError(BridgeError::ContractPaused).into();
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn unpause_restores_bridge_in() {
+        /*
+            Scenario:
+            1. Call "pause" entrypoint
+            2. Call "unpause" entrypoint
+            3. Assert paused flag and event
+            4. Call "bridge_in" entrypoint and assert success
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = pause(bridge_hash, context.account.address);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = unpause(bridge_hash, context.account.address);
+        let paused: bool = get_context(&mut context, deploy_item)
+            .expect_success()
+            .get_value(bridge_hash, "paused");
+        assert!(!paused);
+
+        let unpause_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(unpause_event, BridgeEvent::Unpaused);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+    }
+
+    #[test]
+    fn pause_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "pause" and "unpause" entrypoints from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        for deploy_item in [
+            pause(bridge_hash, user.address),
+            unpause(bridge_hash, user.address),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn add_token_happy_path() {
+        /*
+            Scenario:
+            1. Call "add_token" entrypoint
+            2. Assert that the token is enabled in the registry
+            3. Call "remove_token" entrypoint
+            4. Assert that the token is disabled in the registry
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash) = deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let deploy_item = add_token(bridge_hash, context.account.address, token_package_hash);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert!(config.enabled);
+
+        let deploy_item = remove_token(bridge_hash, context.account.address, token_package_hash);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert!(!config.enabled);
+    }
+
+    #[test]
+    fn set_token_mode_happy_path() {
+        /*
+            Scenario:
+            1. Call "add_token" and "set_token_mode" entrypoints to register a wrapped token
+            2. Assert that the token is enabled and minted/burned in the registry
+            3. Call "remove_token" entrypoint
+            4. Assert that the token is disabled and keeps its mode
+            5. Call "set_token_mode" entrypoint to lock the token again
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash) = deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let deploy_item = add_token(bridge_hash, context.account.address, token_package_hash);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(config.mode, TokenMode::Lock);
+
+        let deploy_item = set_token_mode(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            TokenMode::MintBurn as u8,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert!(config.enabled);
+        assert_eq!(config.mode, TokenMode::MintBurn);
+
+        let deploy_item = remove_token(bridge_hash, context.account.address, token_package_hash);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert!(!config.enabled);
+        assert_eq!(config.mode, TokenMode::MintBurn);
+
+        let deploy_item = set_token_mode(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            TokenMode::Lock as u8,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(config.mode, TokenMode::Lock);
+    }
+
+    #[test]
+    fn set_token_mode_invalid() {
+        /*
+            Scenario:
+            1. Call "set_token_mode" entrypoint with an unknown mode
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item =
+            set_token_mode(bridge_hash, context.account.address, token_package_hash, 2);
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::InvalidTokenMode).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn set_token_mode_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "set_token_mode" entrypoint from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        let deploy_item = set_token_mode(
+            bridge_hash,
+            user.address,
+            token_package_hash,
+            TokenMode::MintBurn as u8,
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn bridge_in_unregistered_token() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint with a token that was never added to the bridge
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash) = deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::TokenNotRegistered).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn removed_token_blocks_bridge_out_and_transfer_out() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract.
+            2. Call "remove_token" entrypoint
+            3. Assert "bridge_out" and "transfer_out" fail
+            4. Assert "withdraw_commission" is still available
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = remove_token(bridge_hash, context.account.address, token_package_hash);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_out(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::TokenNotRegistered).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = transfer_out(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            (TEST_AMOUNT()) - expected_total_commission(),
+            expected_total_commission(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
+            TEST_TRANSACTION_ID(),
+            Vec::new(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::TokenNotRegistered).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = withdraw_commission(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            expected_total_commission(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+    }
+
+    #[test]
+    fn add_token_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "add_token" and "remove_token" entrypoints from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash) = deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        for deploy_item in [
+            add_token(bridge_hash, user.address, token_package_hash),
+            remove_token(bridge_hash, user.address, token_package_hash),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn bridge_in_amount_limits() {
+        /*
+            Scenario:
+            1. Set minimal "bridge_in" amount above the transferred amount
+            2. Assert "bridge_in" fails
+            3. Set maximal "bridge_in" amount below the transferred amount
+            4. Assert "bridge_in" fails
+            5. Set limits around the transferred amount and assert "bridge_in" succeeds
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let account_address = context.account.address;
+        let bridge_in_deploy = || {
+            bridge_in(
+                bridge_hash,
+                token_package_hash,
+                account_address,
+                TEST_AMOUNT(),
+                TEST_CORRECT_DEADLINE(),
+                TEST_NONCE(),
+                TEST_TRANSACTION_ID(),
+                TEST_GAS_COMMISSION(),
+                Vec::new(),
+            )
+        };
+
+        let deploy_item = set_bridge_in_limits(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            TEST_AMOUNT() + 1,
+            U256::zero(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let error = execution_error(&mut context, bridge_in_deploy());
+        let expected_error: ApiError = ContractError(BridgeError::AmountBelowMinimum).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = set_bridge_in_limits(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            U256::zero(),
+            TEST_AMOUNT() - 1,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let error = execution_error(&mut context, bridge_in_deploy());
+        let expected_error: ApiError = ContractError(BridgeError::AmountAboveMaximum).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = set_bridge_in_limits(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            TEST_AMOUNT(),
+            TEST_AMOUNT(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        get_context(&mut context, bridge_in_deploy()).expect_success();
+    }
+
+    #[test]
+    fn bridge_out_amount_limits() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract.
+            2. Set per-transfer and windowed "bridge_out" caps
+            3. Assert "bridge_out" above per-transfer cap fails
+            4. Assert "bridge_out" above the window cap fails
+            5. Assert "bridge_out" succeeds again once the window slid past earlier payouts
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (token_hash, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let outflow_window = 100u64;
+        let deploy_item = set_bridge_out_limits(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            U256::one() * 1_000,
+            U256::one() * 1_500,
+            outflow_window,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        // 1. Above per-transfer cap
+        let deploy_item = bridge_out(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_001,
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::AmountExceedTransferLimit).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        // 2. Within both caps
+        let deploy_item = bridge_out(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        // 3. Above window cap
+        let deploy_item = bridge_out_for_transfer(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+            "SOUR",
+            U256::from(2),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::AmountExceedOutflowCap).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        // 4. Next window
+        let deploy_item = bridge_out_for_transfer(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+            "SOUR",
+            U256::from(3),
+        );
+        context
+            .builder
+            .exec(
+                ExecuteRequestBuilder::from_deploy_item(deploy_item)
+                    .with_block_time(TEST_BLOCK_TIME + 2 * outflow_window)
+                    .build(),
+            )
+            .commit()
+            .expect_success();
+
+        let recipient_balance = query_balance(&mut context.builder, token_hash, &recipient_key);
+        assert_eq!(recipient_balance, U256::one() * 2_000);
+    }
+
+    #[test]
+    fn bridge_out_outflow_cap_slides_across_window_boundary() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract
+            2. Set an outflow cap of 1500 per 100 time units
+            3. Pay out 500 at the start and 1000 at the end of the first 100 time units
+            4. Assert that a burst of 1000 right after the boundary fails
+            5. Assert that only the amount released by the earliest payout can be paid out later
+            6. Assert that the rest is released one window after the second payout
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (token_hash, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let outflow_window = 100u64;
+        let deploy_item = set_bridge_out_limits(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            U256::one() * 1_000,
+            U256::one() * 1_500,
+            outflow_window,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let bridge_out_at = |context: &mut TestContext, amount: u64, id: u64, block_time: u64| {
+            let deploy_item = bridge_out_for_transfer(
+                bridge_hash,
+                token_package_hash,
+                context.account.address,
+                recipient_key,
+                U256::from(amount),
+                "SOUR",
+                U256::from(id),
+            );
+            execution_context_at(context, deploy_item, block_time).get_error()
+        };
+        let expected_error: ApiError = ContractError(BridgeError::AmountExceedOutflowCap).into();
+        let expected_error = engine_state::Error::Exec(execution::Error::Revert(expected_error));
+
+        // 3. Two payouts within the first window
+        assert!(bridge_out_at(&mut context, 500, 1, TEST_BLOCK_TIME).is_none());
+        let end_of_window = TEST_BLOCK_TIME + outflow_window - 1;
+        assert!(bridge_out_at(&mut context, 1_000, 2, end_of_window).is_none());
+
+        // 4. Burst right after the boundary
+        let error = bridge_out_at(&mut context, 1_000, 3, TEST_BLOCK_TIME + outflow_window);
+        assert_eq!(error.unwrap().to_string(), expected_error.to_string());
+
+        // 5. Only the first payout has left the window
+        let later = TEST_BLOCK_TIME + outflow_window * 3 / 2;
+        let error = bridge_out_at(&mut context, 501, 4, later);
+        assert_eq!(error.unwrap().to_string(), expected_error.to_string());
+        assert!(bridge_out_at(&mut context, 500, 5, later).is_none());
+
+        // 6. The second payout has left the window
+        let released = end_of_window + 2 * outflow_window;
+        assert!(bridge_out_at(&mut context, 1_000, 6, released).is_none());
+
+        let recipient_balance = query_balance(&mut context.builder, token_hash, &recipient_key);
+        assert_eq!(recipient_balance, U256::one() * 3_000);
+    }
+
+    #[test]
+    fn bridge_out_outflow_cap_survives_window_change() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract
+            2. Set an outflow cap of 1500 per 100 time units and pay out 1000
+            3. Grow the outflow window to 1000 time units
+            4. Assert that the earlier payout still counts against the cap
+            5. Assert that it keeps counting once the old window elapsed
+            6. Shrink the outflow window to 10 time units
+            7. Assert that payouts older than the new window are released
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (token_hash, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let set_outflow_window = |context: &mut TestContext, outflow_window: u64| {
+            let deploy_item = set_bridge_out_limits(
+                bridge_hash,
+                context.account.address,
+                token_package_hash,
+                U256::zero(),
+                U256::one() * 1_500,
+                outflow_window,
+            );
+            get_context(context, deploy_item).expect_success();
+        };
+        let bridge_out_at = |context: &mut TestContext, amount: u64, id: u64, block_time: u64| {
+            let deploy_item = bridge_out_for_transfer(
+                bridge_hash,
+                token_package_hash,
+                context.account.address,
+                recipient_key,
+                U256::from(amount),
+                "SOUR",
+                U256::from(id),
+            );
+            execution_context_at(context, deploy_item, block_time).get_error()
+        };
+        let expected_error: ApiError = ContractError(BridgeError::AmountExceedOutflowCap).into();
+        let expected_error = engine_state::Error::Exec(execution::Error::Revert(expected_error));
+
+        // 2. Payout under the initial window
+        set_outflow_window(&mut context, 100);
+        assert!(bridge_out_at(&mut context, 1_000, 1, TEST_BLOCK_TIME).is_none());
+
+        // 3. Grow the window
+        set_outflow_window(&mut context, 1_000);
+
+        // 4. The earlier payout is still accounted
+        let error = bridge_out_at(&mut context, 501, 2, TEST_BLOCK_TIME + 1);
+        assert_eq!(error.unwrap().to_string(), expected_error.to_string());
+        assert!(bridge_out_at(&mut context, 500, 3, TEST_BLOCK_TIME + 1).is_none());
+
+        // 5. The old window elapsed, the new one did not
+        let error = bridge_out_at(&mut context, 1, 4, TEST_BLOCK_TIME + 150);
+        assert_eq!(error.unwrap().to_string(), expected_error.to_string());
+
+        // 6. Shrink the window
+        set_outflow_window(&mut context, 10);
+
+        // 7. Both payouts are older than the new window
+        assert!(bridge_out_at(&mut context, 1_000, 5, TEST_BLOCK_TIME + 150).is_none());
+
+        let recipient_balance = query_balance(&mut context.builder, token_hash, &recipient_key);
+        assert_eq!(recipient_balance, U256::one() * 2_500);
+    }
+
+    #[test]
+    fn bridge_out_transfer_already_processed() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract
+            2. Call "bridge_out" entrypoint for an inbound transfer and assert that
+               "is_transfer_processed" turns true for it only
+            3. Call "bridge_out" entrypoint again for the same transfer and assert fail
+            4. Call "bridge_out" entrypoint with the same transaction id from another source chain
+            5. Assert that the recipient received both distinct transfers
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (token_hash, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let is_transfer_processed = |context: &mut TestContext, source_chain: &str| -> bool {
+            let args = runtime_args! {
+                PARAM_SOURCE_CHAIN => source_chain.to_string(),
+                PARAM_TRANSACTION_ID => TEST_TRANSACTION_ID(),
+            };
+            query_entry_point(context, bridge_hash, EP_IS_TRANSFER_PROCESSED, args)
+        };
+        assert!(!is_transfer_processed(&mut context, "SOUR"));
+
+        let deploy_item = bridge_out_for_transfer(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+            "SOUR",
+            TEST_TRANSACTION_ID(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        assert!(is_transfer_processed(&mut context, "SOUR"));
+        assert!(!is_transfer_processed(&mut context, "OTHER"));
+
+        let deploy_item = bridge_out_for_transfer(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+            "SOUR",
+            TEST_TRANSACTION_ID(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::TransferAlreadyProcessed).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = bridge_out_for_transfer(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+            "OTHER",
+            TEST_TRANSACTION_ID(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let recipient_balance = query_balance(&mut context.builder, token_hash, &recipient_key);
+        assert_eq!(recipient_balance, U256::one() * 2_000);
+    }
+
+    #[test]
+    fn bridge_out_long_source_chain() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract
+            2. Call "bridge_out" entrypoint for a transfer from a chain with a long name
+            3. Call "bridge_out" entrypoint again for the same transfer and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let recipient_key = arbitrary_user_key(&mut context);
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let source_chain = "SOUR".repeat(64);
+        let deploy_item = bridge_out_for_transfer(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+            &source_chain,
+            TEST_TRANSACTION_ID(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_out_for_transfer(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 1_000,
+            &source_chain,
+            TEST_TRANSACTION_ID(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::TransferAlreadyProcessed).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn set_token_limits_invalid_values() {
+        /*
+            Scenario:
+            1. Set minimal "bridge_in" amount above the maximal one
+            2. Set "bridge_out" window cap without a window
+            3. Assert both fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        for deploy_item in [
+            set_bridge_in_limits(
+                bridge_hash,
+                context.account.address,
+                token_package_hash,
+                U256::one() * 2,
+                U256::one(),
+            ),
+            set_bridge_out_limits(
+                bridge_hash,
+                context.account.address,
+                token_package_hash,
+                U256::zero(),
+                U256::one(),
+                0,
+            ),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error: ApiError = ContractError(BridgeError::InvalidTokenLimits).into();
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn set_token_limits_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "set_bridge_in_limits" and "set_bridge_out_limits" entrypoints from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        for deploy_item in [
+            set_bridge_in_limits(
+                bridge_hash,
+                user.address,
+                token_package_hash,
+                U256::zero(),
+                U256::zero(),
+            ),
+            set_bridge_out_limits(
+                bridge_hash,
+                user.address,
+                token_package_hash,
+                U256::zero(),
+                U256::zero(),
+                0,
+            ),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn token_stable_commission_percent_override() {
+        /*
+            Scenario:
+            1. Call "set_token_stable_commission_percent" entrypoint for the token
+            2. Call "bridge_in" entrypoint and assert that the override is charged and reported
+            3. Call "clear_token_stable_commission_percent" entrypoint for the token
+            4. Call "bridge_in" entrypoint and assert that the global percent is charged and reported
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let override_percent = TEST_COMMISSION_PERCENT();
+        let deploy_item = set_token_stable_commission_percent(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            override_percent,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        if let BridgeEvent::FundsIn {
+            stable_commission_percent,
+            ..
+        } = event
+        {
+            assert_eq!(stable_commission_percent, override_percent);
+        } else {
+            panic!("Expected BridgeEvent::FundsIn but got {event:?}");
+        }
+
+        let overridden_commission =
+            TEST_AMOUNT() * override_percent / HUNDRED_PERCENT_BPS + TEST_GAS_COMMISSION();
+        let commission =
+            query_commission_pool(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(commission, overridden_commission);
+
+        let deploy_item = clear_token_stable_commission_percent(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        if let BridgeEvent::FundsIn {
+            stable_commission_percent,
+            ..
+        } = event
+        {
+            assert_eq!(stable_commission_percent, TEST_STABLE_COMMISSION_PERCENT());
+        } else {
+            panic!("Expected BridgeEvent::FundsIn but got {event:?}");
+        }
+
+        let commission =
+            query_commission_pool(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(
+            commission,
+            overridden_commission + expected_total_commission()
+        );
+    }
+
+    #[test]
+    fn commission_getters_return_pool_values() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract
+            2. Call "get_commission_pool" and "get_available_liquidity" entrypoints and assert
+               the collected commission and the rest of the pool
+            3. Call "get_total_commission" entrypoint and assert the global percent is applied
+            4. Set a stable commission percent override for the token and assert it is applied
+            5. Clear the override and assert the global percent is applied again
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let token_args = || runtime_args! { PARAM_TOKEN_CONTRACT => token_package_hash };
+        let total_commission_args = || {
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+                PARAM_AMOUNT => TEST_AMOUNT(),
+                PARAM_GAS_COMMISSION => TEST_GAS_COMMISSION(),
+            }
+        };
+
+        // 2. Commission pool and available liquidity
+        let commission: U256 = query_entry_point(
+            &mut context,
+            bridge_hash,
+            EP_GET_COMMISSION_POOL,
+            token_args(),
+        );
+        assert_eq!(commission, expected_total_commission());
+
+        let liquidity: U256 = query_entry_point(
+            &mut context,
+            bridge_hash,
+            EP_GET_AVAILABLE_LIQUIDITY,
+            token_args(),
+        );
+        assert_eq!(liquidity, TEST_AMOUNT() - expected_total_commission());
+
+        // 3. Global stable commission percent
+        let total_commission: U256 = query_entry_point(
+            &mut context,
+            bridge_hash,
+            EP_GET_TOTAL_COMMISSION,
+            total_commission_args(),
+        );
+        assert_eq!(total_commission, expected_total_commission());
+
+        // 4. Per-token override
+        let override_percent = TEST_COMMISSION_PERCENT();
+        let deploy_item = set_token_stable_commission_percent(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            override_percent,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let total_commission: U256 = query_entry_point(
+            &mut context,
+            bridge_hash,
+            EP_GET_TOTAL_COMMISSION,
+            total_commission_args(),
+        );
+        assert_eq!(
+            total_commission,
+            TEST_AMOUNT() * override_percent / HUNDRED_PERCENT_BPS + TEST_GAS_COMMISSION()
+        );
+
+        // 5. Fallback to the global percent
+        let deploy_item = clear_token_stable_commission_percent(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let total_commission: U256 = query_entry_point(
+            &mut context,
+            bridge_hash,
+            EP_GET_TOTAL_COMMISSION,
+            total_commission_args(),
+        );
+        assert_eq!(total_commission, expected_total_commission());
+    }
+
+    #[test]
+    fn set_token_stable_commission_percent_invalid_value() {
+        /*
+            Scenario:
+            1. Call "set_token_stable_commission_percent" entrypoint with too big percent
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = set_token_stable_commission_percent(
+            bridge_hash,
+            context.account.address,
+            token_package_hash,
+            U256::from(MAX_STABLE_COMMISSION_BPS + 1),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::InvalidCommissionPercent).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn token_stable_commission_percent_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "set_token_stable_commission_percent" and "clear_token_stable_commission_percent"
+               entrypoints from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        for deploy_item in [
+            set_token_stable_commission_percent(
+                bridge_hash,
+                user.address,
+                token_package_hash,
+                TEST_COMMISSION_PERCENT(),
+            ),
+            clear_token_stable_commission_percent(bridge_hash, user.address, token_package_hash),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    fn test_signer_set() -> Vec<String> {
+        test_public_keys()
+            .iter()
+            .map(|public_key| public_key.to_string())
+            .collect()
+    }
+
+    fn test_signer_hexes() -> Vec<String> {
+        test_public_keys().into_iter().map(signer_hex).collect()
+    }
+
+    #[test]
+    fn set_signer_set_happy_path() {
+        /*
+            Scenario:
+            1. Call "set_signer_set" entrypoint with three signers and a threshold of two
+            2. Assert that the signer set is established
+            3. Verify expected event
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let deploy_item =
+            set_signer_set(bridge_hash, context.account.address, test_signer_set(), 2);
+        let res: SignerSet = get_context(&mut context, deploy_item)
+            .expect_success()
+            .get_value(bridge_hash, "signer_keys");
+
+        assert_eq!(res.signers, stored_signers(&test_signer_set()));
+        assert_eq!(res.threshold, 2);
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(
+            event,
+            BridgeEvent::SignerSetUpdated {
+                signers: test_signer_hexes(),
+                threshold: 2,
+            }
+        );
+    }
+
+    #[test]
+    fn bridge_in_signer_threshold() {
+        /*
+            Scenario:
+            1. Establish a 2-of-2 signer set
+            2. Call "bridge_in" entrypoint signed by a single signer and assert fail
+            3. Call "bridge_in" entrypoint signed twice by the same signer and assert fail
+            4. Call "bridge_in" entrypoint signed by a key outside of the set and assert fail
+            5. Call "bridge_in" entrypoint signed by both signers and assert success
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = set_signer_set(
+            bridge_hash,
+            context.account.address,
+            test_signer_set()[1..].to_vec(),
+            2,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let [outsider_key, first_key, second_key] = test_signer_secret_keys();
+
+        let account_address = context.account.address;
+        let bridge_in_deploy = |signer_secret_keys: &[&str]| {
+            bridge_in_signed_by(
+                bridge_hash,
+                token_package_hash,
+                account_address,
+                TEST_AMOUNT(),
+                TEST_CORRECT_DEADLINE(),
+                TEST_NONCE(),
+                TEST_TRANSACTION_ID(),
+                TEST_GAS_COMMISSION(),
+                Vec::new(),
+                signer_secret_keys,
+            )
+        };
+
+        for (signer_secret_keys, bridge_error) in [
+            (vec![first_key], BridgeError::NotEnoughSignatures),
+            (vec![first_key, first_key], BridgeError::DuplicateSigner),
+            (vec![first_key, outsider_key], BridgeError::InvalidSignature),
+        ] {
+            let deploy_item = bridge_in_deploy(&signer_secret_keys);
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error: ApiError = ContractError(bridge_error).into();
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+
+        let deploy_item = bridge_in_deploy(&[second_key, first_key]);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let commission =
+            query_commission_pool(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(commission, expected_total_commission());
+    }
+
+    #[test]
+    fn set_signer_set_invalid() {
+        /*
+            Scenario:
+            1. Call "set_signer_set" entrypoint with a zero threshold
+            2. Call "set_signer_set" entrypoint with a threshold above the number of signers
+            3. Call "set_signer_set" entrypoint with a repeated signer
+            4. Assert that all calls failed
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let repeated_signer = vec![test_public_key().to_string(), test_public_key().to_string()];
+
+        for (signers, threshold) in [
+            (test_signer_set(), 0),
+            (test_signer_set(), 4),
+            (repeated_signer, 1),
+        ] {
+            let deploy_item =
+                set_signer_set(bridge_hash, context.account.address, signers, threshold);
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error: ApiError = ContractError(BridgeError::InvalidSignerSet).into();
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn set_signer_set_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "set_signer_set" entrypoint from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+        let deploy_item = set_signer_set(bridge_hash, user.address, test_signer_set(), 2);
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn granted_relayer_role_happy_path() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract
+            2. Call "grant_role" entrypoint to grant the relayer role to another account
+            3. Verify expected event
+            4. Call "claim_role" entrypoint from that account and assert the access URef is stored
+            5. Call "bridge_out" entrypoint from that account and assert success
+            6. Call "withdraw_commission" entrypoint from that account and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let relayer = arbitrary_user(&mut context);
+
+        let deploy_item = grant_role(
+            bridge_hash,
+            context.account.address,
+            ROLE_RELAYER,
+            relayer.key(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(
+            event,
+            BridgeEvent::RoleGranted {
+                role: ROLE_RELAYER.to_string(),
+                account: relayer.key(),
+            }
+        );
+
+        let deploy_item = claim_role(bridge_hash, relayer.address, ROLE_RELAYER);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let relayer_account = context
+            .builder
+            .query(None, relayer.key(), &[])
+            .unwrap()
+            .as_account()
+            .cloned()
+            .unwrap();
+        assert!(relayer_account
+            .named_keys()
+            .contains_key(&role_access_key_name(ROLE_RELAYER)));
+
+        let deploy_item = bridge_out(
+            bridge_hash,
+            token_package_hash,
+            relayer.address,
+            relayer.key(),
+            U256::one() * 1_000,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = withdraw_commission(
+            bridge_hash,
+            token_package_hash,
+            relayer.address,
+            relayer.key(),
+            U256::one() * 1_000,
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn revoked_relayer_role_blocks_bridge_out() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint to transfer tokens in contract
+            2. Grant and claim the relayer role for another account
+            3. Call "revoke_role" entrypoint
+            4. Verify expected event
+            5. Call "bridge_out" entrypoint from that account and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, _) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let relayer = arbitrary_user(&mut context);
+
+        let deploy_item = grant_role(
+            bridge_hash,
+            context.account.address,
+            ROLE_RELAYER,
+            relayer.key(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = claim_role(bridge_hash, relayer.address, ROLE_RELAYER);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = revoke_role(
+            bridge_hash,
+            context.account.address,
+            ROLE_RELAYER,
+            relayer.key(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(
+            event,
+            BridgeEvent::RoleRevoked {
+                role: ROLE_RELAYER.to_string(),
+                account: relayer.key(),
+            }
+        );
+
+        let deploy_item = bridge_out(
+            bridge_hash,
+            token_package_hash,
+            relayer.address,
+            relayer.key(),
+            U256::one() * 1_000,
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn grant_role_invalid() {
+        /*
+            Scenario:
+            1. Call "grant_role" entrypoint with an unknown role and assert fail
+            2. Call "grant_role" entrypoint twice for the same role and account and assert fail
+            3. Call "revoke_role" entrypoint for a role that is not granted and assert fail
+            4. Call "claim_role" entrypoint for a role that is not granted and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        let deploy_item = grant_role(bridge_hash, context.account.address, "operator", user.key());
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::UnknownRole).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = grant_role(bridge_hash, context.account.address, ROLE_ADMIN, user.key());
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = grant_role(bridge_hash, context.account.address, ROLE_ADMIN, user.key());
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::RoleAlreadyGranted).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = revoke_role(
+            bridge_hash,
+            context.account.address,
+            ROLE_RELAYER,
+            user.key(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::RoleNotGranted).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = claim_role(bridge_hash, user.address, ROLE_RELAYER);
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::RoleNotGranted).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn grant_role_called_by_non_admin() {
+        /*
+            Scenario:
+            1. Call "grant_role" entrypoint from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+        let deploy_item = grant_role(bridge_hash, user.address, ROLE_ADMIN, user.key());
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn upgrade_keeps_state_and_used_nonces() {
+        /*
+            Scenario:
+            1. Call "bridge_in" entrypoint in bridge contract
+            2. Upgrade the bridge contract, disabling the old version
+            3. Assert that the new version belongs to the same package, reuses every named key of
+               the old version and keeps the commission pool
+            4. Assert that the role groups exist and the operator group grants access to no one
+            5. Call "bridge_in" entrypoint of the new version with the same nonce and assert fail
+            6. Call "bridge_in" entrypoint of the old version and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash, bridge_hash, bridge_package_hash) =
+            deploy_bridge_and_erc20(&mut context.builder, context.account.address);
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let old_named_keys = context
+            .builder
+            .get_contract(bridge_hash)
+            .unwrap()
+            .named_keys()
+            .clone();
+
+        let (new_bridge_hash, new_bridge_package_hash) =
+            upgrade_bridge(&mut context.builder, context.account.address, true);
+
+        assert_ne!(new_bridge_hash, bridge_hash);
+        assert_eq!(new_bridge_package_hash, bridge_package_hash);
+
+        let new_named_keys = context
+            .builder
+            .get_contract(new_bridge_hash)
+            .unwrap()
+            .named_keys()
+            .clone();
+        assert_eq!(new_named_keys, old_named_keys);
+
+        let commission =
+            query_commission_pool(&mut context.builder, new_bridge_hash, token_package_hash);
+        assert_eq!(commission, expected_total_commission());
+
+        let groups = context
+            .builder
+            .get_contract_package(bridge_package_hash)
+            .unwrap()
+            .groups()
+            .clone();
+        for role in ROLES {
+            assert!(groups.contains_key(&Group::new(role)));
+        }
+        assert!(groups
+            .get(&Group::new(GROUP_OPERATOR))
+            .map_or(true, |urefs| urefs.is_empty()));
+
+        let deploy_item = bridge_in(
+            new_bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::AlreadyUsedSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE() + U128::one(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        execution_context(&mut context, deploy_item).expect_failure();
+    }
+
+    fn native_bridge_in(context: &mut TestContext, bridge_hash: ContractHash, nonce: U128) {
+        let deploy_item = bridge_in_native(
+            bridge_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            nonce,
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+        );
+        get_context(context, deploy_item).expect_success();
+    }
+
+    #[test]
+    fn bridge_in_native_happy_path() {
+        /*
+            Scenario:
+
+            1. Run the session which moves CSPR from the caller's main purse and calls "bridge_in_native"
+            2. Assert that the bridge purse received the expected amount of motes
+            3. Assert that commission added in the native pool
+            4. Verify expected event
+            5. Run the session again with the same nonce and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        native_bridge_in(&mut context, bridge_hash, TEST_NONCE());
+
+        let funds_in_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+
+        if let BridgeEvent::FundsIn {
+            token_contract,
+            destination_chain,
+            destination_address,
+            amount,
+            gas_commission,
+            stable_commission_percent,
+            nonce,
+            transaction_id,
+            sender,
+        } = funds_in_event
+        {
+            assert_eq!(token_contract, NATIVE_TOKEN_CONTRACT);
+            assert_eq!(destination_chain, TEST_DESTINATION_CHAIN());
+            assert_eq!(destination_address, TEST_DESTINATION_ADDRESS());
+            assert_eq!(amount, TEST_AMOUNT());
+            assert_eq!(gas_commission, TEST_GAS_COMMISSION());
+            assert_eq!(stable_commission_percent, TEST_STABLE_COMMISSION_PERCENT());
+            assert_eq!(nonce, TEST_NONCE());
+            assert_eq!(transaction_id, TEST_TRANSACTION_ID());
+            assert_eq!(sender, Key::Account(context.account.address));
+        } else {
+            panic!("Expected BridgeEvent::FundsIn but got {funds_in_event:?}");
+        }
+
+        let bridge_balance = query_native_purse_balance(&mut context.builder, bridge_hash);
+        assert_eq!(bridge_balance, U512::from(TEST_AMOUNT().as_u128()));
+
+        let commission = query_native_commission_pool(&mut context.builder, bridge_hash);
+        assert_eq!(commission, expected_total_commission());
+
+        let deploy_item = bridge_in_native(
+            bridge_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::AlreadyUsedSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn bridge_out_native_happy_path() {
+        /*
+            Scenario:
+
+            1. Run the session which calls "bridge_in_native"
+            2. Call "bridge_out_native" entrypoint in bridge contract
+            3. Assert that the bridge purse lost the expected amount of motes
+            4. Assert that the recipient main purse received the expected amount of motes
+            5. Verify expected event
+            6. Call "bridge_out_native" for more than the bridge pool and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let recipient = arbitrary_user(&mut context);
+        let recipient_key = recipient.key();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        native_bridge_in(&mut context, bridge_hash, TEST_NONCE());
+
+        let recipient_balance_before =
+            query_main_purse_balance(&mut context.builder, recipient.address);
+
+        let deploy_item = bridge_out_native(
+            bridge_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 900_000_000_000u64,
+            U256::one(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let bridge_out_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+
+        if let BridgeEvent::FundsOut {
+            token_contract,
+            source_chain,
+            source_address,
+            recipient,
+            amount,
+            transaction_id,
+        } = bridge_out_event
+        {
+            assert_eq!(token_contract, NATIVE_TOKEN_CONTRACT);
+            assert_eq!(source_chain, "SOUR");
+            assert_eq!(source_address, "SOURADDR");
+            assert_eq!(recipient, recipient_key);
+            assert_eq!(amount, U256::one() * 900_000_000_000u64);
+            assert_eq!(transaction_id, U256::one());
+        } else {
+            panic!("Expected bridge out event, but got {bridge_out_event:?}");
+        }
+
+        let bridge_balance = query_native_purse_balance(&mut context.builder, bridge_hash);
+        let recipient_balance_after =
+            query_main_purse_balance(&mut context.builder, recipient.address);
+
+        assert_eq!(bridge_balance, U512::one() * 100_000_000_000u64);
+        assert_eq!(
+            recipient_balance_after - recipient_balance_before,
+            U512::one() * 900_000_000_000u64
+        );
+
+        let deploy_item = bridge_out_native(
+            bridge_hash,
+            context.account.address,
+            recipient_key,
+            U256::one() * 100_000_000_000u64,
+            U256::one() * 2,
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError = ContractError(BridgeError::AmountExceedBridgePool).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn bridge_out_native_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "bridge_out_native" entrypoint by an account without the relayer role
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        native_bridge_in(&mut context, bridge_hash, TEST_NONCE());
+
+        let user = arbitrary_user(&mut context);
+
+        let deploy_item = bridge_out_native(
+            bridge_hash,
+            user.address,
+            user.key(),
+            U256::one() * 1_000,
+            U256::one(),
+        );
+
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn withdraw_native_commission_test() {
+        /*
+            Scenario:
+            1. Run the session which calls "bridge_in_native"
+            2. Call "withdraw_native_commission" entrypoint for more than the pool and assert fail
+            3. Call "withdraw_native_commission" entrypoint for the whole pool
+            4. Assert received commission, native pool and bridge purse balance
+            5. Assert event
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        native_bridge_in(&mut context, bridge_hash, TEST_NONCE());
+
+        let recipient = arbitrary_user(&mut context);
+        let recipient_balance_before =
+            query_main_purse_balance(&mut context.builder, recipient.address);
+
+        let deploy_item = withdraw_native_commission(
+            bridge_hash,
+            context.account.address,
+            recipient.key(),
+            expected_total_commission() * 2,
+        );
+        let error = execution_error(&mut context, deploy_item);
+
+        let expected_error: ApiError =
+            ContractError(BridgeError::AmountExceedCommissionPool).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = withdraw_native_commission(
+            bridge_hash,
+            context.account.address,
+            recipient.key(),
+            expected_total_commission(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let withdraw_commission_event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+
+        let commission_after = query_native_commission_pool(&mut context.builder, bridge_hash);
+        let bridge_balance = query_native_purse_balance(&mut context.builder, bridge_hash);
+        let recipient_balance_after =
+            query_main_purse_balance(&mut context.builder, recipient.address);
+
+        assert_eq!(commission_after, U256::zero());
+        assert_eq!(
+            bridge_balance,
+            U512::from((TEST_AMOUNT() - expected_total_commission()).as_u128())
+        );
+        assert_eq!(
+            recipient_balance_after - recipient_balance_before,
+            U512::from(expected_total_commission().as_u128())
+        );
+
+        if let BridgeEvent::WithdrawCommission {
+            token_contract,
+            amount,
+            recipient: event_recipient,
+        } = withdraw_commission_event
+        {
+            assert_eq!(token_contract, NATIVE_TOKEN_CONTRACT);
+            assert_eq!(amount, expected_total_commission());
+            assert_eq!(event_recipient, recipient.key());
+        } else {
+            panic!("Expected withdraw commission event, but got {withdraw_commission_event:?}");
+        }
+    }
+
+    #[test]
+    fn timelock_change_happy_path() {
+        /*
+            Scenario:
+            1. Call "set_timelock_delay" entrypoint to activate the timelock
+            2. Call "set_signer_set" entrypoint directly and assert fail
+            3. Call "propose_change" entrypoint with a new signer set and verify expected event
+            4. Call "execute_change" entrypoint before the eta and assert fail
+            5. Call "execute_change" entrypoint at the eta
+            6. Assert that the signer set is changed and verify expected event
+            7. Call "execute_change" entrypoint again and assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let delay = 1000;
+        let deploy_item = set_timelock_delay(bridge_hash, context.account.address, delay);
+        let res: u64 = get_context(&mut context, deploy_item)
+            .expect_success()
+            .get_value(bridge_hash, "timelock_delay");
+        assert_eq!(res, delay);
+
+        let deploy_item =
+            set_signer_set(bridge_hash, context.account.address, test_signer_set(), 2);
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::TimelockActive).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let change = Change::SetSignerSet {
+            signers: test_signer_set(),
+            threshold: 2,
+        };
+        let deploy_item = propose_change(bridge_hash, context.account.address, change.clone());
+        get_context(&mut context, deploy_item).expect_success();
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(
+            event,
+            BridgeEvent::ChangeProposed {
+                id: 0,
+                change,
+                eta: TEST_BLOCK_TIME + delay,
+            }
+        );
+
+        let deploy_item = execute_change(bridge_hash, context.account.address, 0);
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::TimelockNotElapsed).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = execute_change(bridge_hash, context.account.address, 0);
+        context
+            .builder
+            .exec(
+                ExecuteRequestBuilder::from_deploy_item(deploy_item)
+                    .with_block_time(TEST_BLOCK_TIME + delay)
+                    .build(),
+            )
+            .commit()
+            .expect_success();
+
+        let res: SignerSet = context.builder.get_value(bridge_hash, "signer_keys");
+        assert_eq!(res.signers, stored_signers(&test_signer_set()));
+        assert_eq!(res.threshold, 2);
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(event, BridgeEvent::ChangeExecuted { id: 0 });
+
+        let deploy_item = execute_change(bridge_hash, context.account.address, 0);
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::UnknownProposal).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+    }
+
+    #[test]
+    fn timelock_cancel_change() {
+        /*
+            Scenario:
+            1. Call "set_timelock_delay" entrypoint to activate the timelock
+            2. Call "propose_change" entrypoint with a new stable commission percent
+            3. Call "cancel_change" entrypoint and verify expected event
+            4. Call "execute_change" entrypoint at the eta and assert fail
+            5. Assert that the stable commission percent is unchanged
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let delay = 1000;
+        let deploy_item = set_timelock_delay(bridge_hash, context.account.address, delay);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = propose_change(
+            bridge_hash,
+            context.account.address,
+            Change::SetStableCommissionPercent {
+                value: U256::one() * 500,
+            },
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = cancel_change(bridge_hash, context.account.address, 0);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(event, BridgeEvent::ChangeCancelled { id: 0 });
+
+        let deploy_item = execute_change(bridge_hash, context.account.address, 0);
+        let error = context
+            .builder
+            .exec(
+                ExecuteRequestBuilder::from_deploy_item(deploy_item)
+                    .with_block_time(TEST_BLOCK_TIME + delay)
+                    .build(),
+            )
+            .commit()
+            .expect_failure()
+            .get_error()
+            .expect("should have error");
+        let expected_error: ApiError = ContractError(BridgeError::UnknownProposal).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let res: U256 = context
+            .builder
+            .get_value(bridge_hash, PARAM_STABLE_COMMISSION_PERCENT);
+        assert_eq!(res, U256::from(DEFAULT_STABLE_COMMISSION_BPS));
+    }
+
+    #[test]
+    fn timelock_called_by_non_owner() {
+        /*
+            Scenario:
+            1. Call "set_timelock_delay", "propose_change", "execute_change" and "cancel_change"
+               entrypoints from another account
+            2. Assert fail
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge(&mut context.builder, context.account.address);
+
+        let user = arbitrary_user(&mut context);
+
+        for deploy_item in [
+            set_timelock_delay(bridge_hash, user.address, 1000),
+            propose_change(
+                bridge_hash,
+                user.address,
+                Change::SetTimelockDelay { delay: 0 },
+            ),
+            execute_change(bridge_hash, user.address, 0),
+            cancel_change(bridge_hash, user.address, 0),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+
+            let expected_error = engine_state::Error::Exec(execution::Error::InvalidContext);
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+    }
+
+    #[test]
+    fn timelock_gates_pool_and_token_changes() {
+        /*
+            Scenario:
+            1. Install the bridge with the default timelock delay
+            2. Call "add_token" entrypoint and assert fail, then propose and execute the addition
+            3. Call "bridge_in" entrypoint and tighten the token limits directly
+            4. Call "rebalance_liquidity", "set_token_mode" entrypoints and loosen the token
+               limits with "set_bridge_out_limits" and "set_bridge_in_limits", assert fail
+            5. Propose the same changes and execute them once the timelock elapsed
+            6. Assert treasury balance, token limits and token config
+        */
+
+        let mut context = setup_context();
+
+        let (token_hash, token_package_hash) =
+            deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge_with_timelock_delay(
+            &mut context.builder,
+            context.account.address,
+            DEFAULT_TIMELOCK_DELAY,
+        );
+
+        let account = context.account.address;
+        let expected_error: ApiError = ContractError(BridgeError::TimelockActive).into();
+
+        let deploy_item = add_token(bridge_hash, account, token_package_hash);
+        let error = execution_error(&mut context, deploy_item);
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let change = Change::AddToken {
+            token_contract: token_package_hash,
+        };
+        let deploy_item = propose_change(bridge_hash, account, change);
+        get_context(&mut context, deploy_item).expect_success();
+        let deploy_item = execute_change(bridge_hash, account, 0);
+        execution_context_at(
+            &mut context,
+            deploy_item,
+            TEST_BLOCK_TIME + DEFAULT_TIMELOCK_DELAY,
+        )
+        .expect_success();
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            account,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        for deploy_item in [
+            set_bridge_out_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::one() * 500,
+                U256::zero(),
+                0,
+            ),
+            set_bridge_in_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::one() * 100,
+                U256::one() * 1_000,
+            ),
+        ] {
+            get_context(&mut context, deploy_item).expect_success();
+        }
+
+        let pool = TEST_AMOUNT() - expected_total_commission();
+
+        for deploy_item in [
+            rebalance_liquidity(bridge_hash, token_package_hash, account, pool),
+            set_bridge_out_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::one() * 1_000,
+                U256::zero(),
+                0,
+            ),
+            set_bridge_in_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::zero(),
+                U256::zero(),
+            ),
+            set_token_mode(
+                bridge_hash,
+                account,
+                token_package_hash,
+                TokenMode::MintBurn as u8,
+            ),
+        ] {
+            let error = execution_error(&mut context, deploy_item);
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+
+        let treasury_key = arbitrary_user_key(&mut context);
+        set_treasury(&mut context, bridge_hash, treasury_key);
+
+        let changes = [
+            Change::RebalanceLiquidity {
+                token_contract: token_package_hash,
+                amount: pool,
+            },
+            Change::SetBridgeOutLimits {
+                token_contract: token_package_hash,
+                max_amount: U256::one() * 1_000,
+                outflow_cap: U256::zero(),
+                outflow_window: 0,
+            },
+            Change::SetBridgeInLimits {
+                token_contract: token_package_hash,
+                min_amount: U256::zero(),
+                max_amount: U256::zero(),
+            },
+            Change::SetTokenMode {
+                token_contract: token_package_hash,
+                mode: TokenMode::MintBurn as u8,
+            },
+        ];
+        for change in changes {
+            let deploy_item = propose_change(bridge_hash, account, change);
+            get_context(&mut context, deploy_item).expect_success();
+        }
+        for id in 2..=5 {
+            let deploy_item = execute_change(bridge_hash, account, id);
+            execution_context_at(
+                &mut context,
+                deploy_item,
+                TEST_BLOCK_TIME + DEFAULT_TIMELOCK_DELAY,
+            )
+            .expect_success();
+        }
+
+        let treasury_balance = query_balance(&mut context.builder, token_hash, &treasury_key);
+        assert_eq!(treasury_balance, pool);
+
+        let limits = query_token_limits(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(limits.max_bridge_out, U256::one() * 1_000);
+        assert_eq!(limits.min_bridge_in, U256::zero());
+        assert_eq!(limits.max_bridge_in, U256::zero());
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(config.mode, TokenMode::MintBurn);
+        assert!(config.enabled);
+    }
+
+    #[test]
+    fn timelock_does_not_delay_revocations_and_restrictions() {
+        /*
+            Scenario:
+            1. Install the bridge with the default timelock delay
+            2. Propose and execute a relayer role grant
+            3. Call "revoke_role" and "remove_token" entrypoints directly and assert success
+            4. Tighten the outflow cap and the per-transfer limits directly and assert success
+            5. Shorten the outflow window and raise the outflow cap directly and assert fail
+            6. Assert token limits and token config
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash) = deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge_with_timelock_delay(
+            &mut context.builder,
+            context.account.address,
+            DEFAULT_TIMELOCK_DELAY,
+        );
+
+        let account = context.account.address;
+        let relayer = arbitrary_user(&mut context);
+
+        let change = Change::GrantRole {
+            role: ROLE_RELAYER.to_string(),
+            account: relayer.key(),
+        };
+        let deploy_item = propose_change(bridge_hash, account, change);
+        get_context(&mut context, deploy_item).expect_success();
+        let deploy_item = execute_change(bridge_hash, account, 0);
+        execution_context_at(
+            &mut context,
+            deploy_item,
+            TEST_BLOCK_TIME + DEFAULT_TIMELOCK_DELAY,
+        )
+        .expect_success();
+
+        let deploy_item = revoke_role(bridge_hash, account, ROLE_RELAYER, relayer.key());
+        get_context(&mut context, deploy_item).expect_success();
+
+        let event = read_contract_event::<_, BridgeEvent>(
+            &mut context.builder,
+            bridge_hash,
+            "event_trigger",
+        );
+        assert_eq!(
+            event,
+            BridgeEvent::RoleRevoked {
+                role: ROLE_RELAYER.to_string(),
+                account: relayer.key(),
+            }
+        );
+
+        for deploy_item in [
+            remove_token(bridge_hash, account, token_package_hash),
+            set_bridge_out_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::zero(),
+                U256::one() * 1_500,
+                100,
+            ),
+            set_bridge_out_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::one() * 1_000,
+                U256::one() * 1_000,
+                200,
+            ),
+            set_bridge_in_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::one() * 100,
+                U256::one() * 10_000,
+            ),
+        ] {
+            get_context(&mut context, deploy_item).expect_success();
+        }
+
+        for (max_amount, outflow_cap, outflow_window) in [
+            (U256::one() * 1_000, U256::one() * 1_000, 100),
+            (U256::one() * 1_000, U256::one() * 2_000, 200),
+            (U256::zero(), U256::one() * 1_000, 200),
+        ] {
+            let deploy_item = set_bridge_out_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                max_amount,
+                outflow_cap,
+                outflow_window,
+            );
+            let error = execution_error(&mut context, deploy_item);
+            let expected_error: ApiError = ContractError(BridgeError::TimelockActive).into();
+            assert_eq!(error.to_string(), expected_error.to_string());
+        }
+
+        let limits = query_token_limits(&mut context.builder, bridge_hash, token_package_hash);
+        assert_eq!(limits.max_bridge_out, U256::one() * 1_000);
+        assert_eq!(limits.outflow_cap, U256::one() * 1_000);
+        assert_eq!(limits.outflow_window, 200);
+        assert_eq!(limits.min_bridge_in, U256::one() * 100);
+        assert_eq!(limits.max_bridge_in, U256::one() * 10_000);
+
+        let config = query_token_config(&mut context.builder, bridge_hash, token_package_hash);
+        assert!(!config.enabled);
+    }
+
+    #[test]
+    fn ces_events_are_appended() {
+        /*
+            Scenario:
+            1. Install the bridge in the CES event mode and assert the CES named keys
+            2. Call "pause" and "unpause" entrypoints
+            3. Call "execute_change" entrypoint, which emits two events in one deploy
+            4. Assert that every event is readable by its index
+        */
+
+        let mut context = setup_context();
+
+        let (bridge_hash, _) = deploy_bridge_with_event_mode(
+            &mut context.builder,
+            context.account.address,
+            EventMode::Ces,
+        );
+
+        let version: String = context
+            .builder
+            .get_value(bridge_hash, EVENTS_CES_VERSION_UREF_NAME);
+        assert_eq!(version, CES_VERSION);
+        let schemas: Schemas = context
+            .builder
+            .get_value(bridge_hash, EVENTS_SCHEMA_UREF_NAME);
+        assert_eq!(schemas, event_schemas());
+        let length: u32 = context
+            .builder
+            .get_value(bridge_hash, EVENTS_LENGTH_UREF_NAME);
+        assert_eq!(length, 0);
+
+        let deploy_item = pause(bridge_hash, context.account.address);
+        get_context(&mut context, deploy_item).expect_success();
+        let deploy_item = unpause(bridge_hash, context.account.address);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let change = Change::SetSignerSet {
+            signers: test_signer_set(),
+            threshold: 2,
+        };
+        let deploy_item = propose_change(bridge_hash, context.account.address, change.clone());
+        get_context(&mut context, deploy_item).expect_success();
+        let deploy_item = execute_change(bridge_hash, context.account.address, 0);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let length: u32 = context
+            .builder
+            .get_value(bridge_hash, EVENTS_LENGTH_UREF_NAME);
+        assert_eq!(length, 5);
+
+        let expected_events = vec![
+            BridgeEvent::Paused,
+            BridgeEvent::Unpaused,
+            BridgeEvent::ChangeProposed {
+                id: 0,
+                change,
+                eta: TEST_BLOCK_TIME,
+            },
+            BridgeEvent::SignerSetUpdated {
+                signers: test_signer_hexes(),
+                threshold: 2,
+            },
+            BridgeEvent::ChangeExecuted { id: 0 },
+        ];
+        for (index, expected_event) in expected_events.into_iter().enumerate() {
+            let event = read_ces_event(&mut context.builder, bridge_hash, index as u32);
+            assert_eq!(event, Some(expected_event));
+        }
+        assert_eq!(read_ces_event(&mut context.builder, bridge_hash, 5), None);
+    }
+
+    #[test]
+    fn admin_changes_fire_events() {
+        /*
+            Scenario:
+            1. Install the bridge in the CES event mode
+            2. Call "set_signer", token, commission, limit, horizon and timelock entrypoints
+            3. Assert that each call fired exactly one event describing the change
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash) = deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge_with_event_mode(
+            &mut context.builder,
+            context.account.address,
+            EventMode::Ces,
+        );
+
+        let account = context.account.address;
+        let deploy_items = [
+            set_test_signer(bridge_hash, account, test_public_key()),
+            add_token(bridge_hash, account, token_package_hash),
+            set_token_stable_commission_percent(
+                bridge_hash,
+                account,
+                token_package_hash,
+                TEST_COMMISSION_PERCENT(),
+            ),
+            clear_token_stable_commission_percent(bridge_hash, account, token_package_hash),
+            set_bridge_in_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::one(),
+                U256::one() * 1_000,
+            ),
+            set_bridge_out_limits(
+                bridge_hash,
+                account,
+                token_package_hash,
+                U256::one() * 500,
+                U256::one() * 1_500,
+                100,
+            ),
+            set_token_mode(
+                bridge_hash,
+                account,
+                token_package_hash,
+                TokenMode::MintBurn as u8,
+            ),
+            remove_token(bridge_hash, account, token_package_hash),
+            set_max_deadline_horizon(bridge_hash, account, 3600),
+            set_timelock_delay(bridge_hash, account, 1000),
+        ];
+        for deploy_item in deploy_items {
+            get_context(&mut context, deploy_item).expect_success();
+        }
+
+        let expected_events = vec![
+            BridgeEvent::SignerChanged {
+                old: signer_hex(test_public_key()),
+                new: signer_hex(test_public_key()),
+            },
+            BridgeEvent::TokenConfigChanged {
+                token_contract: token_package_hash,
+                enabled: true,
+                mode: TokenMode::Lock as u8,
+            },
+            BridgeEvent::TokenStableCommissionChanged {
+                token_contract: token_package_hash,
+                old: None,
+                new: Some(TEST_COMMISSION_PERCENT()),
+            },
+            BridgeEvent::TokenStableCommissionChanged {
+                token_contract: token_package_hash,
+                old: Some(TEST_COMMISSION_PERCENT()),
+                new: None,
+            },
+            BridgeEvent::TokenLimitsChanged {
+                token_contract: token_package_hash,
+                min_bridge_in: U256::one(),
+                max_bridge_in: U256::one() * 1_000,
+                max_bridge_out: U256::zero(),
+                outflow_cap: U256::zero(),
+                outflow_window: 0,
+            },
+            BridgeEvent::TokenLimitsChanged {
+                token_contract: token_package_hash,
+                min_bridge_in: U256::one(),
+                max_bridge_in: U256::one() * 1_000,
+                max_bridge_out: U256::one() * 500,
+                outflow_cap: U256::one() * 1_500,
+                outflow_window: 100,
+            },
+            BridgeEvent::TokenConfigChanged {
+                token_contract: token_package_hash,
+                enabled: true,
+                mode: TokenMode::MintBurn as u8,
+            },
+            BridgeEvent::TokenConfigChanged {
+                token_contract: token_package_hash,
+                enabled: false,
+                mode: TokenMode::MintBurn as u8,
+            },
+            BridgeEvent::MaxDeadlineHorizonChanged {
+                old: DEFAULT_MAX_DEADLINE_HORIZON,
+                new: 3600,
+            },
+            BridgeEvent::TimelockDelayChanged { old: 0, new: 1000 },
+        ];
+
+        let length: u32 = context
+            .builder
+            .get_value(bridge_hash, EVENTS_LENGTH_UREF_NAME);
+        assert_eq!(length as usize, expected_events.len());
+
+        for (index, expected_event) in expected_events.into_iter().enumerate() {
+            let event = read_ces_event(&mut context.builder, bridge_hash, index as u32);
+            assert_eq!(event, Some(expected_event));
+        }
+    }
+
+    #[test]
+    fn eip191_bridge_in_by_address() {
+        /*
+            Scenario:
+            1. Install the bridge in the EIP-191 signature mode with an Ethereum address signer
+            2. Assert that the address is stored as the signer
+            3. Call "bridge_in" entrypoint with a 64-byte raw signature and assert it fails
+            4. Call "bridge_in" entrypoint with a 65-byte recoverable signature
+            5. Assert that bridge contract received the expected amount of tokens
+        */
+
+        let mut context = setup_context();
+
+        let (token_hash, token_package_hash) =
+            deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, bridge_package_hash) = deploy_bridge_with_signature_mode(
+            &mut context.builder,
+            context.account.address,
+            SignatureMode::Eip191,
+            &test_signer_address(),
+        );
+        let res: SignerSet = context.builder.get_value(bridge_hash, "signer_keys");
+        let signer = parse_signer(SignatureMode::Eip191, &test_signer_address()).unwrap();
+        assert_eq!(res.signers, vec![signer]);
+
+        let deploy_item = add_token(bridge_hash, context.account.address, token_package_hash);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_in(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            Vec::new(),
+        );
+        let error = execution_error(&mut context, deploy_item);
+        let expected_error: ApiError = ContractError(BridgeError::InvalidSignature).into();
+        assert_eq!(error.to_string(), expected_error.to_string());
+
+        let deploy_item = bridge_in_with_signature_mode(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            SignatureMode::Eip191,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+
+        let bridge_balance = query_balance(
+            &mut context.builder,
+            token_hash,
+            &Key::Hash(bridge_package_hash.value()),
+        );
+        assert_eq!(bridge_balance, TEST_AMOUNT());
+    }
+
+    #[test]
+    fn eip191_bridge_in_by_compressed_key() {
+        /*
+            Scenario:
+            1. Install the bridge in the EIP-191 signature mode with a compressed public key signer
+            2. Call "bridge_in" entrypoint with a 65-byte recoverable signature and assert success
+        */
+
+        let mut context = setup_context();
+
+        let (_, token_package_hash) = deploy_erc20(&mut context.builder, context.account.address);
+        let (bridge_hash, _) = deploy_bridge_with_signature_mode(
+            &mut context.builder,
+            context.account.address,
+            SignatureMode::Eip191,
+            &test_signer_compressed_key(),
+        );
+        let deploy_item = add_token(bridge_hash, context.account.address, token_package_hash);
+        get_context(&mut context, deploy_item).expect_success();
+
+        let deploy_item = bridge_in_with_signature_mode(
+            bridge_hash,
+            token_package_hash,
+            context.account.address,
+            TEST_AMOUNT(),
+            TEST_CORRECT_DEADLINE(),
+            TEST_NONCE(),
+            TEST_TRANSACTION_ID(),
+            TEST_GAS_COMMISSION(),
+            SignatureMode::Eip191,
+        );
+        get_context(&mut context, deploy_item).expect_success();
+    }
 }
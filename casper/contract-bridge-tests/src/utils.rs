@@ -8,7 +8,11 @@ use casper_engine_test_support::{
     DEFAULT_ACCOUNT_INITIAL_BALANCE, DEFAULT_GENESIS_CONFIG, DEFAULT_GENESIS_CONFIG_HASH,
     DEFAULT_PAYMENT,
 };
-use contract_util::signatures::{cook_msg_bridge_in, cook_msg_transfer_out, get_signature_bytes};
+use contract_util::signatures::{
+    check_public_key, cook_msg_bridge_in, cook_msg_transfer_out, ethereum_address,
+    get_signature_bytes, parse_signer, sign_for_mode, SignatureMode, SignerId,
+};
+use k256::elliptic_curve::sec1::ToEncodedPoint;
 
 use casper_execution_engine::{
     core::{
@@ -24,28 +28,50 @@ use casper_execution_engine::{
 
 use casper_types::{
     account::AccountHash,
-    bytesrepr::{Bytes, ToBytes},
-    runtime_args, ContractHash, ContractPackageHash, Key, Motes, PublicKey, RuntimeArgs, SecretKey,
-    StoredValue, U128, U256, U512,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    crypto, runtime_args, AsymmetricType, CLTyped, ContractHash, ContractPackageHash, Key, Motes,
+    PublicKey, RuntimeArgs, SecretKey, Signature, StoredValue, U128, U256, U512,
 };
 
+use casper_common::{
+    change::Change,
+    event::{read_ces_event as read_ces_event_by_index, BridgeEvent, NATIVE_TOKEN_CONTRACT},
+};
 use contract_bridge::entry_points::{
-    EP_BRIDGE_IN, EP_BRIDGE_OUT, EP_SET_SIGNER, EP_TRANSFER_OUT, EP_WITHDRAW_COMMISSION,
-    PARAM_AMOUNT, PARAM_COMMISSION, PARAM_DEADLINE, PARAM_DESTINATION_ADDRESS,
-    PARAM_DESTINATION_CHAIN, PARAM_GAS_COMMISSION, PARAM_NONCE, PARAM_RECIPIENT, PARAM_SIGNATURE,
-    PARAM_SIGNER, PARAM_SOURCE_ADDRESS, PARAM_SOURCE_CHAIN, PARAM_TOKEN_CONTRACT,
-    PARAM_TRANSACTION_ID,
+    EP_ADD_TOKEN, EP_BRIDGE_IN, EP_BRIDGE_IN_FROM, EP_BRIDGE_OUT, EP_BRIDGE_OUT_NATIVE,
+    EP_CANCEL_CHANGE, EP_CLAIM_ROLE, EP_CLEAR_TOKEN_STABLE_COMMISSION_PERCENT,
+    EP_DEPOSIT_LIQUIDITY, EP_EXECUTE_CHANGE, EP_GRANT_ROLE, EP_PAUSE, EP_PROPOSE_CHANGE,
+    EP_REBALANCE_LIQUIDITY, EP_REMOVE_TOKEN, EP_REVOKE_ROLE, EP_SET_BRIDGE_IN_LIMITS,
+    EP_SET_BRIDGE_OUT_LIMITS, EP_SET_MAX_DEADLINE_HORIZON, EP_SET_SIGNER, EP_SET_SIGNER_SET,
+    EP_SET_TIMELOCK_DELAY, EP_SET_TOKEN_MODE, EP_SET_TOKEN_STABLE_COMMISSION_PERCENT,
+    EP_TRANSFER_OUT, EP_UNPAUSE, EP_WITHDRAW_COMMISSION, EP_WITHDRAW_NATIVE_COMMISSION,
+    PARAM_ACCOUNT, PARAM_AMOUNT, PARAM_BRIDGE_CONTRACT, PARAM_CHAIN_NAME, PARAM_CHANGE,
+    PARAM_COMMISSION, PARAM_DEADLINE, PARAM_DELAY, PARAM_DESTINATION_ADDRESS,
+    PARAM_DESTINATION_CHAIN, PARAM_DISABLE_OLD_VERSION, PARAM_ENTRY_POINT, PARAM_EVENT_MODE,
+    PARAM_GAS_COMMISSION, PARAM_HORIZON, PARAM_MAX_AMOUNT, PARAM_MIN_AMOUNT, PARAM_MODE,
+    PARAM_NONCE, PARAM_OUTFLOW_CAP, PARAM_OUTFLOW_WINDOW, PARAM_PROPOSAL_ID, PARAM_RECIPIENT,
+    PARAM_ROLE, PARAM_SIGNATURES, PARAM_SIGNATURE_MODE, PARAM_SIGNER, PARAM_SIGNERS,
+    PARAM_SOURCE_ADDRESS, PARAM_SOURCE_CHAIN, PARAM_STABLE_COMMISSION_PERCENT, PARAM_THRESHOLD,
+    PARAM_TIMELOCK_DELAY, PARAM_TOKEN_CONTRACT, PARAM_TRANSACTION_ID,
 };
-use contract_util::event::ContractEvent;
+use contract_bridge::{
+    constants::{HUNDRED_PERCENT_BPS, NATIVE_COMMISSION_KEY_NAME, NATIVE_PURSE_KEY_NAME},
+    limits::TokenLimits,
+    token_registry::TokenConfig,
+};
+use contract_util::event::{ContractEvent, EventMode, EVENTS_DICT_NAME};
 
 const CONTRACT_ERC20_BYTES: &[u8] = include_bytes!("contract_erc20.wasm");
 const CONTRACT_BRIDGE_BYTES: &[u8] = include_bytes!("contract_bridge.wasm");
+const BRIDGE_IN_NATIVE_SESSION_BYTES: &[u8] = include_bytes!("bridge_in_native_session.wasm");
+const BRIDGE_QUERY_SESSION_BYTES: &[u8] = include_bytes!("bridge_query_session.wasm");
 
 static DEPLOY_COUNTER: AtomicUsize = AtomicUsize::new(0);
 
 use crate::constants::{
-    TEST_ACCOUNT, TEST_ACCOUNT_BALANCE, TEST_AMOUNT, TEST_BLOCK_TIME, TEST_DESTINATION_ADDRESS,
-    TEST_DESTINATION_CHAIN, TEST_GAS_COMMISSION, TEST_STABLE_COMMISSION_PERCENT,
+    TEST_ACCOUNT, TEST_ACCOUNT_BALANCE, TEST_AMOUNT, TEST_BLOCK_TIME, TEST_CHAIN_NAME,
+    TEST_DESTINATION_ADDRESS, TEST_DESTINATION_CHAIN, TEST_GAS_COMMISSION,
+    TEST_STABLE_COMMISSION_PERCENT,
 };
 
 pub fn test_public_key() -> &'static str {
@@ -56,6 +82,66 @@ pub fn test_signer_secret_key() -> &'static str {
     include_str!("config/signer_secret_key.in")
 }
 
+pub fn test_public_keys() -> [&'static str; 3] {
+    [
+        test_public_key(),
+        include_str!("config/public_key_2.in"),
+        include_str!("config/public_key_3.in"),
+    ]
+}
+
+pub fn test_signer_secret_keys() -> [&'static str; 3] {
+    [
+        test_signer_secret_key(),
+        include_str!("config/signer_secret_key_2.in"),
+        include_str!("config/signer_secret_key_3.in"),
+    ]
+}
+
+/// Hex encoded Ethereum address of `test_public_key()`, as an EIP-191 signer.
+pub fn test_signer_address() -> String {
+    let address = ethereum_address(&check_public_key(test_public_key()));
+    format!("0x{}", to_hex(&address))
+}
+
+/// Hex encoded compressed `test_public_key()`, as an EIP-191 signer.
+pub fn test_signer_compressed_key() -> String {
+    to_hex(
+        check_public_key(test_public_key())
+            .to_encoded_point(true)
+            .as_bytes(),
+    )
+}
+
+/// Ed25519 key of a signer other than the one the bridge is installed with.
+pub fn test_ed25519_signer_secret_key() -> SecretKey {
+    SecretKey::ed25519_from_bytes([7u8; 32]).unwrap()
+}
+
+/// Hex encoded Casper public key of `test_ed25519_signer_secret_key()`.
+pub fn test_ed25519_signer_public_key() -> String {
+    PublicKey::from(&test_ed25519_signer_secret_key()).to_hex()
+}
+
+/// Signers as the bridge stores them in the raw signature mode.
+pub fn stored_signers<S: AsRef<str>>(signers: &[S]) -> Vec<SignerId> {
+    signers
+        .iter()
+        .map(|signer| parse_signer(SignatureMode::Raw, signer.as_ref()).unwrap())
+        .collect()
+}
+
+/// Hex encoded signer of the raw signature mode, as the bridge reports it in events.
+pub fn signer_hex(signer: &str) -> String {
+    parse_signer(SignatureMode::Raw, signer)
+        .unwrap()
+        .to_string()
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{byte:02x}")).collect()
+}
+
 pub fn new_deploy_hash() -> [u8; 32] {
     let counter = DEPLOY_COUNTER.fetch_add(1, Ordering::SeqCst);
     let hash = repeat(counter)
@@ -195,6 +281,74 @@ pub fn deploy_bridge<S>(
     builder: &mut WasmTestBuilder<S>,
     account: AccountHash,
 ) -> (ContractHash, ContractPackageHash)
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    deploy_bridge_with_event_mode(builder, account, EventMode::Legacy)
+}
+
+pub fn deploy_bridge_with_event_mode<S>(
+    builder: &mut WasmTestBuilder<S>,
+    account: AccountHash,
+    event_mode: EventMode,
+) -> (ContractHash, ContractPackageHash)
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let deploy_args = runtime_args! {
+        PARAM_SIGNER => test_public_key(),
+        PARAM_CHAIN_NAME => TEST_CHAIN_NAME,
+        PARAM_EVENT_MODE => u8::from(event_mode),
+        PARAM_SIGNATURE_MODE => u8::from(SignatureMode::Raw),
+        PARAM_TIMELOCK_DELAY => 0u64,
+    };
+
+    deploy_contract(
+        builder,
+        account,
+        CONTRACT_BRIDGE_BYTES,
+        deploy_args,
+        "bridge_contract",
+    )
+}
+
+pub fn deploy_bridge_with_signature_mode<S>(
+    builder: &mut WasmTestBuilder<S>,
+    account: AccountHash,
+    signature_mode: SignatureMode,
+    signer: &str,
+) -> (ContractHash, ContractPackageHash)
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let deploy_args = runtime_args! {
+        PARAM_SIGNER => signer,
+        PARAM_CHAIN_NAME => TEST_CHAIN_NAME,
+        PARAM_EVENT_MODE => u8::from(EventMode::Legacy),
+        PARAM_SIGNATURE_MODE => u8::from(signature_mode),
+        PARAM_TIMELOCK_DELAY => 0u64,
+    };
+
+    deploy_contract(
+        builder,
+        account,
+        CONTRACT_BRIDGE_BYTES,
+        deploy_args,
+        "bridge_contract",
+    )
+}
+
+pub fn deploy_bridge_with_timelock_delay<S>(
+    builder: &mut WasmTestBuilder<S>,
+    account: AccountHash,
+    timelock_delay: u64,
+) -> (ContractHash, ContractPackageHash)
 where
     S: StateProvider + CommitProvider,
     EngineError: From<S::Error>,
@@ -202,6 +356,34 @@ where
 {
     let deploy_args = runtime_args! {
         PARAM_SIGNER => test_public_key(),
+        PARAM_CHAIN_NAME => TEST_CHAIN_NAME,
+        PARAM_EVENT_MODE => u8::from(EventMode::Legacy),
+        PARAM_SIGNATURE_MODE => u8::from(SignatureMode::Raw),
+        PARAM_TIMELOCK_DELAY => timelock_delay,
+    };
+
+    deploy_contract(
+        builder,
+        account,
+        CONTRACT_BRIDGE_BYTES,
+        deploy_args,
+        "bridge_contract",
+    )
+}
+
+pub fn upgrade_bridge<S>(
+    builder: &mut WasmTestBuilder<S>,
+    account: AccountHash,
+    disable_old_version: bool,
+) -> (ContractHash, ContractPackageHash)
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let deploy_args = runtime_args! {
+        PARAM_DISABLE_OLD_VERSION => disable_old_version,
+        PARAM_CHAIN_NAME => TEST_CHAIN_NAME,
     };
 
     deploy_contract(
@@ -231,6 +413,12 @@ where
 
     let (bridge_hash, bridge_package_hash) = deploy_bridge(builder, account_address);
 
+    let deploy_item = add_token(bridge_hash, account_address, token_package_hash);
+    builder
+        .exec(ExecuteRequestBuilder::from_deploy_item(deploy_item).build())
+        .commit()
+        .expect_success();
+
     (
         token_hash,
         token_package_hash,
@@ -355,6 +543,218 @@ where
 
     value
 }
+
+pub fn query_token_config<S>(
+    builder: &mut WasmTestBuilder<S>,
+    contract: ContractHash,
+    address: ContractPackageHash,
+) -> TokenConfig
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let contract = builder
+        .query(None, Key::Hash(contract.value()), &[])
+        .unwrap()
+        .as_contract()
+        .cloned()
+        .unwrap();
+
+    let uref = contract
+        .named_keys()
+        .get("token_registry")
+        .unwrap()
+        .as_uref()
+        .cloned()
+        .unwrap();
+
+    let value = builder
+        .query_dictionary_item(None, uref, &dictionary_key(&address))
+        .unwrap()
+        .as_cl_value()
+        .cloned()
+        .unwrap()
+        .into_t::<TokenConfig>()
+        .unwrap();
+
+    value
+}
+
+pub fn query_token_limits<S>(
+    builder: &mut WasmTestBuilder<S>,
+    contract: ContractHash,
+    address: ContractPackageHash,
+) -> TokenLimits
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let contract = builder
+        .query(None, Key::Hash(contract.value()), &[])
+        .unwrap()
+        .as_contract()
+        .cloned()
+        .unwrap();
+
+    let uref = contract
+        .named_keys()
+        .get("limits_by_token")
+        .unwrap()
+        .as_uref()
+        .cloned()
+        .unwrap();
+
+    let value = builder
+        .query_dictionary_item(None, uref, &dictionary_key(&address))
+        .unwrap()
+        .as_cl_value()
+        .cloned()
+        .unwrap()
+        .into_t::<TokenLimits>()
+        .unwrap();
+
+    value
+}
+
+pub fn query_native_purse_balance<S>(
+    builder: &mut WasmTestBuilder<S>,
+    contract: ContractHash,
+) -> U512
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let contract = builder
+        .query(None, Key::Hash(contract.value()), &[])
+        .unwrap()
+        .as_contract()
+        .cloned()
+        .unwrap();
+
+    let purse = contract
+        .named_keys()
+        .get(NATIVE_PURSE_KEY_NAME)
+        .unwrap()
+        .as_uref()
+        .cloned()
+        .unwrap();
+
+    builder.get_purse_balance(purse)
+}
+
+pub fn query_native_commission_pool<S>(
+    builder: &mut WasmTestBuilder<S>,
+    contract: ContractHash,
+) -> U256
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    builder
+        .query(
+            None,
+            Key::Hash(contract.value()),
+            &[NATIVE_COMMISSION_KEY_NAME.to_string()],
+        )
+        .unwrap()
+        .as_cl_value()
+        .cloned()
+        .unwrap()
+        .into_t::<U256>()
+        .unwrap()
+}
+
+pub fn query_main_purse_balance<S>(builder: &mut WasmTestBuilder<S>, account: AccountHash) -> U512
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let main_purse = builder.get_account(account).unwrap().main_purse();
+    builder.get_purse_balance(main_purse)
+}
+
+/// Value returned by a read-only entry point of the bridge, called through the query session
+pub fn query_entry_point<T: CLTyped + FromBytes>(
+    context: &mut TestContext,
+    bridge_hash: ContractHash,
+    entry_point: &str,
+    mut args: RuntimeArgs,
+) -> T {
+    args.insert(PARAM_BRIDGE_CONTRACT, bridge_hash).unwrap();
+    args.insert(PARAM_ENTRY_POINT, entry_point.to_string())
+        .unwrap();
+
+    let deploy_item = simple_deploy_builder(context.account.address)
+        .with_session_bytes(BRIDGE_QUERY_SESSION_BYTES.into(), args)
+        .build();
+    get_context(context, deploy_item).expect_success();
+
+    let value_key = *context
+        .builder
+        .get_account(context.account.address)
+        .unwrap()
+        .named_keys()
+        .get(entry_point)
+        .unwrap();
+
+    context
+        .builder
+        .query(None, value_key, &[])
+        .unwrap()
+        .as_cl_value()
+        .cloned()
+        .unwrap()
+        .into_t::<T>()
+        .unwrap()
+}
+
+pub fn read_ces_event<S>(
+    builder: &mut WasmTestBuilder<S>,
+    contract: ContractHash,
+    index: u32,
+) -> Option<BridgeEvent>
+where
+    S: StateProvider + CommitProvider,
+    EngineError: From<S::Error>,
+    <S as StateProvider>::Error: Into<ExecError>,
+{
+    let contract = builder
+        .query(None, Key::Hash(contract.value()), &[])
+        .unwrap()
+        .as_contract()
+        .cloned()
+        .unwrap();
+
+    let uref = contract
+        .named_keys()
+        .get(EVENTS_DICT_NAME)
+        .unwrap()
+        .as_uref()
+        .cloned()
+        .unwrap();
+
+    read_ces_event_by_index(index, |dictionary_item_key| {
+        builder
+            .query_dictionary_item(None, uref, dictionary_item_key)
+            .ok()
+            .map(|value| {
+                value
+                    .as_cl_value()
+                    .cloned()
+                    .unwrap()
+                    .into_t::<Bytes>()
+                    .unwrap()
+                    .to_vec()
+            })
+    })
+    .unwrap()
+}
+
 pub fn read_contract_event<S, E>(
     builder: &mut WasmTestBuilder<S>,
     contract: ContractHash,
@@ -438,7 +838,25 @@ pub fn fill_purse_on_token_contract(
     assert_eq!(balance, amount);
 }
 
-pub fn bridge_in(
+pub fn approve(
+    token_hash: ContractHash,
+    account_address: AccountHash,
+    spender: Key,
+    amount: U256,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            token_hash,
+            "approve",
+            runtime_args! {
+                "spender" => spender,
+                PARAM_AMOUNT => amount,
+            },
+        )
+        .build()
+}
+
+pub fn bridge_in(
     bridge_hash: ContractHash,
     token_package_hash: ContractPackageHash,
     account_address: AccountHash,
@@ -447,11 +865,120 @@ pub fn bridge_in(
     nonce: U128,
     transaction_id: U256,
     gas_commission: U256,
-    mut signature_bytes: Vec<u8>,
+    signature_bytes: Vec<u8>,
+) -> DeployItem {
+    bridge_in_signed_by(
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        amount,
+        deadline,
+        nonce,
+        transaction_id,
+        gas_commission,
+        signature_bytes,
+        &[test_signer_secret_key()],
+    )
+}
+
+pub fn bridge_in_signed_by(
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    gas_commission: U256,
+    signature_bytes: Vec<u8>,
+    signer_secret_keys: &[&str],
+) -> DeployItem {
+    bridge_in_deploy(
+        EP_BRIDGE_IN,
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        amount,
+        deadline,
+        nonce,
+        transaction_id,
+        gas_commission,
+        signature_bytes,
+        SignatureMode::Raw,
+        signer_secret_keys,
+    )
+}
+
+pub fn bridge_in_with_signature_mode(
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    gas_commission: U256,
+    signature_mode: SignatureMode,
 ) -> DeployItem {
+    bridge_in_deploy(
+        EP_BRIDGE_IN,
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        amount,
+        deadline,
+        nonce,
+        transaction_id,
+        gas_commission,
+        Vec::new(),
+        signature_mode,
+        &[test_signer_secret_key()],
+    )
+}
 
+pub fn bridge_in_from(
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    gas_commission: U256,
+) -> DeployItem {
+    bridge_in_deploy(
+        EP_BRIDGE_IN_FROM,
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        amount,
+        deadline,
+        nonce,
+        transaction_id,
+        gas_commission,
+        Vec::new(),
+        SignatureMode::Raw,
+        &[test_signer_secret_key()],
+    )
+}
+
+fn bridge_in_deploy(
+    entry_point: &str,
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    gas_commission: U256,
+    mut signature_bytes: Vec<u8>,
+    signature_mode: SignatureMode,
+    signer_secret_keys: &[&str],
+) -> DeployItem {
     if signature_bytes.is_empty() {
         signature_bytes = cook_msg_bridge_in(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             account_address,
@@ -464,12 +991,94 @@ pub fn bridge_in(
             &TEST_DESTINATION_ADDRESS(),
         );
     }
-    let signature_bytes = get_signature_bytes(&signature_bytes, test_signer_secret_key());
+    let signatures: Vec<Bytes> = signer_secret_keys
+        .iter()
+        .map(|signer_secret_key| {
+            Bytes::from(sign_for_mode(
+                signature_mode,
+                &signature_bytes,
+                signer_secret_key,
+            ))
+        })
+        .collect();
+
+    bridge_in_deploy_with_signatures(
+        entry_point,
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        amount,
+        deadline,
+        nonce,
+        transaction_id,
+        gas_commission,
+        signatures,
+    )
+}
 
+pub fn bridge_in_signed_by_ed25519(
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    gas_commission: U256,
+    signer_secret_key: &SecretKey,
+) -> DeployItem {
+    let signature_bytes = cook_msg_bridge_in(
+        TEST_CHAIN_NAME,
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        &TEST_DESTINATION_CHAIN(),
+        &TEST_DESTINATION_ADDRESS(),
+    );
+    let signature = match crypto::sign(
+        signature_bytes,
+        signer_secret_key,
+        &PublicKey::from(signer_secret_key),
+    ) {
+        Signature::Ed25519(signature) => signature.to_bytes(),
+        _ => panic!("not an ed25519 key"),
+    };
+
+    bridge_in_deploy_with_signatures(
+        EP_BRIDGE_IN,
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        amount,
+        deadline,
+        nonce,
+        transaction_id,
+        gas_commission,
+        vec![Bytes::from(signature.to_vec())],
+    )
+}
+
+fn bridge_in_deploy_with_signatures(
+    entry_point: &str,
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    gas_commission: U256,
+    signatures: Vec<Bytes>,
+) -> DeployItem {
     simple_deploy_builder(account_address)
         .with_stored_session_hash(
             bridge_hash,
-            EP_BRIDGE_IN,
+            entry_point,
             runtime_args! {
                 PARAM_TOKEN_CONTRACT => token_package_hash,
                 PARAM_AMOUNT => amount,
@@ -479,7 +1088,7 @@ pub fn bridge_in(
                 PARAM_TRANSACTION_ID => transaction_id,
                 PARAM_DESTINATION_CHAIN => TEST_DESTINATION_CHAIN(),
                 PARAM_DESTINATION_ADDRESS => TEST_DESTINATION_ADDRESS(),
-                PARAM_SIGNATURE => signature_bytes,
+                PARAM_SIGNATURES => signatures,
             },
         )
         .build()
@@ -492,23 +1101,27 @@ pub fn transfer_out(
     recipient: Key,
     amount_to_transfer: U256,
     commission: U256,
+    deadline: U256,
     nonce: U128,
     transaction_id: U256,
     mut signature_bytes: Vec<u8>,
 ) -> DeployItem {
     if signature_bytes.is_empty() {
         signature_bytes = cook_msg_transfer_out(
+            TEST_CHAIN_NAME,
             bridge_hash,
             token_package_hash,
             account_address,
             recipient,
             amount_to_transfer,
             commission,
+            deadline,
             nonce,
             transaction_id
         );
     }
     let signature_bytes = get_signature_bytes(&signature_bytes, test_signer_secret_key());
+    let signature_bytes = Bytes::from(signature_bytes.to_vec());
 
     simple_deploy_builder(account_address)
         .with_stored_session_hash(
@@ -518,10 +1131,11 @@ pub fn transfer_out(
                 PARAM_TOKEN_CONTRACT => token_package_hash,
                 PARAM_AMOUNT => amount_to_transfer,
                 PARAM_COMMISSION => commission,
+                PARAM_DEADLINE => deadline,
                 PARAM_NONCE => nonce,
                 PARAM_TRANSACTION_ID => transaction_id,
                 PARAM_RECIPIENT => recipient,
-                PARAM_SIGNATURE => signature_bytes,
+                PARAM_SIGNATURES => vec![signature_bytes],
             },
         )
         .build()
@@ -533,6 +1147,26 @@ pub fn bridge_out(
     account_address: AccountHash,
     recipient_key: Key,
     amount: U256,
+) -> DeployItem {
+    bridge_out_for_transfer(
+        bridge_hash,
+        token_package_hash,
+        account_address,
+        recipient_key,
+        amount,
+        "SOUR",
+        U256::one(),
+    )
+}
+
+pub fn bridge_out_for_transfer(
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    recipient_key: Key,
+    amount: U256,
+    source_chain: &str,
+    transaction_id: U256,
 ) -> DeployItem {
     simple_deploy_builder(account_address)
         .with_stored_session_hash(
@@ -541,8 +1175,8 @@ pub fn bridge_out(
             runtime_args! {
                 PARAM_TOKEN_CONTRACT => token_package_hash,
                 PARAM_AMOUNT => amount,
-                PARAM_TRANSACTION_ID => U256::one(),
-                PARAM_SOURCE_CHAIN => "SOUR".to_string(),
+                PARAM_TRANSACTION_ID => transaction_id,
+                PARAM_SOURCE_CHAIN => source_chain.to_string(),
                 PARAM_SOURCE_ADDRESS => "SOURADDR".to_string(),
                 PARAM_RECIPIENT => recipient_key
             },
@@ -570,6 +1204,135 @@ pub fn withdraw_commission(
         .build()
 }
 
+pub fn rebalance_liquidity(
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_REBALANCE_LIQUIDITY,
+            runtime_args! {
+               PARAM_TOKEN_CONTRACT => token_package_hash,
+               PARAM_AMOUNT => amount,
+            },
+        )
+        .build()
+}
+
+pub fn deposit_liquidity(
+    bridge_hash: ContractHash,
+    token_package_hash: ContractPackageHash,
+    account_address: AccountHash,
+    amount: U256,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_DEPOSIT_LIQUIDITY,
+            runtime_args! {
+               PARAM_TOKEN_CONTRACT => token_package_hash,
+               PARAM_AMOUNT => amount,
+            },
+        )
+        .build()
+}
+
+pub fn propose_change(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    change: Change,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_PROPOSE_CHANGE,
+            runtime_args! {
+                PARAM_CHANGE => change,
+            },
+        )
+        .build()
+}
+
+pub fn execute_change(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    id: u64,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_EXECUTE_CHANGE,
+            runtime_args! {
+                PARAM_PROPOSAL_ID => id,
+            },
+        )
+        .build()
+}
+
+pub fn cancel_change(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    id: u64,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_CANCEL_CHANGE,
+            runtime_args! {
+                PARAM_PROPOSAL_ID => id,
+            },
+        )
+        .build()
+}
+
+/// Sets the treasury through a change proposal executed once its timelock has elapsed.
+pub fn set_treasury(context: &mut TestContext, bridge_hash: ContractHash, treasury: Key) {
+    let id: u64 = context.builder.get_value(bridge_hash, "next_proposal_id");
+    let delay: u64 = context.builder.get_value(bridge_hash, "timelock_delay");
+
+    let change = Change::SetTreasury { treasury };
+    let deploy_item = propose_change(bridge_hash, context.account.address, change);
+    get_context(context, deploy_item).expect_success();
+
+    let deploy_item = execute_change(bridge_hash, context.account.address, id);
+    execution_context_at(context, deploy_item, TEST_BLOCK_TIME + delay).expect_success();
+}
+
+pub fn set_timelock_delay(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    delay: u64,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_SET_TIMELOCK_DELAY,
+            runtime_args! {
+                PARAM_DELAY => delay,
+            },
+        )
+        .build()
+}
+
+pub fn set_max_deadline_horizon(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    horizon: u64,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_SET_MAX_DEADLINE_HORIZON,
+            runtime_args! {
+                PARAM_HORIZON => horizon,
+            },
+        )
+        .build()
+}
+
 pub fn set_test_signer(
     bridge_hash: ContractHash,
     account_address: AccountHash,
@@ -586,6 +1349,297 @@ pub fn set_test_signer(
         .build()
 }
 
+pub fn set_signer_set(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    signers: Vec<String>,
+    threshold: u8,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_SET_SIGNER_SET,
+            runtime_args! {
+                PARAM_SIGNERS => signers,
+                PARAM_THRESHOLD => threshold,
+            },
+        )
+        .build()
+}
+
+pub fn pause(bridge_hash: ContractHash, account_address: AccountHash) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(bridge_hash, EP_PAUSE, runtime_args! {})
+        .build()
+}
+
+pub fn unpause(bridge_hash: ContractHash, account_address: AccountHash) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(bridge_hash, EP_UNPAUSE, runtime_args! {})
+        .build()
+}
+
+pub fn add_token(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    token_package_hash: ContractPackageHash,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_ADD_TOKEN,
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+            },
+        )
+        .build()
+}
+
+pub fn remove_token(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    token_package_hash: ContractPackageHash,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_REMOVE_TOKEN,
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+            },
+        )
+        .build()
+}
+
+pub fn set_token_mode(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    token_package_hash: ContractPackageHash,
+    mode: u8,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_SET_TOKEN_MODE,
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+                PARAM_MODE => mode,
+            },
+        )
+        .build()
+}
+
+pub fn set_bridge_in_limits(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    token_package_hash: ContractPackageHash,
+    min_amount: U256,
+    max_amount: U256,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_SET_BRIDGE_IN_LIMITS,
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+                PARAM_MIN_AMOUNT => min_amount,
+                PARAM_MAX_AMOUNT => max_amount,
+            },
+        )
+        .build()
+}
+
+pub fn set_bridge_out_limits(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    token_package_hash: ContractPackageHash,
+    max_amount: U256,
+    outflow_cap: U256,
+    outflow_window: u64,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_SET_BRIDGE_OUT_LIMITS,
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+                PARAM_MAX_AMOUNT => max_amount,
+                PARAM_OUTFLOW_CAP => outflow_cap,
+                PARAM_OUTFLOW_WINDOW => outflow_window,
+            },
+        )
+        .build()
+}
+
+pub fn set_token_stable_commission_percent(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    token_package_hash: ContractPackageHash,
+    stable_commission_percent: U256,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_SET_TOKEN_STABLE_COMMISSION_PERCENT,
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+                PARAM_STABLE_COMMISSION_PERCENT => stable_commission_percent,
+            },
+        )
+        .build()
+}
+
+pub fn clear_token_stable_commission_percent(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    token_package_hash: ContractPackageHash,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_CLEAR_TOKEN_STABLE_COMMISSION_PERCENT,
+            runtime_args! {
+                PARAM_TOKEN_CONTRACT => token_package_hash,
+            },
+        )
+        .build()
+}
+
+pub fn grant_role(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    role: &str,
+    account: Key,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_GRANT_ROLE,
+            runtime_args! {
+                PARAM_ROLE => role.to_string(),
+                PARAM_ACCOUNT => account,
+            },
+        )
+        .build()
+}
+
+pub fn revoke_role(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    role: &str,
+    account: Key,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_REVOKE_ROLE,
+            runtime_args! {
+                PARAM_ROLE => role.to_string(),
+                PARAM_ACCOUNT => account,
+            },
+        )
+        .build()
+}
+
+pub fn claim_role(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    role: &str,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_CLAIM_ROLE,
+            runtime_args! {
+                PARAM_ROLE => role.to_string(),
+            },
+        )
+        .build()
+}
+
+pub fn bridge_in_native(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    amount: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    gas_commission: U256,
+) -> DeployItem {
+    let signature_bytes = cook_msg_bridge_in(
+        TEST_CHAIN_NAME,
+        bridge_hash,
+        NATIVE_TOKEN_CONTRACT,
+        account_address,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        &TEST_DESTINATION_CHAIN(),
+        &TEST_DESTINATION_ADDRESS(),
+    );
+    let signature =
+        Bytes::from(get_signature_bytes(&signature_bytes, test_signer_secret_key()).to_vec());
+
+    simple_deploy_builder(account_address)
+        .with_session_bytes(
+            BRIDGE_IN_NATIVE_SESSION_BYTES.into(),
+            runtime_args! {
+                PARAM_BRIDGE_CONTRACT => bridge_hash,
+                PARAM_AMOUNT => amount,
+                PARAM_GAS_COMMISSION => gas_commission,
+                PARAM_DEADLINE => deadline,
+                PARAM_NONCE => nonce,
+                PARAM_TRANSACTION_ID => transaction_id,
+                PARAM_DESTINATION_CHAIN => TEST_DESTINATION_CHAIN(),
+                PARAM_DESTINATION_ADDRESS => TEST_DESTINATION_ADDRESS(),
+                PARAM_SIGNATURES => vec![signature],
+            },
+        )
+        .build()
+}
+
+pub fn bridge_out_native(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    recipient_key: Key,
+    amount: U256,
+    transaction_id: U256,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_BRIDGE_OUT_NATIVE,
+            runtime_args! {
+                PARAM_AMOUNT => amount,
+                PARAM_TRANSACTION_ID => transaction_id,
+                PARAM_SOURCE_CHAIN => "SOUR".to_string(),
+                PARAM_SOURCE_ADDRESS => "SOURADDR".to_string(),
+                PARAM_RECIPIENT => recipient_key
+            },
+        )
+        .build()
+}
+
+pub fn withdraw_native_commission(
+    bridge_hash: ContractHash,
+    account_address: AccountHash,
+    recipient_key: Key,
+    amount: U256,
+) -> DeployItem {
+    simple_deploy_builder(account_address)
+        .with_stored_session_hash(
+            bridge_hash,
+            EP_WITHDRAW_NATIVE_COMMISSION,
+            runtime_args! {
+               PARAM_AMOUNT => amount,
+               PARAM_RECIPIENT => recipient_key,
+            },
+        )
+        .build()
+}
+
 pub fn arbitrary_user(context: &mut TestContext) -> UserAccount {
     UserAccount::unique_account(context, 0)
 }
@@ -597,12 +1651,20 @@ pub fn arbitrary_user_key(context: &mut TestContext) -> Key {
 pub fn execution_context(
     context: &mut TestContext,
     deploy_item: DeployItem,
+) -> &mut WasmTestBuilder<InMemoryGlobalState> {
+    execution_context_at(context, deploy_item, TEST_BLOCK_TIME)
+}
+
+pub fn execution_context_at(
+    context: &mut TestContext,
+    deploy_item: DeployItem,
+    block_time: u64,
 ) -> &mut WasmTestBuilder<InMemoryGlobalState> {
     context
         .builder
         .exec(
             ExecuteRequestBuilder::from_deploy_item(deploy_item)
-                .with_block_time(TEST_BLOCK_TIME)
+                .with_block_time(block_time)
                 .build(),
         )
         .commit()
@@ -630,5 +1692,5 @@ pub fn get_context(
 }
 
 pub fn get_expected_total_commission() -> U256 {
-    (TEST_AMOUNT() * TEST_STABLE_COMMISSION_PERCENT() / 100) + TEST_GAS_COMMISSION()
+    (TEST_AMOUNT() * TEST_STABLE_COMMISSION_PERCENT() / HUNDRED_PERCENT_BPS) + TEST_GAS_COMMISSION()
 }
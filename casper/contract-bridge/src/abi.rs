@@ -0,0 +1,166 @@
+//! JSON description of the bridge contract interface.
+//!
+//! Lists every entry point with its arguments, return type and access, the `BridgeError` codes and
+//! the `BridgeEvent` layouts, so that off-chain clients can generate bindings instead of
+//! hardcoding names. The `bridge-abi` binary prints it.
+use casper_common::event::{
+    event_layouts, EventLayout, EVENT_ENCODING_VERSION, EVENT_ENVELOPE_MAGIC,
+};
+use casper_types::{CLType, EntryPoint, EntryPointAccess, EntryPointType, Parameter};
+use contract_util::error::ERROR_CONTRACT_START;
+use serde::Serialize;
+
+use crate::{contract::default_entry_points, error::BridgeError};
+
+/// Version of the ABI document format.
+pub const ABI_VERSION: u32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Abi {
+    pub version: u32,
+    pub entry_points: Vec<EntryPointAbi>,
+    pub errors: Vec<ErrorAbi>,
+    /// Marker byte every event envelope starts with.
+    pub event_envelope_magic: u8,
+    /// Version of the envelope every event is wrapped in.
+    pub event_encoding_version: u8,
+    pub events: Vec<EventAbi>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EntryPointAbi {
+    pub name: String,
+    pub args: Vec<ArgAbi>,
+    pub ret: CLType,
+    pub access: AccessAbi,
+    pub entry_point_type: EntryPointTypeAbi,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ArgAbi {
+    pub name: String,
+    pub cl_type: CLType,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AccessAbi {
+    Public,
+    /// Names of the groups allowed to call the entry point.
+    Groups(Vec<String>),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryPointTypeAbi {
+    Session,
+    Contract,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ErrorAbi {
+    pub name: String,
+    /// `BridgeError` discriminant.
+    pub code: u16,
+    /// Code of the `ApiError::User` the contract reverts with.
+    pub user_error: u16,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct EventAbi {
+    pub name: String,
+    pub tag: u8,
+    pub fields: Vec<ArgAbi>,
+}
+
+impl From<&Parameter> for ArgAbi {
+    fn from(parameter: &Parameter) -> Self {
+        ArgAbi {
+            name: parameter.name().into(),
+            cl_type: parameter.cl_type().clone(),
+        }
+    }
+}
+
+impl From<&EntryPoint> for EntryPointAbi {
+    fn from(entry_point: &EntryPoint) -> Self {
+        let access = match entry_point.access() {
+            EntryPointAccess::Public => AccessAbi::Public,
+            EntryPointAccess::Groups(groups) => {
+                AccessAbi::Groups(groups.iter().map(|group| group.value().into()).collect())
+            }
+        };
+        let entry_point_type = match entry_point.entry_point_type() {
+            EntryPointType::Session => EntryPointTypeAbi::Session,
+            EntryPointType::Contract => EntryPointTypeAbi::Contract,
+        };
+
+        EntryPointAbi {
+            name: entry_point.name().into(),
+            args: entry_point.args().iter().map(ArgAbi::from).collect(),
+            ret: entry_point.ret().clone(),
+            access,
+            entry_point_type,
+        }
+    }
+}
+
+impl From<EventLayout> for EventAbi {
+    fn from(layout: EventLayout) -> Self {
+        EventAbi {
+            name: layout.name.into(),
+            tag: layout.tag,
+            fields: layout
+                .fields
+                .into_iter()
+                .map(|(name, cl_type)| ArgAbi {
+                    name: name.into(),
+                    cl_type,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Lists every `BridgeError` variant, ordered by code.
+fn errors() -> Vec<ErrorAbi> {
+    (0..=u8::MAX as u16)
+        .filter_map(|code| {
+            let error = BridgeError::from(code);
+            let name = format!("{:?}", error);
+            let message = error.to_string();
+            let error_code: u16 = error.into();
+            (error_code == code).then(|| ErrorAbi {
+                name,
+                code,
+                user_error: code + ERROR_CONTRACT_START,
+                message,
+            })
+        })
+        .collect()
+}
+
+/// Builds the ABI of the bridge contract.
+pub fn abi() -> Abi {
+    let mut entry_points: Vec<EntryPointAbi> = default_entry_points()
+        .take_entry_points()
+        .iter()
+        .map(EntryPointAbi::from)
+        .collect();
+    entry_points.sort_by(|a, b| a.name.cmp(&b.name));
+
+    Abi {
+        version: ABI_VERSION,
+        entry_points,
+        errors: errors(),
+        event_envelope_magic: EVENT_ENVELOPE_MAGIC,
+        event_encoding_version: EVENT_ENCODING_VERSION,
+        events: event_layouts().into_iter().map(EventAbi::from).collect(),
+    }
+}
+
+/// Renders the ABI of the bridge contract as pretty-printed JSON.
+pub fn abi_json() -> String {
+    serde_json::to_string_pretty(&abi()).expect("ABI is serializable")
+}
@@ -0,0 +1,438 @@
+//! Named arguments of the bridge entry points.
+//!
+//! Each entry point declares its arguments once here. The `Parameter` schema registered with the
+//! contract, the `RuntimeArgs` sent by callers and the values read by the contract are all derived
+//! from the same declaration, so they cannot drift apart.
+use alloc::{string::String, vec, vec::Vec};
+
+use casper_contract::contract_api::runtime;
+use casper_types::{
+    bytesrepr::Bytes, CLTyped, CLValueError, ContractPackageHash, Key, Parameter, RuntimeArgs,
+    URef, U128, U256,
+};
+
+use crate::entry_points::{
+    PARAM_ACCOUNT, PARAM_AMOUNT, PARAM_BYTES, PARAM_CHAIN_NAME, PARAM_CHANGE, PARAM_COMMISSION,
+    PARAM_DEADLINE, PARAM_DELAY, PARAM_DESTINATION_ADDRESS, PARAM_DESTINATION_CHAIN,
+    PARAM_GAS_COMMISSION, PARAM_HORIZON, PARAM_MAX_AMOUNT, PARAM_MIN_AMOUNT, PARAM_MODE,
+    PARAM_NONCE, PARAM_OUTFLOW_CAP, PARAM_OUTFLOW_WINDOW, PARAM_PROPOSAL_ID, PARAM_PURSE,
+    PARAM_RECIPIENT, PARAM_ROLE, PARAM_SENDER, PARAM_SIGNATURES, PARAM_SIGNER, PARAM_SIGNERS,
+    PARAM_SOURCE_ADDRESS, PARAM_SOURCE_CHAIN, PARAM_STABLE_COMMISSION_PERCENT, PARAM_THRESHOLD,
+    PARAM_TOKEN_CONTRACT, PARAM_TRANSACTION_ID,
+};
+use casper_common::change::Change;
+
+/// Declares the named arguments of an entry point as a struct, along with the parameters the entry
+/// point is registered with, the runtime args to call it with and how the contract reads them.
+macro_rules! entry_point_args {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($field:ident: $ty:ty => $param:ident,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq)]
+        pub struct $name {
+            $(pub $field: $ty,)*
+        }
+
+        impl $name {
+            /// Parameters the entry point is registered with.
+            pub fn parameters() -> Vec<Parameter> {
+                vec![$(Parameter::new($param, <$ty>::cl_type()),)*]
+            }
+
+            /// Runtime args to call the entry point with.
+            pub fn into_runtime_args(self) -> Result<RuntimeArgs, CLValueError> {
+                RuntimeArgs::try_new(|args| {
+                    $(args.insert($param, self.$field)?;)*
+                    Ok(())
+                })
+            }
+
+            /// Reads the arguments the entry point is called with.
+            pub fn from_named_args() -> Self {
+                Self {
+                    $($field: runtime::get_named_arg($param),)*
+                }
+            }
+        }
+    };
+}
+
+entry_point_args! {
+    /// Named arguments of `bridge_in`.
+    BridgeInArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+        gas_commission: U256 => PARAM_GAS_COMMISSION,
+        deadline: U256 => PARAM_DEADLINE,
+        nonce: U128 => PARAM_NONCE,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+        destination_chain: String => PARAM_DESTINATION_CHAIN,
+        destination_address: String => PARAM_DESTINATION_ADDRESS,
+        signatures: Vec<Bytes> => PARAM_SIGNATURES,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `bridge_in_confirm`.
+    BridgeInConfirmArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+        gas_commission: U256 => PARAM_GAS_COMMISSION,
+        nonce: U128 => PARAM_NONCE,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+        destination_chain: String => PARAM_DESTINATION_CHAIN,
+        destination_address: String => PARAM_DESTINATION_ADDRESS,
+        sender: Key => PARAM_SENDER,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `check_params`.
+    CheckParamsArgs {
+        bytes: Bytes => PARAM_BYTES,
+        signatures: Vec<Bytes> => PARAM_SIGNATURES,
+        nonce: U128 => PARAM_NONCE,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `bridge_out`.
+    BridgeOutArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+        source_chain: String => PARAM_SOURCE_CHAIN,
+        source_address: String => PARAM_SOURCE_ADDRESS,
+        recipient: Key => PARAM_RECIPIENT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `transfer_out`.
+    TransferOutArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+        commission: U256 => PARAM_COMMISSION,
+        deadline: U256 => PARAM_DEADLINE,
+        nonce: U128 => PARAM_NONCE,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+        recipient: Key => PARAM_RECIPIENT,
+        signatures: Vec<Bytes> => PARAM_SIGNATURES,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `withdraw_commission`.
+    WithdrawCommissionArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+        recipient: Key => PARAM_RECIPIENT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_stable_commission_percent`.
+    SetStableCommissionPercentArgs {
+        stable_commission_percent: U256 => PARAM_STABLE_COMMISSION_PERCENT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_token_stable_commission_percent`.
+    SetTokenStableCommissionPercentArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        stable_commission_percent: U256 => PARAM_STABLE_COMMISSION_PERCENT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `clear_token_stable_commission_percent`.
+    ClearTokenStableCommissionPercentArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_token_stable_commission_percent`.
+    GetTokenStableCommissionPercentArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_signer`.
+    SetSignerArgs {
+        signer: String => PARAM_SIGNER,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_signer_set`.
+    SetSignerSetArgs {
+        signers: Vec<String> => PARAM_SIGNERS,
+        threshold: u8 => PARAM_THRESHOLD,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `add_token`.
+    AddTokenArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `remove_token`.
+    RemoveTokenArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_token_config`.
+    GetTokenConfigArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_bridge_in_limits`.
+    SetBridgeInLimitsArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        min_amount: U256 => PARAM_MIN_AMOUNT,
+        max_amount: U256 => PARAM_MAX_AMOUNT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_bridge_out_limits`.
+    SetBridgeOutLimitsArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        max_amount: U256 => PARAM_MAX_AMOUNT,
+        outflow_cap: U256 => PARAM_OUTFLOW_CAP,
+        outflow_window: u64 => PARAM_OUTFLOW_WINDOW,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_token_limits`.
+    GetTokenLimitsArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_outflow_volume`.
+    GetOutflowVolumeArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `grant_role`.
+    GrantRoleArgs {
+        role: String => PARAM_ROLE,
+        account: Key => PARAM_ACCOUNT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `revoke_role`.
+    RevokeRoleArgs {
+        role: String => PARAM_ROLE,
+        account: Key => PARAM_ACCOUNT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `claim_role`.
+    ClaimRoleArgs {
+        role: String => PARAM_ROLE,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `take_role_access`.
+    TakeRoleAccessArgs {
+        role: String => PARAM_ROLE,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `has_role`.
+    HasRoleArgs {
+        role: String => PARAM_ROLE,
+        account: Key => PARAM_ACCOUNT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `is_nonce_used`.
+    IsNonceUsedArgs {
+        nonce: U128 => PARAM_NONCE,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `is_transfer_processed`.
+    IsTransferProcessedArgs {
+        source_chain: String => PARAM_SOURCE_CHAIN,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `bridge_in_native`.
+    BridgeInNativeArgs {
+        amount: U256 => PARAM_AMOUNT,
+        gas_commission: U256 => PARAM_GAS_COMMISSION,
+        deadline: U256 => PARAM_DEADLINE,
+        nonce: U128 => PARAM_NONCE,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+        destination_chain: String => PARAM_DESTINATION_CHAIN,
+        destination_address: String => PARAM_DESTINATION_ADDRESS,
+        signatures: Vec<Bytes> => PARAM_SIGNATURES,
+        purse: URef => PARAM_PURSE,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `bridge_out_native`.
+    BridgeOutNativeArgs {
+        amount: U256 => PARAM_AMOUNT,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+        source_chain: String => PARAM_SOURCE_CHAIN,
+        source_address: String => PARAM_SOURCE_ADDRESS,
+        recipient: Key => PARAM_RECIPIENT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `withdraw_native_commission`.
+    WithdrawNativeCommissionArgs {
+        amount: U256 => PARAM_AMOUNT,
+        recipient: Key => PARAM_RECIPIENT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_token_mode`.
+    SetTokenModeArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        mode: u8 => PARAM_MODE,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_wrapped_supply`.
+    GetWrappedSupplyArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `bridge_in_from`.
+    BridgeInFromArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+        gas_commission: U256 => PARAM_GAS_COMMISSION,
+        deadline: U256 => PARAM_DEADLINE,
+        nonce: U128 => PARAM_NONCE,
+        transaction_id: U256 => PARAM_TRANSACTION_ID,
+        destination_chain: String => PARAM_DESTINATION_CHAIN,
+        destination_address: String => PARAM_DESTINATION_ADDRESS,
+        signatures: Vec<Bytes> => PARAM_SIGNATURES,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `rebalance_liquidity`.
+    RebalanceLiquidityArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `deposit_liquidity`.
+    DepositLiquidityArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `propose_change`.
+    ProposeChangeArgs {
+        change: Change => PARAM_CHANGE,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `execute_change`.
+    ExecuteChangeArgs {
+        proposal_id: u64 => PARAM_PROPOSAL_ID,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `cancel_change`.
+    CancelChangeArgs {
+        proposal_id: u64 => PARAM_PROPOSAL_ID,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_proposal`.
+    GetProposalArgs {
+        proposal_id: u64 => PARAM_PROPOSAL_ID,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_timelock_delay`.
+    SetTimelockDelayArgs {
+        delay: u64 => PARAM_DELAY,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `set_max_deadline_horizon`, with the horizon in milliseconds.
+    SetMaxDeadlineHorizonArgs {
+        horizon: u64 => PARAM_HORIZON,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_commission_pool`.
+    GetCommissionPoolArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_available_liquidity`.
+    GetAvailableLiquidityArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `get_total_commission`.
+    GetTotalCommissionArgs {
+        token_contract: ContractPackageHash => PARAM_TOKEN_CONTRACT,
+        amount: U256 => PARAM_AMOUNT,
+        gas_commission: U256 => PARAM_GAS_COMMISSION,
+    }
+}
+
+entry_point_args! {
+    /// Named arguments of `migrate`.
+    MigrateArgs {
+        chain_name: String => PARAM_CHAIN_NAME,
+    }
+}
@@ -0,0 +1,6 @@
+//! Prints the JSON ABI of the bridge contract.
+//!
+//! Usage: `cargo run --bin bridge-abi --features abi > bridge-abi.json`
+fn main() {
+    println!("{}", contract_bridge::abi::abi_json());
+}
@@ -6,12 +6,24 @@ extern crate contract_bridge;
 
 use alloc::string::String;
 use casper_contract::{contract_api::runtime, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{bytesrepr::Bytes, CLValue, ContractPackageHash, Key, U128, U256};
+use casper_types::CLValue;
+use contract_bridge::args::{
+    AddTokenArgs, BridgeInArgs, BridgeInConfirmArgs, BridgeInFromArgs, BridgeInNativeArgs,
+    BridgeOutArgs, BridgeOutNativeArgs, CancelChangeArgs, CheckParamsArgs, ClaimRoleArgs,
+    ClearTokenStableCommissionPercentArgs, DepositLiquidityArgs, ExecuteChangeArgs,
+    GetAvailableLiquidityArgs, GetCommissionPoolArgs, GetOutflowVolumeArgs, GetProposalArgs,
+    GetTokenConfigArgs, GetTokenLimitsArgs, GetTokenStableCommissionPercentArgs,
+    GetTotalCommissionArgs, GetWrappedSupplyArgs, GrantRoleArgs, HasRoleArgs, IsNonceUsedArgs,
+    IsTransferProcessedArgs, MigrateArgs, ProposeChangeArgs, RebalanceLiquidityArgs,
+    RemoveTokenArgs, RevokeRoleArgs, SetBridgeInLimitsArgs, SetBridgeOutLimitsArgs,
+    SetMaxDeadlineHorizonArgs, SetSignerArgs, SetSignerSetArgs, SetStableCommissionPercentArgs,
+    SetTimelockDelayArgs, SetTokenModeArgs, SetTokenStableCommissionPercentArgs,
+    TakeRoleAccessArgs, TransferOutArgs, WithdrawCommissionArgs, WithdrawNativeCommissionArgs,
+};
+use contract_bridge::constants::{NK_ACCESS_UREF, NK_CONTRACT};
 use contract_bridge::entry_points::{
-    PARAM_AMOUNT, PARAM_BYTES, PARAM_COMMISSION, PARAM_DEADLINE, PARAM_DESTINATION_ADDRESS,
-    PARAM_DESTINATION_CHAIN, PARAM_GAS_COMMISSION, PARAM_NONCE, PARAM_RECIPIENT, PARAM_SENDER,
-    PARAM_SIGNATURE, PARAM_SIGNER, PARAM_SOURCE_ADDRESS, PARAM_SOURCE_CHAIN,
-    PARAM_STABLE_COMMISSION_PERCENT, PARAM_TOKEN_CONTRACT, PARAM_TRANSACTION_ID,
+    PARAM_CHAIN_NAME, PARAM_DISABLE_OLD_VERSION, PARAM_EVENT_MODE, PARAM_SIGNATURE_MODE,
+    PARAM_SIGNER, PARAM_TIMELOCK_DELAY,
 };
 
 /// Transfers funds to the bridge, with metadata specifying the destination chain.
@@ -19,16 +31,17 @@ use contract_bridge::entry_points::{
 /// Call context: session
 #[no_mangle]
 pub extern "C" fn bridge_in() {
-    let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
-    let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
-    let gas_commission: U256 = runtime::get_named_arg(PARAM_GAS_COMMISSION);
-    let deadline: U256 = runtime::get_named_arg(PARAM_DEADLINE);
-    let nonce: U128 = runtime::get_named_arg(PARAM_NONCE);
-    let transaction_id: U256 = runtime::get_named_arg(PARAM_TRANSACTION_ID);
-    let destination_chain: String = runtime::get_named_arg(PARAM_DESTINATION_CHAIN);
-    let destination_address: String = runtime::get_named_arg(PARAM_DESTINATION_ADDRESS);
-
-    let signature: [u8; 64] = runtime::get_named_arg(PARAM_SIGNATURE);
+    let BridgeInArgs {
+        token_contract,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
+    } = BridgeInArgs::from_named_args();
 
     contract_bridge::contract::bridge_in(
         token_contract,
@@ -39,20 +52,52 @@ pub extern "C" fn bridge_in() {
         transaction_id,
         destination_chain,
         destination_address,
-        signature,
+        signatures,
+    );
+}
+
+/// Transfers funds to the bridge through an allowance, with metadata specifying the destination chain.
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn bridge_in_from() {
+    let BridgeInFromArgs {
+        token_contract,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
+    } = BridgeInFromArgs::from_named_args();
+
+    contract_bridge::contract::bridge_in_from(
+        token_contract,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
     );
 }
 
 #[no_mangle]
 pub extern "C" fn bridge_in_confirm() {
-    let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
-    let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
-    let gas_commission: U256 = runtime::get_named_arg(PARAM_GAS_COMMISSION);
-    let nonce: U128 = runtime::get_named_arg(PARAM_NONCE);
-    let transaction_id: U256 = runtime::get_named_arg(PARAM_TRANSACTION_ID);
-    let destination_chain: String = runtime::get_named_arg(PARAM_DESTINATION_CHAIN);
-    let destination_address: String = runtime::get_named_arg(PARAM_DESTINATION_ADDRESS);
-    let from: Key = runtime::get_named_arg(PARAM_SENDER);
+    let BridgeInConfirmArgs {
+        token_contract,
+        amount,
+        gas_commission,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        sender: from,
+    } = BridgeInConfirmArgs::from_named_args();
 
     contract_bridge::contract::bridge_in_confirm(
         token_contract,
@@ -68,10 +113,12 @@ pub extern "C" fn bridge_in_confirm() {
 
 #[no_mangle]
 pub extern "C" fn check_params() {
-    let bytes: Bytes = runtime::get_named_arg(PARAM_BYTES);
-    let signature: [u8; 64] = runtime::get_named_arg(PARAM_SIGNATURE);
-    let nonce: U128 = runtime::get_named_arg(PARAM_NONCE);
-    contract_bridge::contract::check_params(bytes, signature, nonce);
+    let CheckParamsArgs {
+        bytes,
+        signatures,
+        nonce,
+    } = CheckParamsArgs::from_named_args();
+    contract_bridge::contract::check_params(bytes, signatures, nonce);
 }
 
 /// Transfers funds from the bridge.
@@ -79,12 +126,14 @@ pub extern "C" fn check_params() {
 /// Call context: contract
 #[no_mangle]
 pub extern "C" fn bridge_out() {
-    let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
-    let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
-    let transaction_id: U256 = runtime::get_named_arg(PARAM_TRANSACTION_ID);
-    let source_chain: String = runtime::get_named_arg(PARAM_SOURCE_CHAIN);
-    let source_address: String = runtime::get_named_arg(PARAM_SOURCE_ADDRESS);
-    let recipient: Key = runtime::get_named_arg(PARAM_RECIPIENT);
+    let BridgeOutArgs {
+        token_contract,
+        amount,
+        transaction_id,
+        source_chain,
+        source_address,
+        recipient,
+    } = BridgeOutArgs::from_named_args();
 
     contract_bridge::contract::bridge_out(
         token_contract,
@@ -101,22 +150,26 @@ pub extern "C" fn bridge_out() {
 /// Call context: contract
 #[no_mangle]
 pub extern "C" fn transfer_out() {
-    let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
-    let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
-    let commission: U256 = runtime::get_named_arg(PARAM_COMMISSION);
-    let nonce: U128 = runtime::get_named_arg(PARAM_NONCE);
-    let transaction_id: U256 = runtime::get_named_arg(PARAM_TRANSACTION_ID);
-    let recipient: Key = runtime::get_named_arg(PARAM_RECIPIENT);
-    let signature: [u8; 64] = runtime::get_named_arg(PARAM_SIGNATURE);
+    let TransferOutArgs {
+        token_contract,
+        amount,
+        commission,
+        deadline,
+        nonce,
+        transaction_id,
+        recipient,
+        signatures,
+    } = TransferOutArgs::from_named_args();
 
     contract_bridge::contract::transfer_out(
         token_contract,
         amount,
         commission,
+        deadline,
         nonce,
         transaction_id,
         recipient,
-        signature,
+        signatures,
     );
 }
 
@@ -125,18 +178,22 @@ pub extern "C" fn transfer_out() {
 /// Call context: contract
 #[no_mangle]
 pub extern "C" fn withdraw_commission() {
-    let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
-    let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
-    let recipient: Key = runtime::get_named_arg(PARAM_RECIPIENT);
+    let WithdrawCommissionArgs {
+        token_contract,
+        amount,
+        recipient,
+    } = WithdrawCommissionArgs::from_named_args();
     contract_bridge::contract::withdraw_commission(token_contract, amount, recipient);
 }
 
-/// Manually set commission percent
+/// Manually set commission percent, in BPS
 ///
 /// Call context:
 #[no_mangle]
 pub extern "C" fn set_stable_commission_percent() {
-    let stable_commission_percent: U256 = runtime::get_named_arg(PARAM_STABLE_COMMISSION_PERCENT);
+    let SetStableCommissionPercentArgs {
+        stable_commission_percent,
+    } = SetStableCommissionPercentArgs::from_named_args();
     contract_bridge::contract::set_stable_commission_percent(stable_commission_percent);
 }
 
@@ -149,13 +206,57 @@ pub extern "C" fn get_stable_commission_percent() {
     runtime::ret(CLValue::from_t(res).unwrap_or_revert());
 }
 
+/// Convert commission percent stored by a pre-BPS version of the contract to BPS
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn migrate_stable_commission_to_bps() {
+    contract_bridge::contract::migrate_stable_commission_to_bps();
+}
+
+/// Manually set commission percent of a token in BPS, overriding the global one
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn set_token_stable_commission_percent() {
+    let SetTokenStableCommissionPercentArgs {
+        token_contract,
+        stable_commission_percent,
+    } = SetTokenStableCommissionPercentArgs::from_named_args();
+    contract_bridge::contract::set_token_stable_commission_percent(
+        token_contract,
+        stable_commission_percent,
+    );
+}
+
+/// Remove commission percent override of a token
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn clear_token_stable_commission_percent() {
+    let ClearTokenStableCommissionPercentArgs { token_contract } =
+        ClearTokenStableCommissionPercentArgs::from_named_args();
+    contract_bridge::contract::clear_token_stable_commission_percent(token_contract);
+}
+
+/// Get commission percent applied to a token
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_token_stable_commission_percent() {
+    let GetTokenStableCommissionPercentArgs { token_contract } =
+        GetTokenStableCommissionPercentArgs::from_named_args();
+    let res = contract_bridge::contract::get_token_stable_commission_percent(token_contract);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
 /// Manually set signer
 ///
 /// Call context:
 #[no_mangle]
 // #[inline(always)]
 pub extern "C" fn set_signer() {
-    let signer: String = runtime::get_named_arg(PARAM_SIGNER);
+    let SetSignerArgs { signer } = SetSignerArgs::from_named_args();
     contract_bridge::contract::set_signer(signer);
 }
 
@@ -168,14 +269,36 @@ pub extern "C" fn get_signer() {
     runtime::ret(CLValue::from_t(res).unwrap_or_revert());
 }
 
+/// Replace the signer set and its threshold
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn set_signer_set() {
+    let SetSignerSetArgs { signers, threshold } = SetSignerSetArgs::from_named_args();
+    contract_bridge::contract::set_signer_set(signers, threshold);
+}
+
+/// Get signer set
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_signer_set() {
+    let res = contract_bridge::contract::get_signer_set();
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
 /// Get total commission
 ///
 /// Call context:
 #[no_mangle]
 pub extern "C" fn get_total_commission() {
-    let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
-    let gas_commission: U256 = runtime::get_named_arg(PARAM_GAS_COMMISSION);
-    let res = contract_bridge::contract::get_total_commission(amount, gas_commission);
+    let GetTotalCommissionArgs {
+        token_contract,
+        amount,
+        gas_commission,
+    } = GetTotalCommissionArgs::from_named_args();
+    let res =
+        contract_bridge::contract::get_total_commission(token_contract, amount, gas_commission);
     runtime::ret(CLValue::from_t(res).unwrap_or_revert());
 }
 
@@ -183,14 +306,406 @@ pub extern "C" fn get_total_commission() {
 ///
 /// Call context:
 #[no_mangle]
-pub extern "C" fn get_commission_by_token() {
-    let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
+pub extern "C" fn get_commission_pool() {
+    let GetCommissionPoolArgs { token_contract } = GetCommissionPoolArgs::from_named_args();
     let res = contract_bridge::contract::get_commission_by_token(token_contract);
     runtime::ret(CLValue::from_t(res).unwrap_or_revert());
 }
 
+/// Get liquidity of a token available to `bridge_out`
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_available_liquidity() {
+    let GetAvailableLiquidityArgs { token_contract } = GetAvailableLiquidityArgs::from_named_args();
+    let res = contract_bridge::contract::get_available_liquidity(token_contract);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Stop user-facing functionality of the bridge
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn pause() {
+    contract_bridge::contract::pause();
+}
+
+/// Resume user-facing functionality of the bridge
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn unpause() {
+    contract_bridge::contract::unpause();
+}
+
+/// Allow a token to be bridged
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn add_token() {
+    let AddTokenArgs { token_contract } = AddTokenArgs::from_named_args();
+    contract_bridge::contract::add_token(token_contract);
+}
+
+/// Disallow a token to be bridged
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn remove_token() {
+    let RemoveTokenArgs { token_contract } = RemoveTokenArgs::from_named_args();
+    contract_bridge::contract::remove_token(token_contract);
+}
+
+/// Get token config
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_token_config() {
+    let GetTokenConfigArgs { token_contract } = GetTokenConfigArgs::from_named_args();
+    let res = contract_bridge::contract::get_token_config(token_contract);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Set minimal and maximal `bridge_in` amounts of a token
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn set_bridge_in_limits() {
+    let SetBridgeInLimitsArgs {
+        token_contract,
+        min_amount,
+        max_amount,
+    } = SetBridgeInLimitsArgs::from_named_args();
+    contract_bridge::contract::set_bridge_in_limits(token_contract, min_amount, max_amount);
+}
+
+/// Set per-transfer and windowed `bridge_out` caps of a token
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn set_bridge_out_limits() {
+    let SetBridgeOutLimitsArgs {
+        token_contract,
+        max_amount,
+        outflow_cap,
+        outflow_window,
+    } = SetBridgeOutLimitsArgs::from_named_args();
+    contract_bridge::contract::set_bridge_out_limits(
+        token_contract,
+        max_amount,
+        outflow_cap,
+        outflow_window,
+    );
+}
+
+/// Get token limits
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_token_limits() {
+    let GetTokenLimitsArgs { token_contract } = GetTokenLimitsArgs::from_named_args();
+    let res = contract_bridge::contract::get_token_limits(token_contract);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Get `bridge_out` volume of the current window
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_outflow_volume() {
+    let GetOutflowVolumeArgs { token_contract } = GetOutflowVolumeArgs::from_named_args();
+    let res = contract_bridge::contract::get_outflow_volume(token_contract);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Grant a role to an account
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn grant_role() {
+    let GrantRoleArgs { role, account } = GrantRoleArgs::from_named_args();
+    contract_bridge::contract::grant_role(role, account);
+}
+
+/// Revoke a role from an account
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn revoke_role() {
+    let RevokeRoleArgs { role, account } = RevokeRoleArgs::from_named_args();
+    contract_bridge::contract::revoke_role(role, account);
+}
+
+/// Store the access URef of a granted role into the caller's named keys
+///
+/// Call context: session
+#[no_mangle]
+pub extern "C" fn claim_role() {
+    let ClaimRoleArgs { role } = ClaimRoleArgs::from_named_args();
+    contract_bridge::contract::claim_role(role);
+}
+
+/// Hand over the access URef of a granted role to `claim_role`
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn take_role_access() {
+    let TakeRoleAccessArgs { role } = TakeRoleAccessArgs::from_named_args();
+    let res = contract_bridge::contract::take_role_access(role);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Check whether an account holds a role
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn has_role() {
+    let HasRoleArgs { role, account } = HasRoleArgs::from_named_args();
+    let res = contract_bridge::contract::has_role(role, account);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Check whether a signature nonce has already been used
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn is_nonce_used() {
+    let IsNonceUsedArgs { nonce } = IsNonceUsedArgs::from_named_args();
+    let res = contract_bridge::contract::is_nonce_used(nonce);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Check whether an inbound transfer was already paid out by `bridge_out`
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn is_transfer_processed() {
+    let IsTransferProcessedArgs {
+        source_chain,
+        transaction_id,
+    } = IsTransferProcessedArgs::from_named_args();
+    let res = contract_bridge::contract::is_transfer_processed(source_chain, transaction_id);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Bring the state of a deployment made by an older version up to date
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn migrate() {
+    let MigrateArgs { chain_name } = MigrateArgs::from_named_args();
+    contract_bridge::contract::migrate(chain_name);
+}
+
+/// Transfers native CSPR from the given purse to the bridge, with metadata specifying the destination chain.
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn bridge_in_native() {
+    let BridgeInNativeArgs {
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
+        purse,
+    } = BridgeInNativeArgs::from_named_args();
+
+    contract_bridge::contract::bridge_in_native(
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
+        purse,
+    );
+}
+
+/// Transfers native CSPR from the bridge.
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn bridge_out_native() {
+    let BridgeOutNativeArgs {
+        amount,
+        transaction_id,
+        source_chain,
+        source_address,
+        recipient,
+    } = BridgeOutNativeArgs::from_named_args();
+
+    contract_bridge::contract::bridge_out_native(
+        amount,
+        transaction_id,
+        source_chain,
+        source_address,
+        recipient,
+    );
+}
+
+/// Withdraw commission collected in native CSPR to the specified owner account
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn withdraw_native_commission() {
+    let WithdrawNativeCommissionArgs { amount, recipient } =
+        WithdrawNativeCommissionArgs::from_named_args();
+    contract_bridge::contract::withdraw_native_commission(amount, recipient);
+}
+
+/// Get commission collected in native CSPR
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_native_commission() {
+    let res = contract_bridge::contract::get_native_commission();
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Choose whether a token is locked or minted and burned by the bridge
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn set_token_mode() {
+    let SetTokenModeArgs {
+        token_contract,
+        mode,
+    } = SetTokenModeArgs::from_named_args();
+    contract_bridge::contract::set_token_mode(token_contract, mode);
+}
+
+/// Get supply of a wrapped token minted by the bridge
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_wrapped_supply() {
+    let GetWrappedSupplyArgs { token_contract } = GetWrappedSupplyArgs::from_named_args();
+    let res = contract_bridge::contract::get_wrapped_supply(token_contract);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Move pool funds that are not collected commission to the treasury
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn rebalance_liquidity() {
+    let RebalanceLiquidityArgs {
+        token_contract,
+        amount,
+    } = RebalanceLiquidityArgs::from_named_args();
+    contract_bridge::contract::rebalance_liquidity(token_contract, amount);
+}
+
+/// Top up the pool of a token through an allowance
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn deposit_liquidity() {
+    let DepositLiquidityArgs {
+        token_contract,
+        amount,
+    } = DepositLiquidityArgs::from_named_args();
+    contract_bridge::contract::deposit_liquidity(token_contract, amount);
+}
+
+/// Schedule a privileged change, executable once the timelock delay has elapsed
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn propose_change() {
+    let ProposeChangeArgs { change } = ProposeChangeArgs::from_named_args();
+    contract_bridge::contract::propose_change(change);
+}
+
+/// Apply a proposed change whose timelock has elapsed
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn execute_change() {
+    let ExecuteChangeArgs { proposal_id: id } = ExecuteChangeArgs::from_named_args();
+    contract_bridge::contract::execute_change(id);
+}
+
+/// Drop a pending change proposal
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn cancel_change() {
+    let CancelChangeArgs { proposal_id: id } = CancelChangeArgs::from_named_args();
+    contract_bridge::contract::cancel_change(id);
+}
+
+/// Get a pending change proposal
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_proposal() {
+    let GetProposalArgs { proposal_id: id } = GetProposalArgs::from_named_args();
+    let res = contract_bridge::contract::get_proposal(id);
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Set the delay between proposing a change and executing it
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn set_timelock_delay() {
+    let SetTimelockDelayArgs { delay } = SetTimelockDelayArgs::from_named_args();
+    contract_bridge::contract::set_timelock_delay(delay);
+}
+
+/// Get the delay between proposing a change and executing it
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_timelock_delay() {
+    let res = contract_bridge::contract::get_timelock_delay();
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Set how far ahead of the block time signed deadlines may be
+///
+/// Call context: contract
+#[no_mangle]
+pub extern "C" fn set_max_deadline_horizon() {
+    let SetMaxDeadlineHorizonArgs { horizon } = SetMaxDeadlineHorizonArgs::from_named_args();
+    contract_bridge::contract::set_max_deadline_horizon(horizon);
+}
+
+/// Get how far ahead of the block time signed deadlines may be
+///
+/// Call context:
+#[no_mangle]
+pub extern "C" fn get_max_deadline_horizon() {
+    let res = contract_bridge::contract::get_max_deadline_horizon();
+    runtime::ret(CLValue::from_t(res).unwrap_or_revert());
+}
+
+/// Installs the bridge, or upgrades it if the caller account already holds an installed one.
 #[no_mangle]
 pub extern "C" fn call() {
-    let signer: String = runtime::get_named_arg(PARAM_SIGNER);
-    contract_bridge::contract::install(signer);
+    if runtime::has_key(NK_CONTRACT) && runtime::has_key(NK_ACCESS_UREF) {
+        let disable_old_version: bool = runtime::get_named_arg(PARAM_DISABLE_OLD_VERSION);
+        let chain_name: String = runtime::get_named_arg(PARAM_CHAIN_NAME);
+        contract_bridge::contract::upgrade(disable_old_version, chain_name);
+    } else {
+        let signer: String = runtime::get_named_arg(PARAM_SIGNER);
+        let chain_name: String = runtime::get_named_arg(PARAM_CHAIN_NAME);
+        let event_mode: u8 = runtime::get_named_arg(PARAM_EVENT_MODE);
+        let signature_mode: u8 = runtime::get_named_arg(PARAM_SIGNATURE_MODE);
+        let timelock_delay: u64 = runtime::get_named_arg(PARAM_TIMELOCK_DELAY);
+        contract_bridge::contract::install(
+            signer,
+            chain_name,
+            event_mode,
+            signature_mode,
+            timelock_delay,
+        );
+    }
 }
@@ -0,0 +1,56 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+extern crate contract_bridge;
+
+use alloc::{string::String, vec::Vec};
+use casper_contract::{
+    contract_api::{account, runtime, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::Bytes, ContractHash, U128, U256};
+use contract_bridge::{
+    entry_points::{
+        PARAM_AMOUNT, PARAM_BRIDGE_CONTRACT, PARAM_DEADLINE, PARAM_DESTINATION_ADDRESS,
+        PARAM_DESTINATION_CHAIN, PARAM_GAS_COMMISSION, PARAM_NONCE, PARAM_SIGNATURES,
+        PARAM_TRANSACTION_ID,
+    },
+    interface, native,
+};
+
+/// Moves `amount` motes from the caller's main purse into a fresh purse and bridges it in.
+#[no_mangle]
+pub extern "C" fn call() {
+    let bridge_contract: ContractHash = runtime::get_named_arg(PARAM_BRIDGE_CONTRACT);
+    let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
+    let gas_commission: U256 = runtime::get_named_arg(PARAM_GAS_COMMISSION);
+    let deadline: U256 = runtime::get_named_arg(PARAM_DEADLINE);
+    let nonce: U128 = runtime::get_named_arg(PARAM_NONCE);
+    let transaction_id: U256 = runtime::get_named_arg(PARAM_TRANSACTION_ID);
+    let destination_chain: String = runtime::get_named_arg(PARAM_DESTINATION_CHAIN);
+    let destination_address: String = runtime::get_named_arg(PARAM_DESTINATION_ADDRESS);
+    let signatures: Vec<Bytes> = runtime::get_named_arg(PARAM_SIGNATURES);
+
+    let purse = system::create_purse();
+    system::transfer_from_purse_to_purse(
+        account::get_main_purse(),
+        purse,
+        native::to_motes(amount),
+        None,
+    )
+    .unwrap_or_revert();
+
+    interface::onchain::bridge_in_native(
+        bridge_contract,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
+        purse,
+    );
+}
@@ -0,0 +1,70 @@
+#![no_std]
+#![no_main]
+
+extern crate alloc;
+extern crate contract_bridge;
+
+use alloc::string::String;
+use casper_contract::contract_api::{runtime, storage};
+use casper_types::{ApiError, ContractHash, ContractPackageHash, URef, U128, U256};
+use contract_bridge::{
+    entry_points::{
+        EP_GET_AVAILABLE_LIQUIDITY, EP_GET_COMMISSION_POOL, EP_GET_TOTAL_COMMISSION,
+        EP_IS_NONCE_USED, EP_IS_TRANSFER_PROCESSED, PARAM_AMOUNT, PARAM_BRIDGE_CONTRACT,
+        PARAM_ENTRY_POINT, PARAM_GAS_COMMISSION, PARAM_NONCE, PARAM_SOURCE_CHAIN,
+        PARAM_TOKEN_CONTRACT, PARAM_TRANSACTION_ID,
+    },
+    interface,
+};
+
+/// Calls a read-only entry point of the bridge and stores the value it returns under a named key
+/// of the caller, named after the entry point.
+#[no_mangle]
+pub extern "C" fn call() {
+    let bridge_contract: ContractHash = runtime::get_named_arg(PARAM_BRIDGE_CONTRACT);
+    let entry_point: String = runtime::get_named_arg(PARAM_ENTRY_POINT);
+
+    let value: URef = match entry_point.as_str() {
+        EP_IS_NONCE_USED => {
+            let nonce: U128 = runtime::get_named_arg(PARAM_NONCE);
+            storage::new_uref(interface::onchain::is_nonce_used(bridge_contract, nonce))
+        }
+        EP_IS_TRANSFER_PROCESSED => {
+            let source_chain: String = runtime::get_named_arg(PARAM_SOURCE_CHAIN);
+            let transaction_id: U256 = runtime::get_named_arg(PARAM_TRANSACTION_ID);
+            storage::new_uref(interface::onchain::is_transfer_processed(
+                bridge_contract,
+                source_chain,
+                transaction_id,
+            ))
+        }
+        EP_GET_COMMISSION_POOL => {
+            let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
+            storage::new_uref(interface::onchain::get_commission_pool(
+                bridge_contract,
+                token_contract,
+            ))
+        }
+        EP_GET_AVAILABLE_LIQUIDITY => {
+            let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
+            storage::new_uref(interface::onchain::get_available_liquidity(
+                bridge_contract,
+                token_contract,
+            ))
+        }
+        EP_GET_TOTAL_COMMISSION => {
+            let token_contract: ContractPackageHash = runtime::get_named_arg(PARAM_TOKEN_CONTRACT);
+            let amount: U256 = runtime::get_named_arg(PARAM_AMOUNT);
+            let gas_commission: U256 = runtime::get_named_arg(PARAM_GAS_COMMISSION);
+            storage::new_uref(interface::onchain::get_total_commission(
+                bridge_contract,
+                token_contract,
+                amount,
+                gas_commission,
+            ))
+        }
+        _ => runtime::revert(ApiError::InvalidArgument),
+    };
+
+    runtime::put_key(&entry_point, value.into());
+}
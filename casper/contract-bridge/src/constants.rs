@@ -3,6 +3,74 @@
 /// Name of dictionary-key for `nonces`
 pub const USED_NONCES_KEY_NAME: &str = "used_nonces";
 pub const COMMISSIONS_BY_TOKEN_KEY_NAME: &str = "commission_by_token";
+/// Name of dictionary-key for per-token stable commission percent overrides
+pub const STABLE_COMMISSION_BY_TOKEN_KEY_NAME: &str = "stable_commission_by_token";
+/// Name of dictionary-key for registered tokens
+pub const TOKEN_REGISTRY_KEY_NAME: &str = "token_registry";
+/// Name of dictionary-key for transfer limits
+pub const LIMITS_BY_TOKEN_KEY_NAME: &str = "limits_by_token";
+/// Name of dictionary-key for `bridge_out` volume within the current window
+pub const OUTFLOW_BY_TOKEN_KEY_NAME: &str = "outflow_by_token";
+/// Name of dictionary-key for the supply of wrapped tokens minted by the bridge
+pub const WRAPPED_SUPPLY_BY_TOKEN_KEY_NAME: &str = "wrapped_supply_by_token";
+/// Name of dictionary-key for inbound transfers already paid out by `bridge_out`
+pub const PROCESSED_TRANSFERS_KEY_NAME: &str = "processed_transfers";
+/// Name of dictionary-key for access URefs granted per role and account
+pub const ROLE_GRANTS_KEY_NAME: &str = "role_grants";
+/// Name of dictionary-key for pending change proposals by id
+pub const PROPOSALS_KEY_NAME: &str = "proposals";
+/// Name of the named key holding the single signer of deployments made before signer sets
+pub const SIGNER_KEY_NAME: &str = "signer";
+/// Name of the named key holding the signer set of deployments made before signers were stored
+/// parsed
+pub const SIGNER_SET_KEY_NAME: &str = "signer_set";
+/// Name of the named key holding the signer set and its threshold
+pub const SIGNER_KEYS_KEY_NAME: &str = "signer_keys";
+/// Name of the named key holding the name of the chain the contract is installed on
+pub const CHAIN_NAME_KEY_NAME: &str = "chain_name";
+/// Name of the named key holding the purse with bridged native CSPR
+pub const NATIVE_PURSE_KEY_NAME: &str = "native_purse";
+/// Name of the named key holding the commission collected in native CSPR
+pub const NATIVE_COMMISSION_KEY_NAME: &str = "native_commission";
+/// Name of the named key holding the `paused` flag
+pub const PAUSED_KEY_NAME: &str = "paused";
+/// Name of the named key holding the id of the next change proposal
+pub const NEXT_PROPOSAL_ID_KEY_NAME: &str = "next_proposal_id";
+/// Name of the named key holding the delay between proposing and executing a change
+pub const TIMELOCK_DELAY_KEY_NAME: &str = "timelock_delay";
+/// Name of the named key holding how far ahead of the block time a signature deadline may be
+pub const MAX_DEADLINE_HORIZON_KEY_NAME: &str = "max_deadline_horizon";
+/// Name of the named key holding the treasury receiving rebalanced liquidity
+pub const TREASURY_KEY_NAME: &str = "treasury";
+/// Name of the named key holding the signature mode selected at install
+pub const SIGNATURE_MODE_KEY_NAME: &str = "signature_mode";
+/// Name of the named key marking that `stable_commission_percent` is denominated in BPS
+pub const STABLE_COMMISSION_IN_BPS_KEY_NAME: &str = "stable_commission_in_bps";
 pub const NK_ACCESS_UREF: &str = "bridge_contract_uref";
 pub const NK_CONTRACT: &str = "bridge_contract";
+pub const NK_CONTRACT_PACKAGE: &str = "bridge_contract_package";
+/// Access group of deployments made before role-based access control.
 pub const GROUP_OPERATOR: &str = "operator";
+
+/// Role allowed to release bridged funds with `bridge_out`.
+pub const ROLE_RELAYER: &str = "relayer";
+/// Role allowed to withdraw and configure commissions.
+pub const ROLE_FEE_MANAGER: &str = "fee_manager";
+/// Role allowed to manage signers, tokens, limits and roles.
+pub const ROLE_ADMIN: &str = "admin";
+/// Role allowed to pause and unpause the bridge.
+pub const ROLE_PAUSER: &str = "pauser";
+/// All roles, each backed by a contract user group of the same name.
+pub const ROLES: [&str; 4] = [ROLE_RELAYER, ROLE_FEE_MANAGER, ROLE_ADMIN, ROLE_PAUSER];
+
+/// Default commission percent (in BPS) for an instantiated bridge contract.
+pub const DEFAULT_STABLE_COMMISSION_BPS: u64 = 400;
+/// Maximal commission percent (in BPS) the fee manager may set.
+pub const MAX_STABLE_COMMISSION_BPS: u64 = 5000;
+/// Hundred percent in BPS.
+pub const HUNDRED_PERCENT_BPS: u64 = 10000;
+/// Default maximal distance between the block time and a signature deadline: 30 days, in
+/// milliseconds like the block time.
+pub const DEFAULT_MAX_DEADLINE_HORIZON: u64 = 30 * 24 * 60 * 60 * 1000;
+/// Default delay between proposing and executing a change: 2 days.
+pub const DEFAULT_TIMELOCK_DELAY: u64 = 2 * 24 * 60 * 60 * 1000;
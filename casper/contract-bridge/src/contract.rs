@@ -1,66 +1,320 @@
-use alloc::string::String;
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::convert::TryFrom;
 
 use casper_contract::{
     contract_api::{
         runtime::{self, revert},
-        storage,
+        storage, system,
     },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    account::AccountHash, bytesrepr::Bytes, contracts::NamedKeys, system::CallStackElement,
-    ContractPackageHash, EntryPoints, Key, U128, U256,
+    account::AccountHash,
+    bytesrepr::Bytes,
+    contracts::{self, NamedKeys},
+    system::CallStackElement,
+    ApiError, ContractHash, ContractPackageHash, EntryPoints, Key, URef, U128, U256,
 };
 use contract_util::{
     caller_context, current_contract, erc20,
-    event::fire,
-    signatures::{check_public_key, cook_msg_bridge_in, cook_msg_transfer_out},
+    event::{fire, EventMode, EVENTS_SCHEMA_UREF_NAME, EVENT_MODE_UREF_NAME},
+    signatures::{
+        cook_msg_bridge_in, cook_msg_transfer_out, find_signer, parse_signer, SignatureMode,
+        SignerId,
+    },
 };
 
 use crate::{
     commissions,
-    constants::{COMMISSIONS_BY_TOKEN_KEY_NAME, NK_ACCESS_UREF, NK_CONTRACT, USED_NONCES_KEY_NAME},
-    entry_points::{self, PARAM_SIGNER, PARAM_STABLE_COMMISSION_PERCENT},
+    constants::{
+        CHAIN_NAME_KEY_NAME, COMMISSIONS_BY_TOKEN_KEY_NAME, DEFAULT_MAX_DEADLINE_HORIZON,
+        DEFAULT_STABLE_COMMISSION_BPS, DEFAULT_TIMELOCK_DELAY, GROUP_OPERATOR, HUNDRED_PERCENT_BPS,
+        LIMITS_BY_TOKEN_KEY_NAME, MAX_DEADLINE_HORIZON_KEY_NAME, MAX_STABLE_COMMISSION_BPS,
+        NATIVE_COMMISSION_KEY_NAME, NATIVE_PURSE_KEY_NAME, NEXT_PROPOSAL_ID_KEY_NAME,
+        NK_ACCESS_UREF, NK_CONTRACT, NK_CONTRACT_PACKAGE, OUTFLOW_BY_TOKEN_KEY_NAME,
+        PAUSED_KEY_NAME, PROCESSED_TRANSFERS_KEY_NAME, PROPOSALS_KEY_NAME, ROLES,
+        ROLE_GRANTS_KEY_NAME, SIGNATURE_MODE_KEY_NAME, SIGNER_KEYS_KEY_NAME, SIGNER_KEY_NAME,
+        SIGNER_SET_KEY_NAME, STABLE_COMMISSION_BY_TOKEN_KEY_NAME,
+        STABLE_COMMISSION_IN_BPS_KEY_NAME, TIMELOCK_DELAY_KEY_NAME, TOKEN_REGISTRY_KEY_NAME,
+        TREASURY_KEY_NAME, USED_NONCES_KEY_NAME, WRAPPED_SUPPLY_BY_TOKEN_KEY_NAME,
+    },
+    entry_points::{self, PARAM_CONTRACT_PACKAGE_HASH, PARAM_STABLE_COMMISSION_PERCENT},
     error::BridgeError,
-    interface, uref, used_nonces,
+    interface,
+    limits::{self, TokenLimits},
+    native, processed_transfers, roles,
+    signers::{self, LegacySignerSet, SignerSet},
+    stable_commissions,
+    timelock::{self, Proposal},
+    token_registry::{self, TokenConfig, TokenMode},
+    uref, used_nonces, util, wrapped_supply,
+};
+use casper_common::{
+    change::Change,
+    event::{event_schemas, BridgeEvent, NATIVE_TOKEN_CONTRACT},
 };
-use casper_common::event::BridgeEvent;
 
-pub fn install(signer: String) {
-    let mut named_keys = NamedKeys::new();
-    let default_percent = storage::new_uref(U256::one() * 3);
-    let default_signer = storage::new_uref(signer);
+pub fn install(
+    signer: String,
+    chain_name: String,
+    event_mode: u8,
+    signature_mode: u8,
+    timelock_delay: u64,
+) {
+    let event_mode = EventMode::try_from(event_mode)
+        .ok()
+        .unwrap_or_revert_with(BridgeError::InvalidEventMode);
+    let signature_mode = SignatureMode::try_from(signature_mode)
+        .ok()
+        .unwrap_or_revert_with(BridgeError::InvalidSignatureMode);
+
+    let default_signer_set = {
+        let signers = if signer.is_empty() {
+            Vec::new()
+        } else {
+            parse_signers(signature_mode, &[signer])
+        };
+        let threshold = signers.len() as u8;
+        SignerSet { signers, threshold }
+    };
+    let mut named_keys = default_named_keys(
+        default_signer_set,
+        chain_name,
+        event_mode,
+        signature_mode,
+        timelock_delay,
+        |_| true,
+    );
+    named_keys.insert(
+        String::from(STABLE_COMMISSION_IN_BPS_KEY_NAME),
+        Key::URef(storage::new_uref(true)),
+    );
 
-    let used_nonces_uref = storage::new_dictionary(USED_NONCES_KEY_NAME).unwrap_or_revert();
+    let (contract_package_hash, access_uref) = storage::create_contract_package_at_hash();
 
-    let used_nonces_dictionary_key = {
-        runtime::remove_key(USED_NONCES_KEY_NAME);
-        Key::from(used_nonces_uref)
+    // The contract provisions and removes role URefs itself, which needs the package access URef.
+    named_keys.insert(String::from(NK_ACCESS_UREF), access_uref.into());
+
+    let (contract_hash, _) =
+        storage::add_contract_version(contract_package_hash, default_entry_points(), named_keys);
+
+    // The installer holds the package access URef, which belongs to every role group.
+    for role in ROLES {
+        storage::create_contract_user_group(contract_package_hash, role, 0, [access_uref].into())
+            .unwrap_or_revert();
+    }
+
+    runtime::put_key(NK_ACCESS_UREF, access_uref.into());
+    runtime::put_key(NK_CONTRACT, contract_hash.into());
+    runtime::put_key(NK_CONTRACT_PACKAGE, contract_package_hash.into());
+}
+
+/// Add a new version of the installed contract to its package, keeping the state of the old one.
+///
+/// Named keys of the old version are carried forward by the execution engine. Keys introduced
+/// since then are created by `migrate`; for example, only deployments made before `chain_name`
+/// was kept in the contract lack it, and they receive the `chain_name` passed here.
+pub fn upgrade(disable_old_version: bool, chain_name: String) {
+    let contract_package_hash = match runtime::get_key(NK_CONTRACT_PACKAGE) {
+        Some(key) => ContractPackageHash::new(key.into_hash().unwrap_or_revert()),
+        // Deployments made before the package hash was kept in the installer account.
+        None => runtime::get_named_arg(PARAM_CONTRACT_PACKAGE_HASH),
     };
+    let old_contract_hash = runtime::get_key(NK_CONTRACT)
+        .and_then(Key::into_hash)
+        .map(ContractHash::new)
+        .unwrap_or_revert();
+    let access_uref = util::get_uref(NK_ACCESS_UREF);
 
-    named_keys.insert(
-        String::from(USED_NONCES_KEY_NAME),
-        used_nonces_dictionary_key,
-    );
+    let mut named_keys = NamedKeys::new();
+    named_keys.insert(String::from(NK_ACCESS_UREF), access_uref.into());
+
+    let (contract_hash, _) =
+        storage::add_contract_version(contract_package_hash, default_entry_points(), named_keys);
+
+    retire_operator_group(contract_package_hash, access_uref);
+
+    // Groups created by an earlier install or upgrade already exist.
+    for role in ROLES {
+        let _ = storage::create_contract_user_group(
+            contract_package_hash,
+            role,
+            0,
+            [access_uref].into(),
+        );
+    }
+
+    if disable_old_version {
+        storage::disable_contract_version(contract_package_hash, old_contract_hash)
+            .unwrap_or_revert();
+    }
 
-    let commissions_by_tokens_uref =
-        storage::new_dictionary(COMMISSIONS_BY_TOKEN_KEY_NAME).unwrap_or_revert();
+    interface::onchain::migrate(contract_hash, chain_name);
 
-    let commissions_by_tokens_dictionary_key = { 
-        runtime::remove_key(COMMISSIONS_BY_TOKEN_KEY_NAME);
-        Key::from(commissions_by_tokens_uref) 
+    runtime::put_key(NK_CONTRACT, contract_hash.into());
+    runtime::put_key(NK_CONTRACT_PACKAGE, contract_package_hash.into());
+}
+
+/// Take the package access URef out of the operator group of deployments made before role-based
+/// access control.
+///
+/// The execution engine refuses to remove a group that entry points of any version in the
+/// package still use, disabled versions included, and the first versions gate their entry points
+/// by the operator group. Emptying it instead leaves those entry points callable by no one.
+fn retire_operator_group(contract_package_hash: ContractPackageHash, access_uref: URef) {
+    let group_in_use = ApiError::from(contracts::Error::GroupInUse);
+    let result = match storage::remove_contract_user_group(contract_package_hash, GROUP_OPERATOR) {
+        Err(error) if error == group_in_use => storage::remove_contract_user_group_urefs(
+            contract_package_hash,
+            GROUP_OPERATOR,
+            [access_uref].into(),
+        ),
+        result => result,
     };
-    named_keys.insert(
-        String::from(COMMISSIONS_BY_TOKEN_KEY_NAME),
-        commissions_by_tokens_dictionary_key,
+
+    // Deployments installed with roles never had the group, and earlier upgrades emptied it.
+    let group_missing = ApiError::from(contracts::Error::GroupDoesNotExist);
+    let group_emptied = ApiError::from(contracts::Error::UnableToRemoveURef);
+    match result {
+        Err(error) if error == group_missing || error == group_emptied => {}
+        result => result.unwrap_or_revert(),
+    }
+}
+
+/// Bring the state of a deployment made by an older version up to date
+pub fn migrate(chain_name: String) {
+    // Deployments keep the event and signature modes they were installed with.
+    let named_keys = default_named_keys(
+        SignerSet::default(),
+        chain_name,
+        EventMode::Legacy,
+        SignatureMode::Raw,
+        DEFAULT_TIMELOCK_DELAY,
+        |name| !runtime::has_key(name),
     );
+    for (name, key) in named_keys {
+        runtime::put_key(&name, key);
+    }
+
+    // Signers of older deployments are stored as given, they are parsed once here.
+    if runtime::has_key(SIGNER_SET_KEY_NAME) {
+        let LegacySignerSet { signers, threshold } = uref::read(SIGNER_SET_KEY_NAME);
+        runtime::remove_key(SIGNER_SET_KEY_NAME);
+
+        signers::write(SignerSet {
+            signers: parse_signers(get_signature_mode(), &signers),
+            threshold,
+        });
+    }
 
-    let default_percent_key_name = String::from(PARAM_STABLE_COMMISSION_PERCENT);
-    named_keys.insert(default_percent_key_name, Key::URef(default_percent));
+    if runtime::has_key(SIGNER_KEY_NAME) {
+        let signer: String = uref::read(SIGNER_KEY_NAME);
+        runtime::remove_key(SIGNER_KEY_NAME);
 
-    let signer_key = String::from(PARAM_SIGNER);
-    named_keys.insert(signer_key, Key::URef(default_signer));
+        if signers::read().signers.is_empty() && !signer.is_empty() {
+            signers::write(SignerSet {
+                signers: parse_signers(get_signature_mode(), &[signer]),
+                threshold: 1,
+            });
+        }
+    }
+
+    // Events introduced by the new version have to be described to CES indexers.
+    if contract_util::event::mode() == EventMode::Ces {
+        uref::write(EVENTS_SCHEMA_UREF_NAME, event_schemas());
+    }
+}
+
+/// Named keys of a fresh deployment, without the package access URef.
+///
+/// Only keys for which `is_missing` holds are created, so that an upgraded deployment doesn't
+/// allocate state it already has.
+fn default_named_keys(
+    signer_set: SignerSet,
+    chain_name: String,
+    event_mode: EventMode,
+    signature_mode: SignatureMode,
+    timelock_delay: u64,
+    is_missing: impl Fn(&str) -> bool,
+) -> NamedKeys {
+    let mut named_keys = NamedKeys::new();
+
+    for name in [
+        USED_NONCES_KEY_NAME,
+        COMMISSIONS_BY_TOKEN_KEY_NAME,
+        STABLE_COMMISSION_BY_TOKEN_KEY_NAME,
+        TOKEN_REGISTRY_KEY_NAME,
+        LIMITS_BY_TOKEN_KEY_NAME,
+        OUTFLOW_BY_TOKEN_KEY_NAME,
+        PROCESSED_TRANSFERS_KEY_NAME,
+        WRAPPED_SUPPLY_BY_TOKEN_KEY_NAME,
+        ROLE_GRANTS_KEY_NAME,
+        PROPOSALS_KEY_NAME,
+    ] {
+        if is_missing(name) {
+            let dictionary_uref = storage::new_dictionary(name).unwrap_or_revert();
+            // The dictionary is created under the caller named keys, it is moved to the contract.
+            runtime::remove_key(name);
+            named_keys.insert(String::from(name), Key::from(dictionary_uref));
+        }
+    }
+
+    let values: [(&str, &dyn Fn() -> URef); 11] = [
+        (PARAM_STABLE_COMMISSION_PERCENT, &|| {
+            storage::new_uref(U256::from(DEFAULT_STABLE_COMMISSION_BPS))
+        }),
+        (SIGNER_KEYS_KEY_NAME, &|| {
+            storage::new_uref(signer_set.clone())
+        }),
+        (PAUSED_KEY_NAME, &|| storage::new_uref(false)),
+        (NEXT_PROPOSAL_ID_KEY_NAME, &|| storage::new_uref(0u64)),
+        (TIMELOCK_DELAY_KEY_NAME, &|| {
+            storage::new_uref(timelock_delay)
+        }),
+        (MAX_DEADLINE_HORIZON_KEY_NAME, &|| {
+            storage::new_uref(DEFAULT_MAX_DEADLINE_HORIZON)
+        }),
+        (TREASURY_KEY_NAME, &|| {
+            storage::new_uref(Option::<Key>::None)
+        }),
+        (NATIVE_PURSE_KEY_NAME, &system::create_purse),
+        (NATIVE_COMMISSION_KEY_NAME, &|| {
+            storage::new_uref(U256::zero())
+        }),
+        (CHAIN_NAME_KEY_NAME, &|| {
+            storage::new_uref(chain_name.clone())
+        }),
+        (SIGNATURE_MODE_KEY_NAME, &|| {
+            storage::new_uref(u8::from(signature_mode))
+        }),
+    ];
+    for (name, value) in values {
+        if is_missing(name) {
+            named_keys.insert(String::from(name), Key::URef(value()));
+        }
+    }
+
+    if is_missing(EVENT_MODE_UREF_NAME) {
+        let mut event_named_keys = NamedKeys::new();
+        contract_util::event::install(&mut event_named_keys, event_mode, event_schemas());
+        // Deployments made before the event mode was kept already have the event trigger.
+        named_keys.extend(
+            event_named_keys
+                .into_iter()
+                .filter(|(name, _)| is_missing(name)),
+        );
+    }
+
+    named_keys
+}
 
+/// Entry points of the current bridge contract version.
+pub fn default_entry_points() -> EntryPoints {
     let mut entry_points = EntryPoints::new();
 
     entry_points.add_entry_point(entry_points::bridge_in());
@@ -72,19 +326,52 @@ pub fn install(signer: String) {
     entry_points.add_entry_point(entry_points::set_stable_commission_percent());
     entry_points.add_entry_point(entry_points::set_signer());
     entry_points.add_entry_point(entry_points::get_signer());
+    entry_points.add_entry_point(entry_points::set_signer_set());
+    entry_points.add_entry_point(entry_points::get_signer_set());
     entry_points.add_entry_point(entry_points::get_stable_commission_percent());
-
-    contract_util::event::install(&mut named_keys);
-
-    let (contract_package_hash, access_uref) = storage::create_contract_package_at_hash();
-    let (contract_hash, _) =
-        storage::add_contract_version(contract_package_hash, entry_points, named_keys);
-
-    storage::create_contract_user_group(contract_package_hash, "operator", 0, [access_uref].into())
-        .unwrap_or_revert();
-
-    runtime::put_key(NK_ACCESS_UREF, access_uref.into());
-    runtime::put_key(NK_CONTRACT, contract_hash.into());
+    entry_points.add_entry_point(entry_points::migrate_stable_commission_to_bps());
+    entry_points.add_entry_point(entry_points::set_token_stable_commission_percent());
+    entry_points.add_entry_point(entry_points::clear_token_stable_commission_percent());
+    entry_points.add_entry_point(entry_points::get_token_stable_commission_percent());
+    entry_points.add_entry_point(entry_points::pause());
+    entry_points.add_entry_point(entry_points::unpause());
+    entry_points.add_entry_point(entry_points::add_token());
+    entry_points.add_entry_point(entry_points::remove_token());
+    entry_points.add_entry_point(entry_points::get_token_config());
+    entry_points.add_entry_point(entry_points::set_bridge_in_limits());
+    entry_points.add_entry_point(entry_points::set_bridge_out_limits());
+    entry_points.add_entry_point(entry_points::get_token_limits());
+    entry_points.add_entry_point(entry_points::get_outflow_volume());
+    entry_points.add_entry_point(entry_points::grant_role());
+    entry_points.add_entry_point(entry_points::revoke_role());
+    entry_points.add_entry_point(entry_points::claim_role());
+    entry_points.add_entry_point(entry_points::take_role_access());
+    entry_points.add_entry_point(entry_points::has_role());
+    entry_points.add_entry_point(entry_points::migrate());
+    entry_points.add_entry_point(entry_points::is_nonce_used());
+    entry_points.add_entry_point(entry_points::is_transfer_processed());
+    entry_points.add_entry_point(entry_points::bridge_in_native());
+    entry_points.add_entry_point(entry_points::bridge_out_native());
+    entry_points.add_entry_point(entry_points::withdraw_native_commission());
+    entry_points.add_entry_point(entry_points::get_native_commission());
+    entry_points.add_entry_point(entry_points::set_token_mode());
+    entry_points.add_entry_point(entry_points::get_wrapped_supply());
+    entry_points.add_entry_point(entry_points::bridge_in_from());
+    entry_points.add_entry_point(entry_points::rebalance_liquidity());
+    entry_points.add_entry_point(entry_points::deposit_liquidity());
+    entry_points.add_entry_point(entry_points::propose_change());
+    entry_points.add_entry_point(entry_points::execute_change());
+    entry_points.add_entry_point(entry_points::cancel_change());
+    entry_points.add_entry_point(entry_points::get_proposal());
+    entry_points.add_entry_point(entry_points::set_timelock_delay());
+    entry_points.add_entry_point(entry_points::get_timelock_delay());
+    entry_points.add_entry_point(entry_points::set_max_deadline_horizon());
+    entry_points.add_entry_point(entry_points::get_max_deadline_horizon());
+    entry_points.add_entry_point(entry_points::get_commission_pool());
+    entry_points.add_entry_point(entry_points::get_available_liquidity());
+    entry_points.add_entry_point(entry_points::get_total_commission());
+
+    entry_points
 }
 
 fn verify_caller_is_self() {
@@ -102,27 +389,161 @@ fn verify_caller_is_self() {
     }
 }
 
-fn verify_signature(bytes: Bytes, signature: [u8; 64]) {
-    let signer: String = uref::read(PARAM_SIGNER);
+/// Signature mode the contract was installed with.
+fn get_signature_mode() -> SignatureMode {
+    let mode: u8 = uref::read(SIGNATURE_MODE_KEY_NAME);
+    SignatureMode::try_from(mode)
+        .ok()
+        .unwrap_or_revert_with(BridgeError::InvalidSignatureMode)
+}
+
+/// Parses the signers of a signer set, reverting if one is malformed.
+fn parse_signers(mode: SignatureMode, signers: &[String]) -> Vec<SignerId> {
+    signers
+        .iter()
+        .map(|signer| {
+            parse_signer(mode, signer).unwrap_or_revert_with(BridgeError::InvalidSignerSet)
+        })
+        .collect()
+}
+
+/// Checks that `bytes` are signed by at least `threshold` distinct signers of the signer set.
+fn verify_signatures(bytes: Bytes, signatures: Vec<Bytes>) {
+    let signer_set = signers::read();
 
-    if signer.is_empty() {
+    if signer_set.signers.is_empty() {
         revert(BridgeError::SignerIsNotEstablished);
     }
 
-    let res = contract_util::signatures::verify_signature(&signer, &signature, &bytes);
+    let mode = get_signature_mode();
+    let mut approved = vec![false; signer_set.signers.len()];
+
+    for signature in signatures.iter() {
+        let index = find_signer(mode, &signer_set.signers, signature, &bytes)
+            .unwrap_or_revert_with(BridgeError::InvalidSignature);
+
+        if approved[index] {
+            revert(BridgeError::DuplicateSigner);
+        }
+        approved[index] = true;
+    }
 
-    if !res {
-        revert(BridgeError::InvalidSignature);
+    let approvals = approved.iter().filter(|approved| **approved).count();
+    if approvals < usize::from(signer_set.threshold) {
+        revert(BridgeError::NotEnoughSignatures);
     }
 }
 
+/// Signed deadlines must not have passed nor lie beyond the maximal deadline horizon. Deadlines
+/// are block times, in milliseconds.
 fn verify_deadline(deadline: U256) {
     let current_time: u64 = runtime::get_blocktime().into();
-    let deadline = U256::as_u64(&deadline);
 
-    if current_time > deadline {
+    if U256::from(current_time) > deadline {
         revert(BridgeError::ExpiredSignature)
     }
+
+    let furthest_deadline = current_time.saturating_add(get_max_deadline_horizon());
+    if deadline > U256::from(furthest_deadline) {
+        revert(BridgeError::DeadlineTooFar)
+    }
+}
+
+fn verify_not_paused() {
+    if is_paused() {
+        revert(BridgeError::ContractPaused)
+    }
+}
+
+fn verify_token_registered(token_contract: ContractPackageHash) {
+    if !token_registry::is_registered(token_contract) {
+        revert(BridgeError::TokenNotRegistered)
+    }
+}
+
+fn verify_bridge_in_limits(token_contract: ContractPackageHash, amount: U256) {
+    let limits = limits::read(token_contract);
+
+    if amount < limits.min_bridge_in {
+        revert(BridgeError::AmountBelowMinimum)
+    }
+
+    if !limits.max_bridge_in.is_zero() && amount > limits.max_bridge_in {
+        revert(BridgeError::AmountAboveMaximum)
+    }
+}
+
+/// Checks per-transfer and windowed `bridge_out` limits and accounts `amount` in the window.
+fn consume_bridge_out_limits(token_contract: ContractPackageHash, amount: U256) {
+    let limits = limits::read(token_contract);
+
+    if !limits.max_bridge_out.is_zero() && amount > limits.max_bridge_out {
+        revert(BridgeError::AmountExceedTransferLimit)
+    }
+
+    if limits.outflow_cap.is_zero() {
+        return;
+    }
+
+    let current_time: u64 = runtime::get_blocktime().into();
+    let bucket_end = limits::bucket_end(current_time, limits.outflow_window);
+    let (mut buckets, volume) =
+        current_outflow(current_time, limits.outflow_window, token_contract);
+
+    let volume = volume
+        .checked_add(amount)
+        .unwrap_or_revert_with(BridgeError::Overflow);
+
+    if volume > limits.outflow_cap {
+        revert(BridgeError::AmountExceedOutflowCap)
+    }
+
+    match buckets.last_mut() {
+        Some((end, bucket_volume)) if *end == bucket_end => *bucket_volume += amount,
+        _ => buckets.push((bucket_end, amount)),
+    }
+    limits::write_outflow(token_contract, buckets);
+}
+
+/// Returns the buckets still within the outflow window at `current_time` and the volume paid out
+/// in them.
+///
+/// A bucket stays accounted for a whole window after it ended, whatever window length it was
+/// recorded under, so changing `outflow_window` neither strands nor drops earlier payouts.
+fn current_outflow(
+    current_time: u64,
+    outflow_window: u64,
+    token_contract: ContractPackageHash,
+) -> (Vec<(u64, U256)>, U256) {
+    let mut buckets = limits::read_outflow(token_contract);
+    buckets.retain(|(end, _)| current_time < end.saturating_add(outflow_window));
+
+    let volume = buckets
+        .iter()
+        .fold(U256::zero(), |volume, (_, bucket_volume)| {
+            volume + bucket_volume
+        });
+    (buckets, volume)
+}
+
+fn verify_stable_commission_percent(value: U256) {
+    if value > U256::from(MAX_STABLE_COMMISSION_BPS) {
+        revert(BridgeError::InvalidCommissionPercent)
+    }
+}
+
+fn mark_stable_commission_in_bps() {
+    if !runtime::has_key(STABLE_COMMISSION_IN_BPS_KEY_NAME) {
+        runtime::put_key(
+            STABLE_COMMISSION_IN_BPS_KEY_NAME,
+            storage::new_uref(true).into(),
+        );
+    }
+}
+
+/// Name of the chain the contract is installed on, signed as part of every message
+fn chain_name() -> String {
+    uref::read(CHAIN_NAME_KEY_NAME)
 }
 
 fn verify_nonce(nonce: U128) {
@@ -131,54 +552,558 @@ fn verify_nonce(nonce: U128) {
     }
 }
 
+fn verify_transfer_not_processed(source_chain: &str, transaction_id: U256) {
+    if processed_transfers::is_processed(source_chain, transaction_id) {
+        revert(BridgeError::TransferAlreadyProcessed)
+    }
+}
+
+fn verify_role_known(role: &str) {
+    if !roles::is_known(role) {
+        revert(BridgeError::UnknownRole)
+    }
+}
+
+/// Privileged changes that grant access or loosen limits are made directly only while the
+/// timelock delay is zero. Revocations and restrictions always take effect immediately.
+fn verify_timelock_inactive() {
+    if timelock::read_delay() > 0 {
+        revert(BridgeError::TimelockActive)
+    }
+}
+
+/// Returns the caller and the address it is identified by in signed messages.
+///
+/// A contract caller is identified by the bytes of its package hash.
 fn from_keys() -> (Key, AccountHash) {
     let context = caller_context();
 
-    let res: Key = match context {
+    match context {
         CallStackElement::Session { account_hash }
-        | CallStackElement::StoredSession { account_hash, .. } => (*account_hash).into(),
+        | CallStackElement::StoredSession { account_hash, .. } => {
+            ((*account_hash).into(), *account_hash)
+        }
         CallStackElement::StoredContract {
             contract_package_hash,
             ..
-        } => (*contract_package_hash).into(),
-    };
-    (res, res.into_account().unwrap_or_revert())
+        } => (
+            (*contract_package_hash).into(),
+            AccountHash::new(contract_package_hash.value()),
+        ),
+    }
 }
 
+/// Stable commission percent in BPS
 pub fn get_stable_commission_percent() -> U256 {
-    uref::read(PARAM_STABLE_COMMISSION_PERCENT)
+    let value: U256 = uref::read(PARAM_STABLE_COMMISSION_PERCENT);
+
+    if runtime::has_key(STABLE_COMMISSION_IN_BPS_KEY_NAME) {
+        value
+    } else {
+        // Deployments made before BPS precision store whole percents.
+        value * (HUNDRED_PERCENT_BPS / 100)
+    }
 }
 
 pub fn set_stable_commission_percent(value: U256) {
-    if value > U256::one() * 50 {
-        revert(BridgeError::InvalidCommissionPercent)
+    verify_timelock_inactive();
+
+    update_stable_commission_percent(value)
+}
+
+fn update_stable_commission_percent(value: U256) {
+    verify_stable_commission_percent(value);
+
+    let old = get_stable_commission_percent();
+    uref::write(PARAM_STABLE_COMMISSION_PERCENT, value);
+    mark_stable_commission_in_bps();
+
+    fire(BridgeEvent::StableCommissionChanged { old, new: value });
+}
+
+/// Convert the stored stable commission percent of a contract installed before BPS
+/// precision to BPS. Does nothing if it is already denominated in BPS.
+///
+/// Only the unit changes, the commission charged stays the same, so no event is fired.
+pub fn migrate_stable_commission_to_bps() {
+    if runtime::has_key(STABLE_COMMISSION_IN_BPS_KEY_NAME) {
+        return;
+    }
+
+    let value = get_stable_commission_percent();
+    uref::write(PARAM_STABLE_COMMISSION_PERCENT, value);
+    mark_stable_commission_in_bps();
+}
+
+/// Stable commission percent applied to a token: its override if set, the global one otherwise
+pub fn get_token_stable_commission_percent(token_contract: ContractPackageHash) -> U256 {
+    stable_commissions::read(token_contract).unwrap_or_else(get_stable_commission_percent)
+}
+
+pub fn set_token_stable_commission_percent(token_contract: ContractPackageHash, value: U256) {
+    verify_timelock_inactive();
+
+    update_token_stable_commission_percent(token_contract, Some(value))
+}
+
+/// Make a token fall back to the global stable commission percent
+pub fn clear_token_stable_commission_percent(token_contract: ContractPackageHash) {
+    verify_timelock_inactive();
+
+    update_token_stable_commission_percent(token_contract, None)
+}
+
+fn update_token_stable_commission_percent(
+    token_contract: ContractPackageHash,
+    value: Option<U256>,
+) {
+    if let Some(value) = value {
+        verify_stable_commission_percent(value);
     }
 
-    uref::write(PARAM_STABLE_COMMISSION_PERCENT, value)
+    let old = stable_commissions::read(token_contract);
+    stable_commissions::write(token_contract, value);
+
+    fire(BridgeEvent::TokenStableCommissionChanged {
+        token_contract,
+        old,
+        new: value,
+    });
 }
 
+/// First signer of the signer set, in hex
 pub fn get_signer() -> String {
-    uref::read(PARAM_SIGNER)
+    signers::read()
+        .signers
+        .first()
+        .map(SignerId::to_string)
+        .unwrap_or_default()
 }
 
-/// value - ecrecover compatible public key
+/// value - PEM encoded secp256k1 key, or hex encoded Casper Ed25519 or secp256k1 public key,
+/// becomes the only signer of the set
+
+// #[inline(always)]
+pub fn set_signer(value: String) {
+    verify_timelock_inactive();
+
+    let old = get_signer();
+    let signers = parse_signers(get_signature_mode(), &[value]);
+    let new = signers[0].to_string();
+
+    write_signer_set(signers, 1);
+
+    fire(BridgeEvent::SignerChanged { old, new });
+}
+
+pub fn get_signer_set() -> SignerSet {
+    signers::read()
+}
+
+/// Replace the signer set. Every signer must be a distinct Ed25519 or secp256k1 public key, or
+/// address in the EIP-191 signature mode, and `threshold` must be between 1 and the number of keys.
+pub fn set_signer_set(signers: Vec<String>, threshold: u8) {
+    verify_timelock_inactive();
+
+    update_signer_set(signers, threshold)
+}
+
+fn update_signer_set(signers: Vec<String>, threshold: u8) {
+    let signers = parse_signers(get_signature_mode(), &signers);
+    let signers_hex = signers.iter().map(SignerId::to_string).collect();
+
+    write_signer_set(signers, threshold);
+
+    fire(BridgeEvent::SignerSetUpdated {
+        signers: signers_hex,
+        threshold,
+    });
+}
+
+fn write_signer_set(signers: Vec<SignerId>, threshold: u8) {
+    if threshold == 0 || usize::from(threshold) > signers.len() {
+        revert(BridgeError::InvalidSignerSet)
+    }
+
+    for (index, signer) in signers.iter().enumerate() {
+        if signers[..index].contains(signer) {
+            revert(BridgeError::InvalidSignerSet)
+        }
+    }
+
+    signers::write(SignerSet { signers, threshold });
+}
+
+pub fn is_paused() -> bool {
+    uref::read(PAUSED_KEY_NAME)
+}
+
+/// Stop all contract functionality allowed to the user, and payouts by `bridge_out` and
+/// `bridge_out_native`
+pub fn pause() {
+    uref::write(PAUSED_KEY_NAME, true);
+
+    fire(BridgeEvent::Paused);
+}
+
+/// Resume all contract functionality allowed to the user
+pub fn unpause() {
+    uref::write(PAUSED_KEY_NAME, false);
+
+    fire(BridgeEvent::Unpaused);
+}
+
+/// Name of the account named key `claim_role` stores the access URef of `role` under
+pub fn role_access_key_name(role: &str) -> String {
+    format!("{}_{}", NK_ACCESS_UREF, role)
+}
+
+/// Grant `role` to `account` by provisioning a new access URef of the role group.
+///
+/// The account takes the URef into its named keys with `claim_role`.
+pub fn grant_role(role: String, account: Key) {
+    verify_timelock_inactive();
+
+    add_role(role, account)
+}
+
+fn add_role(role: String, account: Key) {
+    verify_role_known(&role);
+
+    if roles::read(&role, account).is_some() {
+        revert(BridgeError::RoleAlreadyGranted)
+    }
+
+    let (contract_package_hash, _) = current_contract();
+    let access_uref = storage::provision_contract_user_group_uref(*contract_package_hash, &role)
+        .unwrap_or_revert();
+    roles::write(&role, account, Some(access_uref));
+
+    fire(BridgeEvent::RoleGranted { role, account });
+}
+
+/// Revoke `role` from `account` by removing its access URef from the role group.
+/// Not delayed by the timelock.
+pub fn revoke_role(role: String, account: Key) {
+    remove_role(role, account)
+}
+
+fn remove_role(role: String, account: Key) {
+    verify_role_known(&role);
+
+    let access_uref =
+        roles::read(&role, account).unwrap_or_revert_with(BridgeError::RoleNotGranted);
+
+    let (contract_package_hash, _) = current_contract();
+    storage::remove_contract_user_group_urefs(*contract_package_hash, &role, [access_uref].into())
+        .unwrap_or_revert();
+    roles::write(&role, account, None);
+
+    fire(BridgeEvent::RoleRevoked { role, account });
+}
+
+/// Store the access URef granted to the calling account into its named keys
+pub fn claim_role(role: String) {
+    let (_, self_contract_hash) = current_contract();
+    let access_uref = interface::onchain::take_role_access(*self_contract_hash, role.clone());
+
+    runtime::put_key(&role_access_key_name(&role), access_uref.into());
+}
+
+/// Access URef granted to the calling account. Only callable through `claim_role`.
+pub fn take_role_access(role: String) -> URef {
+    verify_caller_is_self();
+
+    let (account, _) = from_keys();
+    roles::read(&role, account).unwrap_or_revert_with(BridgeError::RoleNotGranted)
+}
+
+pub fn has_role(role: String, account: Key) -> bool {
+    roles::read(&role, account).is_some()
+}
+
+/// Allow a token to be bridged
+pub fn add_token(token_contract: ContractPackageHash) {
+    verify_timelock_inactive();
+
+    enable_token(token_contract)
+}
+
+fn enable_token(token_contract: ContractPackageHash) {
+    let mut config = token_registry::read(token_contract);
+    config.enabled = true;
+    write_token_config(token_contract, config);
+}
+
+/// Disallow a token to be bridged. Collected commission still can be withdrawn.
+/// Not delayed by the timelock.
+pub fn remove_token(token_contract: ContractPackageHash) {
+    disable_token(token_contract)
+}
+
+fn disable_token(token_contract: ContractPackageHash) {
+    let mut config = token_registry::read(token_contract);
+    config.enabled = false;
+    write_token_config(token_contract, config);
+}
+
+/// Choose whether a token is locked in the bridge pool or minted and burned by the bridge.
+///
+/// In mint/burn mode the bridge contract package must be a minter of the token contract.
+pub fn set_token_mode(token_contract: ContractPackageHash, mode: u8) {
+    verify_timelock_inactive();
+
+    update_token_mode(token_contract, mode)
+}
+
+fn update_token_mode(token_contract: ContractPackageHash, mode: u8) {
+    let mode = TokenMode::try_from(mode).unwrap_or_revert_with(BridgeError::InvalidTokenMode);
+
+    if !wrapped_supply::read(token_contract).is_zero() {
+        revert(BridgeError::WrappedSupplyOutstanding)
+    }
+
+    let mut config = token_registry::read(token_contract);
+    config.mode = mode;
+    write_token_config(token_contract, config);
+}
+
+fn write_token_config(token_contract: ContractPackageHash, config: TokenConfig) {
+    token_registry::write(token_contract, config);
+
+    fire(BridgeEvent::TokenConfigChanged {
+        token_contract,
+        enabled: config.enabled,
+        mode: config.mode as u8,
+    });
+}
+
+/// Supply of a wrapped token minted by the bridge and not burned yet
+pub fn get_wrapped_supply(token_contract: ContractPackageHash) -> U256 {
+    wrapped_supply::read(token_contract)
+}
+
+pub fn get_token_config(token_contract: ContractPackageHash) -> TokenConfig {
+    token_registry::read(token_contract)
+}
+
+/// Set the `bridge_in` limits of a token. Only loosening them is delayed by the timelock.
+pub fn set_bridge_in_limits(
+    token_contract: ContractPackageHash,
+    min_amount: U256,
+    max_amount: U256,
+) {
+    let limits = limits::read(token_contract);
+    if min_amount < limits.min_bridge_in || raises_maximum(limits.max_bridge_in, max_amount) {
+        verify_timelock_inactive();
+    }
+
+    update_bridge_in_limits(token_contract, min_amount, max_amount)
+}
+
+fn update_bridge_in_limits(
+    token_contract: ContractPackageHash,
+    min_amount: U256,
+    max_amount: U256,
+) {
+    if !max_amount.is_zero() && min_amount > max_amount {
+        revert(BridgeError::InvalidTokenLimits)
+    }
+
+    let mut limits = limits::read(token_contract);
+    limits.min_bridge_in = min_amount;
+    limits.max_bridge_in = max_amount;
+    write_token_limits(token_contract, limits);
+}
+
+/// Set the `bridge_out` limits of a token. Only loosening them is delayed by the timelock.
+pub fn set_bridge_out_limits(
+    token_contract: ContractPackageHash,
+    max_amount: U256,
+    outflow_cap: U256,
+    outflow_window: u64,
+) {
+    let limits = limits::read(token_contract);
+    let shortens_window = !limits.outflow_cap.is_zero() && outflow_window < limits.outflow_window;
+    if raises_maximum(limits.max_bridge_out, max_amount)
+        || raises_maximum(limits.outflow_cap, outflow_cap)
+        || shortens_window
+    {
+        verify_timelock_inactive();
+    }
+
+    update_bridge_out_limits(token_contract, max_amount, outflow_cap, outflow_window)
+}
+
+/// Whether replacing the maximum `old` with `new` allows more, a zero maximum meaning no limit
+fn raises_maximum(old: U256, new: U256) -> bool {
+    !old.is_zero() && (new.is_zero() || new > old)
+}
+
+fn update_bridge_out_limits(
+    token_contract: ContractPackageHash,
+    max_amount: U256,
+    outflow_cap: U256,
+    outflow_window: u64,
+) {
+    if !outflow_cap.is_zero() && outflow_window == 0 {
+        revert(BridgeError::InvalidTokenLimits)
+    }
+
+    let mut limits = limits::read(token_contract);
+    limits.max_bridge_out = max_amount;
+    limits.outflow_cap = outflow_cap;
+    limits.outflow_window = outflow_window;
+    write_token_limits(token_contract, limits);
+}
+
+fn write_token_limits(token_contract: ContractPackageHash, limits: TokenLimits) {
+    limits::write(token_contract, limits);
+
+    fire(BridgeEvent::TokenLimitsChanged {
+        token_contract,
+        min_bridge_in: limits.min_bridge_in,
+        max_bridge_in: limits.max_bridge_in,
+        max_bridge_out: limits.max_bridge_out,
+        outflow_cap: limits.outflow_cap,
+        outflow_window: limits.outflow_window,
+    });
+}
+
+pub fn get_token_limits(token_contract: ContractPackageHash) -> TokenLimits {
+    limits::read(token_contract)
+}
+
+/// Volume paid out by `bridge_out` within the sliding outflow window
+pub fn get_outflow_volume(token_contract: ContractPackageHash) -> U256 {
+    let current_time: u64 = runtime::get_blocktime().into();
+    let limits = limits::read(token_contract);
+    let (_, volume) = current_outflow(current_time, limits.outflow_window, token_contract);
+    volume
+}
+
+/// Whether a signature nonce has already been used
+pub fn is_nonce_used(nonce: U128) -> bool {
+    used_nonces::is_used_nonce(nonce)
+}
+
+/// Whether an inbound transfer of a source chain was already paid out by `bridge_out`
+pub fn is_transfer_processed(source_chain: String, transaction_id: U256) -> bool {
+    processed_transfers::is_processed(&source_chain, transaction_id)
+}
+
+pub fn get_commission_by_token(token_contract: ContractPackageHash) -> U256 {
+    commissions::read(token_contract)
+}
+
+/// Balance of a token held by the bridge, excluding its collected commission
+pub fn get_available_liquidity(token_contract: ContractPackageHash) -> U256 {
+    let (self_contract_package, _) = current_contract();
+    let self_contract_key: Key = (*self_contract_package).into();
+
+    erc20::balance_of(token_contract, self_contract_key)
+        .checked_sub(commissions::read(token_contract))
+        .unwrap_or_revert()
+}
+
+pub fn get_total_commission(
+    token_contract: ContractPackageHash,
+    amount: U256,
+    gas_commission: U256,
+) -> U256 {
+    let stable_commission = amount * get_token_stable_commission_percent(token_contract)
+        / U256::from(HUNDRED_PERCENT_BPS);
+    stable_commission + gas_commission
+}
+
+pub fn bridge_in(
+    token_contract: ContractPackageHash,
+    amount: U256,
+    gas_commission: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    destination_chain: String,
+    destination_address: String,
+    signatures: Vec<Bytes>,
+) {
+    receive_bridge_in(
+        BridgeInPull::Transfer,
+        token_contract,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
+    );
+}
+
+pub fn bridge_in_confirm(
+    token_contract: ContractPackageHash,
+    amount: U256,
+    gas_commission: U256,
+    nonce: U128,
+    transaction_id: U256,
+    destination_chain: String,
+    destination_address: String,
+    sender: Key,
+) {
+    verify_caller_is_self();
 
-// #[inline(always)]
-pub fn set_signer(value: String) {
-    check_public_key(&value);
-    uref::write(PARAM_SIGNER, value)
+    confirm_bridge_in(
+        token_contract,
+        amount,
+        gas_commission,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        sender,
+    );
 }
 
-pub fn get_commission_by_token(token_contract: ContractPackageHash) -> U256 {
-    commissions::read(token_contract)
+/// Transfers funds to the bridge through an allowance given to the bridge contract package,
+/// with metadata specifying the destination chain.
+pub fn bridge_in_from(
+    token_contract: ContractPackageHash,
+    amount: U256,
+    gas_commission: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    destination_chain: String,
+    destination_address: String,
+    signatures: Vec<Bytes>,
+) {
+    receive_bridge_in(
+        BridgeInPull::TransferFrom,
+        token_contract,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        destination_chain,
+        destination_address,
+        signatures,
+    );
 }
 
-pub fn get_total_commission(amount: U256, gas_commission: U256) -> U256 {
-    let stable_commission = amount * get_stable_commission_percent() / 100;
-    stable_commission + gas_commission
+/// How `bridge_in` moves the funds of the sender to the bridge.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BridgeInPull {
+    /// The session of the sender transfers the funds and confirms them through the contract.
+    Transfer,
+    /// The contract pulls the funds through an allowance given by the sender.
+    TransferFrom,
 }
 
-pub fn bridge_in(
+/// Verifies a signed `bridge_in` message, moves the funds to the bridge and confirms them.
+fn receive_bridge_in(
+    pull: BridgeInPull,
     token_contract: ContractPackageHash,
     amount: U256,
     gas_commission: U256,
@@ -187,13 +1112,15 @@ pub fn bridge_in(
     transaction_id: U256,
     destination_chain: String,
     destination_address: String,
-    signature: [u8; 64],
+    signatures: Vec<Bytes>,
 ) {
+    verify_not_paused();
     verify_deadline(deadline);
 
     let (self_contract_package, self_contract_hash) = current_contract();
     let (from_key, signer) = from_keys();
     let bytes = cook_msg_bridge_in(
+        &chain_name(),
         *self_contract_hash,
         token_contract,
         signer,
@@ -206,32 +1133,53 @@ pub fn bridge_in(
         &destination_address,
     );
 
-    interface::onchain::check_params(*self_contract_hash, bytes, signature, nonce);
+    interface::onchain::check_params(*self_contract_hash, bytes, signatures, nonce);
+
+    verify_token_registered(token_contract);
+    verify_bridge_in_limits(token_contract, amount);
 
     let self_contract_key: Key = (*self_contract_package).into();
     let balance_before = erc20::balance_of(token_contract, self_contract_key);
-    // vvvq, how casper understand from where we transfer it?
-    erc20::transfer(token_contract, self_contract_key, amount);
+    match pull {
+        BridgeInPull::Transfer => erc20::transfer(token_contract, self_contract_key, amount),
+        BridgeInPull::TransferFrom => {
+            erc20::transfer_from(token_contract, from_key, self_contract_key, amount)
+        }
+    }
     let balance_after = erc20::balance_of(token_contract, self_contract_key);
 
     if balance_after.checked_sub(balance_before) != Some(amount) {
         revert(BridgeError::UnexpectedTransferAmount)
     }
 
-    interface::onchain::bridge_in_confirm(
-        *self_contract_hash,
-        token_contract,
-        amount,
-        gas_commission,
-        nonce,
-        transaction_id,
-        destination_chain,
-        destination_address,
-        from_key,
-    );
+    match pull {
+        // Funds of the session are only accounted from the contract context.
+        BridgeInPull::Transfer => interface::onchain::bridge_in_confirm(
+            *self_contract_hash,
+            token_contract,
+            amount,
+            gas_commission,
+            nonce,
+            transaction_id,
+            destination_chain,
+            destination_address,
+            from_key,
+        ),
+        BridgeInPull::TransferFrom => confirm_bridge_in(
+            token_contract,
+            amount,
+            gas_commission,
+            nonce,
+            transaction_id,
+            destination_chain,
+            destination_address,
+            from_key,
+        ),
+    }
 }
 
-pub fn bridge_in_confirm(
+/// Takes the commission of funds received by the bridge and emits `FundsIn`.
+fn confirm_bridge_in(
     token_contract: ContractPackageHash,
     amount: U256,
     gas_commission: U256,
@@ -241,22 +1189,29 @@ pub fn bridge_in_confirm(
     destination_address: String,
     sender: Key,
 ) {
-    verify_caller_is_self();
-
-    let total_commission = get_total_commission(amount, gas_commission);
+    let stable_commission_percent = get_token_stable_commission_percent(token_contract);
+    let total_commission = get_total_commission(token_contract, amount, gas_commission);
     if total_commission > amount {
         revert(BridgeError::CommissionBiggerThanTransferredAmount)
     }
 
     commissions::increase(token_contract, total_commission);
 
+    if token_registry::is_wrapped(token_contract) {
+        // The commission stays in the bridge balance, the rest leaves Casper.
+        let burned = amount - total_commission;
+        let (self_contract_package, _) = current_contract();
+        erc20::burn(token_contract, (*self_contract_package).into(), burned);
+        wrapped_supply::decrease(token_contract, burned);
+    }
+
     let event = BridgeEvent::FundsIn {
         token_contract,
         destination_chain,
         destination_address,
         amount,
         gas_commission,
-        stable_commission_percent: get_stable_commission_percent(),
+        stable_commission_percent,
         nonce,
         transaction_id,
         sender,
@@ -265,12 +1220,12 @@ pub fn bridge_in_confirm(
     fire(event);
 }
 
-pub fn check_params(bytes: Bytes, signature: [u8; 64], nonce: U128) {
+pub fn check_params(bytes: Bytes, signatures: Vec<Bytes>, nonce: U128) {
     verify_caller_is_self();
 
     verify_nonce(nonce);
 
-    verify_signature(bytes, signature);
+    verify_signatures(bytes, signatures);
 
     used_nonces::use_nonce(nonce);
 }
@@ -283,26 +1238,41 @@ pub fn bridge_out(
     source_address: String,
     recipient: Key,
 ) {
-    let (self_contract_package, _) = current_contract();
-    let self_contract_key: Key = (*self_contract_package).into();
+    verify_not_paused();
+    verify_token_registered(token_contract);
+    verify_transfer_not_processed(&source_chain, transaction_id);
 
-    let balance_before = erc20::balance_of(token_contract, self_contract_key);
+    if token_registry::is_wrapped(token_contract) {
+        consume_bridge_out_limits(token_contract, amount);
 
-    let allowed_balance = balance_before
-        .checked_sub(commissions::read(token_contract))
-        .unwrap_or_revert();
+        erc20::mint(token_contract, recipient, amount);
+        wrapped_supply::increase(token_contract, amount);
+    } else {
+        let (self_contract_package, _) = current_contract();
+        let self_contract_key: Key = (*self_contract_package).into();
 
-    if amount > allowed_balance {
-        revert(BridgeError::AmountExceedBridgePool);
-    }
+        let balance_before = erc20::balance_of(token_contract, self_contract_key);
 
-    erc20::transfer(token_contract, recipient, amount);
-    let balance_after = erc20::balance_of(token_contract, self_contract_key);
+        let allowed_balance = balance_before
+            .checked_sub(commissions::read(token_contract))
+            .unwrap_or_revert();
 
-    if balance_before.checked_sub(balance_after) != Some(amount) {
-        revert(BridgeError::UnexpectedTransferAmount)
+        if amount > allowed_balance {
+            revert(BridgeError::AmountExceedBridgePool);
+        }
+
+        consume_bridge_out_limits(token_contract, amount);
+
+        erc20::transfer(token_contract, recipient, amount);
+        let balance_after = erc20::balance_of(token_contract, self_contract_key);
+
+        if balance_before.checked_sub(balance_after) != Some(amount) {
+            revert(BridgeError::UnexpectedTransferAmount)
+        }
     }
 
+    processed_transfers::mark_processed(&source_chain, transaction_id);
+
     let event = BridgeEvent::FundsOut {
         token_contract,
         source_chain,
@@ -319,29 +1289,37 @@ pub fn transfer_out(
     token_contract: ContractPackageHash,
     amount: U256,
     commission: U256,
+    deadline: U256,
     nonce: U128,
     transaction_id: U256,
     recipient: Key,
-    signature: [u8; 64],
+    signatures: Vec<Bytes>,
 ) {
+    verify_not_paused();
+    verify_deadline(deadline);
+
     let (_, self_contract_hash) = current_contract();
 
     let (_, signer) = from_keys();
 
     let bytes = cook_msg_transfer_out(
+        &chain_name(),
         *self_contract_hash,
         token_contract,
         signer,
         recipient,
         amount,
         commission,
+        deadline,
         nonce,
         transaction_id
     );
-    interface::onchain::check_params(*self_contract_hash, bytes, signature, nonce);
+    interface::onchain::check_params(*self_contract_hash, bytes, signatures, nonce);
 
     used_nonces::use_nonce(nonce);
 
+    verify_token_registered(token_contract);
+
     let (self_contract_package, _) = current_contract();
     let self_contract_key: Key = (*self_contract_package).into();
 
@@ -351,20 +1329,36 @@ pub fn transfer_out(
 
     let total_sum_for_transfer = amount.checked_add(commission).unwrap_or_revert();
 
-    erc20::transfer(token_contract, recipient, total_sum_for_transfer);
+    // Burned wrapped tokens are minted back, only the commission is paid from the bridge balance.
+    let is_wrapped = token_registry::is_wrapped(token_contract);
+    let paid_from_balance = if is_wrapped {
+        commission
+    } else {
+        total_sum_for_transfer
+    };
+
+    erc20::transfer(token_contract, recipient, paid_from_balance);
 
     let balance_after = erc20::balance_of(token_contract, self_contract_key);
 
     let actually_transferred = balance_before.checked_sub(balance_after).unwrap();
-    if actually_transferred != total_sum_for_transfer {
+    if actually_transferred != paid_from_balance {
         revert(BridgeError::UnexpectedTransferAmount)
     }
 
+    if is_wrapped {
+        erc20::mint(token_contract, recipient, amount);
+        wrapped_supply::increase(token_contract, amount);
+    }
+
     let event = BridgeEvent::TransferOut {
         token_contract,
         total_sum_for_transfer,
         nonce,
         recipient,
+        amount,
+        commission,
+        transaction_id,
     };
 
     fire(event);
@@ -380,6 +1374,332 @@ pub fn withdraw_commission(token_contract: ContractPackageHash, amount: U256, re
     let event = BridgeEvent::WithdrawCommission {
         token_contract,
         amount,
+        recipient,
+    };
+    fire(event);
+}
+
+/// Commission collected from native CSPR transfers
+pub fn get_native_commission() -> U256 {
+    native::read_commission()
+}
+
+/// Transfers native CSPR from `purse` to the bridge, with metadata specifying the destination chain.
+pub fn bridge_in_native(
+    amount: U256,
+    gas_commission: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    destination_chain: String,
+    destination_address: String,
+    signatures: Vec<Bytes>,
+    purse: URef,
+) {
+    verify_not_paused();
+    verify_deadline(deadline);
+
+    let (_, self_contract_hash) = current_contract();
+    let (from_key, signer) = from_keys();
+    let bytes = cook_msg_bridge_in(
+        &chain_name(),
+        *self_contract_hash,
+        NATIVE_TOKEN_CONTRACT,
+        signer,
+        amount,
+        gas_commission,
+        deadline,
+        nonce,
+        transaction_id,
+        &destination_chain,
+        &destination_address,
+    );
+
+    interface::onchain::check_params(*self_contract_hash, bytes, signatures, nonce);
+
+    verify_bridge_in_limits(NATIVE_TOKEN_CONTRACT, amount);
+
+    let balance_before = native::balance();
+    native::deposit(purse, amount);
+    let balance_after = native::balance();
+
+    if balance_after.checked_sub(balance_before) != Some(amount) {
+        revert(BridgeError::UnexpectedTransferAmount)
+    }
+
+    let stable_commission_percent = get_token_stable_commission_percent(NATIVE_TOKEN_CONTRACT);
+    let total_commission = get_total_commission(NATIVE_TOKEN_CONTRACT, amount, gas_commission);
+    if total_commission > amount {
+        revert(BridgeError::CommissionBiggerThanTransferredAmount)
+    }
+
+    native::increase_commission(total_commission);
+
+    let event = BridgeEvent::FundsIn {
+        token_contract: NATIVE_TOKEN_CONTRACT,
+        destination_chain,
+        destination_address,
+        amount,
+        gas_commission,
+        stable_commission_percent,
+        nonce,
+        transaction_id,
+        sender: from_key,
+    };
+
+    fire(event);
+}
+
+/// Transfers native CSPR from the bridge to the main purse of `recipient`.
+pub fn bridge_out_native(
+    amount: U256,
+    transaction_id: U256,
+    source_chain: String,
+    source_address: String,
+    recipient: Key,
+) {
+    verify_not_paused();
+    verify_transfer_not_processed(&source_chain, transaction_id);
+
+    let recipient_account = recipient
+        .into_account()
+        .unwrap_or_revert_with(BridgeError::RecipientNotAccount);
+
+    let allowed_balance = native::balance()
+        .checked_sub(native::read_commission())
+        .unwrap_or_revert();
+
+    if amount > allowed_balance {
+        revert(BridgeError::AmountExceedBridgePool);
+    }
+
+    consume_bridge_out_limits(NATIVE_TOKEN_CONTRACT, amount);
+
+    native::withdraw(recipient_account, amount);
+
+    processed_transfers::mark_processed(&source_chain, transaction_id);
+
+    let event = BridgeEvent::FundsOut {
+        token_contract: NATIVE_TOKEN_CONTRACT,
+        source_chain,
+        source_address,
+        amount,
+        transaction_id,
+        recipient,
+    };
+
+    fire(event);
+}
+
+/// Withdraws commission collected in native CSPR to the main purse of `recipient`.
+pub fn withdraw_native_commission(amount: U256, recipient: Key) {
+    let recipient_account = recipient
+        .into_account()
+        .unwrap_or_revert_with(BridgeError::RecipientNotAccount);
+
+    if native::read_commission() < amount {
+        revert(BridgeError::AmountExceedCommissionPool)
+    }
+    native::decrease_commission(amount);
+    native::withdraw(recipient_account, amount);
+
+    let event = BridgeEvent::WithdrawCommission {
+        token_contract: NATIVE_TOKEN_CONTRACT,
+        amount,
+        recipient,
+    };
+    fire(event);
+}
+
+/// Moves pool funds that are not collected commission to the treasury.
+///
+/// The treasury is only set through `propose_change`, and the amount is charged against the
+/// outflow cap of the token like any other payout.
+pub fn rebalance_liquidity(token_contract: ContractPackageHash, amount: U256) {
+    verify_timelock_inactive();
+
+    move_liquidity_to_treasury(token_contract, amount)
+}
+
+fn move_liquidity_to_treasury(token_contract: ContractPackageHash, amount: U256) {
+    let treasury: Option<Key> = uref::read(TREASURY_KEY_NAME);
+    let treasury = treasury.unwrap_or_revert_with(BridgeError::TreasuryNotSet);
+
+    let (self_contract_package, _) = current_contract();
+    let self_contract_key: Key = (*self_contract_package).into();
+
+    let balance_before = erc20::balance_of(token_contract, self_contract_key);
+
+    if amount > get_available_liquidity(token_contract) {
+        revert(BridgeError::AmountExceedBridgePool);
+    }
+    consume_bridge_out_limits(token_contract, amount);
+
+    erc20::transfer(token_contract, treasury, amount);
+    let balance_after = erc20::balance_of(token_contract, self_contract_key);
+
+    if balance_before.checked_sub(balance_after) != Some(amount) {
+        revert(BridgeError::UnexpectedTransferAmount)
+    }
+
+    let event = BridgeEvent::LiquidityMoved {
+        token_contract,
+        amount,
+        from: self_contract_key,
+        to: treasury,
+    };
+    fire(event);
+}
+
+/// Tops up the pool of a token through an allowance given to the bridge contract package.
+pub fn deposit_liquidity(token_contract: ContractPackageHash, amount: U256) {
+    verify_token_registered(token_contract);
+
+    let (self_contract_package, _) = current_contract();
+    let self_contract_key: Key = (*self_contract_package).into();
+    let (from_key, _) = from_keys();
+
+    let balance_before = erc20::balance_of(token_contract, self_contract_key);
+    erc20::transfer_from(token_contract, from_key, self_contract_key, amount);
+    let balance_after = erc20::balance_of(token_contract, self_contract_key);
+
+    if balance_after.checked_sub(balance_before) != Some(amount) {
+        revert(BridgeError::UnexpectedTransferAmount)
+    }
+
+    let event = BridgeEvent::LiquidityMoved {
+        token_contract,
+        amount,
+        from: from_key,
+        to: self_contract_key,
     };
     fire(event);
 }
+
+pub fn get_timelock_delay() -> u64 {
+    timelock::read_delay()
+}
+
+/// Set the delay between proposing a change and executing it, in block time units.
+///
+/// A zero delay lets privileged changes be made directly. Once the delay is set, it is
+/// changed through `propose_change` like any other privileged change.
+pub fn set_timelock_delay(delay: u64) {
+    verify_timelock_inactive();
+
+    update_timelock_delay(delay)
+}
+
+fn update_timelock_delay(delay: u64) {
+    let old = timelock::read_delay();
+    timelock::write_delay(delay);
+
+    fire(BridgeEvent::TimelockDelayChanged { old, new: delay });
+}
+
+pub fn get_max_deadline_horizon() -> u64 {
+    uref::read(MAX_DEADLINE_HORIZON_KEY_NAME)
+}
+
+/// Set how far ahead of the block time signed deadlines may be, in milliseconds.
+pub fn set_max_deadline_horizon(horizon: u64) {
+    verify_timelock_inactive();
+
+    update_max_deadline_horizon(horizon)
+}
+
+fn update_max_deadline_horizon(horizon: u64) {
+    let old = get_max_deadline_horizon();
+    uref::write(MAX_DEADLINE_HORIZON_KEY_NAME, horizon);
+
+    fire(BridgeEvent::MaxDeadlineHorizonChanged { old, new: horizon });
+}
+
+fn update_treasury(treasury: Key) {
+    let old: Option<Key> = uref::read(TREASURY_KEY_NAME);
+    uref::write(TREASURY_KEY_NAME, Some(treasury));
+
+    fire(BridgeEvent::TreasuryChanged { old, new: treasury });
+}
+
+/// Pending proposal stored under `id`, if any
+pub fn get_proposal(id: u64) -> Option<Proposal> {
+    timelock::read(id)
+}
+
+/// Schedule a privileged change, executable once the timelock delay has elapsed
+pub fn propose_change(change: Change) {
+    let current_time: u64 = runtime::get_blocktime().into();
+    let eta = current_time.saturating_add(timelock::read_delay());
+    let id = timelock::next_id();
+
+    timelock::write(
+        id,
+        Some(Proposal {
+            change: change.clone(),
+            eta,
+        }),
+    );
+
+    fire(BridgeEvent::ChangeProposed { id, change, eta });
+}
+
+/// Apply a proposed change whose timelock has elapsed
+pub fn execute_change(id: u64) {
+    let proposal = timelock::read(id).unwrap_or_revert_with(BridgeError::UnknownProposal);
+
+    let current_time: u64 = runtime::get_blocktime().into();
+    if current_time < proposal.eta {
+        revert(BridgeError::TimelockNotElapsed)
+    }
+
+    timelock::write(id, None);
+
+    match proposal.change {
+        Change::SetSignerSet { signers, threshold } => update_signer_set(signers, threshold),
+        Change::SetStableCommissionPercent { value } => update_stable_commission_percent(value),
+        Change::SetTokenStableCommissionPercent {
+            token_contract,
+            value,
+        } => update_token_stable_commission_percent(token_contract, value),
+        Change::GrantRole { role, account } => add_role(role, account),
+        Change::RevokeRole { role, account } => remove_role(role, account),
+        Change::SetTimelockDelay { delay } => update_timelock_delay(delay),
+        Change::SetMaxDeadlineHorizon { horizon } => update_max_deadline_horizon(horizon),
+        Change::SetTreasury { treasury } => update_treasury(treasury),
+        Change::SetBridgeOutLimits {
+            token_contract,
+            max_amount,
+            outflow_cap,
+            outflow_window,
+        } => update_bridge_out_limits(token_contract, max_amount, outflow_cap, outflow_window),
+        Change::RemoveToken { token_contract } => disable_token(token_contract),
+        Change::SetTokenMode {
+            token_contract,
+            mode,
+        } => update_token_mode(token_contract, mode),
+        Change::RebalanceLiquidity {
+            token_contract,
+            amount,
+        } => move_liquidity_to_treasury(token_contract, amount),
+        Change::AddToken { token_contract } => enable_token(token_contract),
+        Change::SetBridgeInLimits {
+            token_contract,
+            min_amount,
+            max_amount,
+        } => update_bridge_in_limits(token_contract, min_amount, max_amount),
+    }
+
+    fire(BridgeEvent::ChangeExecuted { id });
+}
+
+/// Drop a pending proposal before it is executed
+pub fn cancel_change(id: u64) {
+    if timelock::read(id).is_none() {
+        revert(BridgeError::UnknownProposal)
+    }
+
+    timelock::write(id, None);
+
+    fire(BridgeEvent::ChangeCancelled { id });
+}
@@ -1,10 +1,26 @@
 use alloc::{string::String, vec};
-use casper_types::{
-    CLType, CLTyped, ContractPackageHash, EntryPoint, EntryPointAccess,
-    EntryPointType, Group, Key, Parameter, U128, U256,
-};
+use casper_types::{CLType, CLTyped, EntryPoint, EntryPointAccess, EntryPointType, Group, URef};
 
-use crate::constants::GROUP_OPERATOR;
+use crate::{
+    args::{
+        AddTokenArgs, BridgeInArgs, BridgeInConfirmArgs, BridgeInFromArgs, BridgeInNativeArgs,
+        BridgeOutArgs, BridgeOutNativeArgs, CancelChangeArgs, CheckParamsArgs, ClaimRoleArgs,
+        ClearTokenStableCommissionPercentArgs, DepositLiquidityArgs, ExecuteChangeArgs,
+        GetAvailableLiquidityArgs, GetCommissionPoolArgs, GetOutflowVolumeArgs, GetProposalArgs,
+        GetTokenConfigArgs, GetTokenLimitsArgs, GetTokenStableCommissionPercentArgs,
+        GetTotalCommissionArgs, GetWrappedSupplyArgs, GrantRoleArgs, HasRoleArgs, IsNonceUsedArgs,
+        IsTransferProcessedArgs, MigrateArgs, ProposeChangeArgs, RebalanceLiquidityArgs,
+        RemoveTokenArgs, RevokeRoleArgs, SetBridgeInLimitsArgs, SetBridgeOutLimitsArgs,
+        SetMaxDeadlineHorizonArgs, SetSignerArgs, SetSignerSetArgs, SetStableCommissionPercentArgs,
+        SetTimelockDelayArgs, SetTokenModeArgs, SetTokenStableCommissionPercentArgs,
+        TakeRoleAccessArgs, TransferOutArgs, WithdrawCommissionArgs, WithdrawNativeCommissionArgs,
+    },
+    constants::{ROLE_ADMIN, ROLE_FEE_MANAGER, ROLE_PAUSER, ROLE_RELAYER},
+    limits::TokenLimits,
+    signers::SignerSet,
+    timelock::Proposal,
+    token_registry::TokenConfig,
+};
 
 pub const EP_BRIDGE_IN: &str = "bridge_in";
 pub const EP_BRIDGE_IN_CONFIRM: &str = "bridge_in_confirm";
@@ -14,8 +30,51 @@ pub const EP_TRANSFER_OUT: &str = "transfer_out";
 pub const EP_WITHDRAW_COMMISSION: &str = "withdraw_commission";
 pub const EP_SET_STABLE_COMMISSION_PERCENT: &str = "set_stable_commission_percent";
 pub const EP_GET_STABLE_COMMISSION_PERCENT: &str = "get_stable_commission_percent";
+pub const EP_MIGRATE_STABLE_COMMISSION_TO_BPS: &str = "migrate_stable_commission_to_bps";
+pub const EP_SET_TOKEN_STABLE_COMMISSION_PERCENT: &str = "set_token_stable_commission_percent";
+pub const EP_CLEAR_TOKEN_STABLE_COMMISSION_PERCENT: &str = "clear_token_stable_commission_percent";
+pub const EP_GET_TOKEN_STABLE_COMMISSION_PERCENT: &str = "get_token_stable_commission_percent";
 pub const EP_SET_SIGNER: &str = "set_signer";
 pub const EP_GET_SIGNER: &str = "get_signer";
+pub const EP_SET_SIGNER_SET: &str = "set_signer_set";
+pub const EP_GET_SIGNER_SET: &str = "get_signer_set";
+pub const EP_PAUSE: &str = "pause";
+pub const EP_UNPAUSE: &str = "unpause";
+pub const EP_ADD_TOKEN: &str = "add_token";
+pub const EP_REMOVE_TOKEN: &str = "remove_token";
+pub const EP_GET_TOKEN_CONFIG: &str = "get_token_config";
+pub const EP_SET_BRIDGE_IN_LIMITS: &str = "set_bridge_in_limits";
+pub const EP_SET_BRIDGE_OUT_LIMITS: &str = "set_bridge_out_limits";
+pub const EP_GET_TOKEN_LIMITS: &str = "get_token_limits";
+pub const EP_GET_OUTFLOW_VOLUME: &str = "get_outflow_volume";
+pub const EP_GRANT_ROLE: &str = "grant_role";
+pub const EP_REVOKE_ROLE: &str = "revoke_role";
+pub const EP_CLAIM_ROLE: &str = "claim_role";
+pub const EP_TAKE_ROLE_ACCESS: &str = "take_role_access";
+pub const EP_HAS_ROLE: &str = "has_role";
+pub const EP_MIGRATE: &str = "migrate";
+pub const EP_IS_NONCE_USED: &str = "is_nonce_used";
+pub const EP_IS_TRANSFER_PROCESSED: &str = "is_transfer_processed";
+pub const EP_BRIDGE_IN_NATIVE: &str = "bridge_in_native";
+pub const EP_BRIDGE_OUT_NATIVE: &str = "bridge_out_native";
+pub const EP_WITHDRAW_NATIVE_COMMISSION: &str = "withdraw_native_commission";
+pub const EP_GET_NATIVE_COMMISSION: &str = "get_native_commission";
+pub const EP_SET_TOKEN_MODE: &str = "set_token_mode";
+pub const EP_GET_WRAPPED_SUPPLY: &str = "get_wrapped_supply";
+pub const EP_BRIDGE_IN_FROM: &str = "bridge_in_from";
+pub const EP_REBALANCE_LIQUIDITY: &str = "rebalance_liquidity";
+pub const EP_DEPOSIT_LIQUIDITY: &str = "deposit_liquidity";
+pub const EP_PROPOSE_CHANGE: &str = "propose_change";
+pub const EP_EXECUTE_CHANGE: &str = "execute_change";
+pub const EP_CANCEL_CHANGE: &str = "cancel_change";
+pub const EP_GET_PROPOSAL: &str = "get_proposal";
+pub const EP_SET_TIMELOCK_DELAY: &str = "set_timelock_delay";
+pub const EP_GET_TIMELOCK_DELAY: &str = "get_timelock_delay";
+pub const EP_SET_MAX_DEADLINE_HORIZON: &str = "set_max_deadline_horizon";
+pub const EP_GET_MAX_DEADLINE_HORIZON: &str = "get_max_deadline_horizon";
+pub const EP_GET_COMMISSION_POOL: &str = "get_commission_pool";
+pub const EP_GET_AVAILABLE_LIQUIDITY: &str = "get_available_liquidity";
+pub const EP_GET_TOTAL_COMMISSION: &str = "get_total_commission";
 
 pub const PARAM_TOKEN_CONTRACT: &str = "token_contract";
 pub const PARAM_AMOUNT: &str = "amount";
@@ -33,26 +92,40 @@ pub const PARAM_SENDER: &str = "sender";
 pub const PARAM_RECIPIENT: &str = "recipient";
 pub const PARAM_SIGNER: &str = "signer";
 pub const PARAM_SIGNATURE: &str = "signature";
+pub const PARAM_SIGNATURES: &str = "signatures";
+pub const PARAM_SIGNERS: &str = "signers";
+pub const PARAM_THRESHOLD: &str = "threshold";
 pub const PARAM_BYTES: &str = "bytes";
+pub const PARAM_MIN_AMOUNT: &str = "min_amount";
+pub const PARAM_MAX_AMOUNT: &str = "max_amount";
+pub const PARAM_OUTFLOW_CAP: &str = "outflow_cap";
+pub const PARAM_OUTFLOW_WINDOW: &str = "outflow_window";
+pub const PARAM_ROLE: &str = "role";
+pub const PARAM_ACCOUNT: &str = "account";
+pub const PARAM_DISABLE_OLD_VERSION: &str = "disable_old_version";
+pub const PARAM_CONTRACT_PACKAGE_HASH: &str = "contract_package_hash";
+pub const PARAM_CHAIN_NAME: &str = "chain_name";
+pub const PARAM_EVENT_MODE: &str = "event_mode";
+pub const PARAM_SIGNATURE_MODE: &str = "signature_mode";
+pub const PARAM_PURSE: &str = "purse";
+pub const PARAM_BRIDGE_CONTRACT: &str = "bridge_contract";
+pub const PARAM_ENTRY_POINT: &str = "entry_point";
+pub const PARAM_MODE: &str = "mode";
+pub const PARAM_CHANGE: &str = "change";
+pub const PARAM_PROPOSAL_ID: &str = "proposal_id";
+pub const PARAM_DELAY: &str = "delay";
+pub const PARAM_TIMELOCK_DELAY: &str = "timelock_delay";
+/// Maximal deadline horizon, in milliseconds like the block time.
+pub const PARAM_HORIZON: &str = "horizon";
 
-fn operator_access() -> EntryPointAccess {
-    EntryPointAccess::Groups(vec![Group::new(GROUP_OPERATOR)])
+fn role_access(role: &str) -> EntryPointAccess {
+    EntryPointAccess::Groups(vec![Group::new(role)])
 }
 
 pub fn bridge_in() -> EntryPoint {
     EntryPoint::new(
         EP_BRIDGE_IN,
-        vec![
-            Parameter::new(PARAM_TOKEN_CONTRACT, ContractPackageHash::cl_type()),
-            Parameter::new(PARAM_AMOUNT, U256::cl_type()),
-            Parameter::new(PARAM_GAS_COMMISSION, U256::cl_type()),
-            Parameter::new(PARAM_DEADLINE, U256::cl_type()),
-            Parameter::new(PARAM_NONCE, U128::cl_type()),
-            Parameter::new(PARAM_TRANSACTION_ID, U256::cl_type()),
-            Parameter::new(PARAM_DESTINATION_CHAIN, String::cl_type()),
-            Parameter::new(PARAM_DESTINATION_ADDRESS, String::cl_type()),
-            Parameter::new(PARAM_SIGNATURE, CLType::ByteArray(64)),
-        ],
+        BridgeInArgs::parameters(),
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Session,
@@ -62,19 +135,7 @@ pub fn bridge_in() -> EntryPoint {
 pub fn bridge_in_confirm() -> EntryPoint {
     EntryPoint::new(
         EP_BRIDGE_IN_CONFIRM,
-        vec![
-            Parameter::new(PARAM_TOKEN_CONTRACT, ContractPackageHash::cl_type()),
-            Parameter::new(PARAM_AMOUNT, U256::cl_type()),
-            Parameter::new(PARAM_GAS_COMMISSION, U256::cl_type()),
-            Parameter::new(PARAM_NONCE, U128::cl_type()),
-            Parameter::new(PARAM_TRANSACTION_ID, U128::cl_type()),
-            Parameter::new(PARAM_DESTINATION_CHAIN, String::cl_type()),
-            Parameter::new(PARAM_DESTINATION_ADDRESS, String::cl_type()),
-            Parameter::new(PARAM_SENDER, Key::cl_type()),
-            Parameter::new(PARAM_GAS_COMMISSION, U256::cl_type()),
-            Parameter::new(PARAM_DEADLINE, U256::cl_type()),
-            Parameter::new(PARAM_SIGNATURE, CLType::ByteArray(64)),
-        ],
+        BridgeInConfirmArgs::parameters(),
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -84,12 +145,7 @@ pub fn bridge_in_confirm() -> EntryPoint {
 pub fn check_params() -> EntryPoint {
     EntryPoint::new(
         EP_CHECK_PARAMS,
-        vec![
-            Parameter::new(PARAM_BYTES, String::cl_type()),
-            Parameter::new(PARAM_SIGNER, String::cl_type()),
-            Parameter::new(PARAM_SIGNATURE, CLType::ByteArray(64)),
-            Parameter::new(PARAM_NONCE, U128::cl_type()),
-        ],
+        CheckParamsArgs::parameters(),
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -99,17 +155,9 @@ pub fn check_params() -> EntryPoint {
 pub fn bridge_out() -> EntryPoint {
     EntryPoint::new(
         EP_BRIDGE_OUT,
-        vec![
-            Parameter::new(PARAM_TOKEN_CONTRACT, ContractPackageHash::cl_type()),
-            Parameter::new(PARAM_AMOUNT, U256::cl_type()),
-            Parameter::new(PARAM_TRANSACTION_ID, U256::cl_type()),
-            Parameter::new(PARAM_SOURCE_CHAIN, String::cl_type()),
-            Parameter::new(PARAM_SOURCE_ADDRESS, String::cl_type()),
-            Parameter::new(PARAM_RECIPIENT, Key::cl_type()),
-            Parameter::new(PARAM_TRANSACTION_ID, U256::cl_type()),
-        ],
+        BridgeOutArgs::parameters(),
         CLType::Unit,
-        operator_access(),
+        role_access(ROLE_RELAYER),
         EntryPointType::Contract,
     )
 }
@@ -117,15 +165,7 @@ pub fn bridge_out() -> EntryPoint {
 pub fn transfer_out() -> EntryPoint {
     EntryPoint::new(
         EP_TRANSFER_OUT,
-        vec![
-            Parameter::new(PARAM_TOKEN_CONTRACT, ContractPackageHash::cl_type()),
-            Parameter::new(PARAM_AMOUNT, U256::cl_type()),
-            Parameter::new(PARAM_COMMISSION, U256::cl_type()),
-            Parameter::new(PARAM_NONCE, U128::cl_type()),
-            Parameter::new(PARAM_TRANSACTION_ID, U256::cl_type()),
-            Parameter::new(PARAM_RECIPIENT, Key::cl_type()),
-            Parameter::new(PARAM_SIGNATURE, CLType::ByteArray(64)),
-        ],
+        TransferOutArgs::parameters(),
         CLType::Unit,
         EntryPointAccess::Public,
         EntryPointType::Contract,
@@ -135,16 +175,9 @@ pub fn transfer_out() -> EntryPoint {
 pub fn withdraw_commission() -> EntryPoint {
     EntryPoint::new(
         EP_WITHDRAW_COMMISSION,
-        vec![
-            Parameter::new(PARAM_TOKEN_CONTRACT, ContractPackageHash::cl_type()),
-            Parameter::new(PARAM_AMOUNT, U256::cl_type()),
-            Parameter::new(PARAM_RECIPIENT, Key::cl_type()),
-            Parameter::new(PARAM_COMMISSION, U256::cl_type()),
-            Parameter::new(PARAM_NONCE, U256::cl_type()),
-            Parameter::new(PARAM_SIGNATURE, CLType::ByteArray(64)),
-        ],
+        WithdrawCommissionArgs::parameters(),
         CLType::Unit,
-        operator_access(),
+        role_access(ROLE_FEE_MANAGER),
         EntryPointType::Contract,
     )
 }
@@ -152,12 +185,9 @@ pub fn withdraw_commission() -> EntryPoint {
 pub fn set_stable_commission_percent() -> EntryPoint {
     EntryPoint::new(
         EP_SET_STABLE_COMMISSION_PERCENT,
-        vec![Parameter::new(
-            PARAM_STABLE_COMMISSION_PERCENT,
-            U256::cl_type(),
-        )],
+        SetStableCommissionPercentArgs::parameters(),
         CLType::Unit,
-        operator_access(),
+        role_access(ROLE_FEE_MANAGER),
         EntryPointType::Contract,
     )
 }
@@ -172,12 +202,52 @@ pub fn get_stable_commission_percent() -> EntryPoint {
     )
 }
 
+pub fn migrate_stable_commission_to_bps() -> EntryPoint {
+    EntryPoint::new(
+        EP_MIGRATE_STABLE_COMMISSION_TO_BPS,
+        vec![],
+        CLType::Unit,
+        role_access(ROLE_FEE_MANAGER),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_token_stable_commission_percent() -> EntryPoint {
+    EntryPoint::new(
+        EP_SET_TOKEN_STABLE_COMMISSION_PERCENT,
+        SetTokenStableCommissionPercentArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_FEE_MANAGER),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn clear_token_stable_commission_percent() -> EntryPoint {
+    EntryPoint::new(
+        EP_CLEAR_TOKEN_STABLE_COMMISSION_PERCENT,
+        ClearTokenStableCommissionPercentArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_FEE_MANAGER),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_token_stable_commission_percent() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_TOKEN_STABLE_COMMISSION_PERCENT,
+        GetTokenStableCommissionPercentArgs::parameters(),
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
 pub fn set_signer() -> EntryPoint {
     EntryPoint::new(
         EP_SET_SIGNER,
-        vec![Parameter::new(PARAM_SIGNER, String::cl_type())],
+        SetSignerArgs::parameters(),
         CLType::Unit,
-        operator_access(),
+        role_access(ROLE_ADMIN),
         EntryPointType::Contract,
     )
 }
@@ -191,3 +261,393 @@ pub fn get_signer() -> EntryPoint {
         EntryPointType::Contract,
     )
 }
+
+pub fn set_signer_set() -> EntryPoint {
+    EntryPoint::new(
+        EP_SET_SIGNER_SET,
+        SetSignerSetArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_signer_set() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_SIGNER_SET,
+        vec![],
+        SignerSet::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn pause() -> EntryPoint {
+    EntryPoint::new(
+        EP_PAUSE,
+        vec![],
+        CLType::Unit,
+        role_access(ROLE_PAUSER),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn unpause() -> EntryPoint {
+    EntryPoint::new(
+        EP_UNPAUSE,
+        vec![],
+        CLType::Unit,
+        role_access(ROLE_PAUSER),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn add_token() -> EntryPoint {
+    EntryPoint::new(
+        EP_ADD_TOKEN,
+        AddTokenArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn remove_token() -> EntryPoint {
+    EntryPoint::new(
+        EP_REMOVE_TOKEN,
+        RemoveTokenArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_token_config() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_TOKEN_CONFIG,
+        GetTokenConfigArgs::parameters(),
+        TokenConfig::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_bridge_in_limits() -> EntryPoint {
+    EntryPoint::new(
+        EP_SET_BRIDGE_IN_LIMITS,
+        SetBridgeInLimitsArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_bridge_out_limits() -> EntryPoint {
+    EntryPoint::new(
+        EP_SET_BRIDGE_OUT_LIMITS,
+        SetBridgeOutLimitsArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_token_limits() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_TOKEN_LIMITS,
+        GetTokenLimitsArgs::parameters(),
+        TokenLimits::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_outflow_volume() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_OUTFLOW_VOLUME,
+        GetOutflowVolumeArgs::parameters(),
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn grant_role() -> EntryPoint {
+    EntryPoint::new(
+        EP_GRANT_ROLE,
+        GrantRoleArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn revoke_role() -> EntryPoint {
+    EntryPoint::new(
+        EP_REVOKE_ROLE,
+        RevokeRoleArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn claim_role() -> EntryPoint {
+    EntryPoint::new(
+        EP_CLAIM_ROLE,
+        ClaimRoleArgs::parameters(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Session,
+    )
+}
+
+pub fn take_role_access() -> EntryPoint {
+    EntryPoint::new(
+        EP_TAKE_ROLE_ACCESS,
+        TakeRoleAccessArgs::parameters(),
+        URef::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn has_role() -> EntryPoint {
+    EntryPoint::new(
+        EP_HAS_ROLE,
+        HasRoleArgs::parameters(),
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn migrate() -> EntryPoint {
+    EntryPoint::new(
+        EP_MIGRATE,
+        MigrateArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn is_nonce_used() -> EntryPoint {
+    EntryPoint::new(
+        EP_IS_NONCE_USED,
+        IsNonceUsedArgs::parameters(),
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn is_transfer_processed() -> EntryPoint {
+    EntryPoint::new(
+        EP_IS_TRANSFER_PROCESSED,
+        IsTransferProcessedArgs::parameters(),
+        CLType::Bool,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn bridge_in_native() -> EntryPoint {
+    EntryPoint::new(
+        EP_BRIDGE_IN_NATIVE,
+        BridgeInNativeArgs::parameters(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn bridge_out_native() -> EntryPoint {
+    EntryPoint::new(
+        EP_BRIDGE_OUT_NATIVE,
+        BridgeOutNativeArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_RELAYER),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn withdraw_native_commission() -> EntryPoint {
+    EntryPoint::new(
+        EP_WITHDRAW_NATIVE_COMMISSION,
+        WithdrawNativeCommissionArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_FEE_MANAGER),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_native_commission() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_NATIVE_COMMISSION,
+        vec![],
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_token_mode() -> EntryPoint {
+    EntryPoint::new(
+        EP_SET_TOKEN_MODE,
+        SetTokenModeArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_wrapped_supply() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_WRAPPED_SUPPLY,
+        GetWrappedSupplyArgs::parameters(),
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn bridge_in_from() -> EntryPoint {
+    EntryPoint::new(
+        EP_BRIDGE_IN_FROM,
+        BridgeInFromArgs::parameters(),
+        CLType::Unit,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn rebalance_liquidity() -> EntryPoint {
+    EntryPoint::new(
+        EP_REBALANCE_LIQUIDITY,
+        RebalanceLiquidityArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn deposit_liquidity() -> EntryPoint {
+    EntryPoint::new(
+        EP_DEPOSIT_LIQUIDITY,
+        DepositLiquidityArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn propose_change() -> EntryPoint {
+    EntryPoint::new(
+        EP_PROPOSE_CHANGE,
+        ProposeChangeArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn execute_change() -> EntryPoint {
+    EntryPoint::new(
+        EP_EXECUTE_CHANGE,
+        ExecuteChangeArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn cancel_change() -> EntryPoint {
+    EntryPoint::new(
+        EP_CANCEL_CHANGE,
+        CancelChangeArgs::parameters(),
+        CLType::Unit,
+        EntryPointAccess::Groups(vec![Group::new(ROLE_ADMIN), Group::new(ROLE_PAUSER)]),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_proposal() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_PROPOSAL,
+        GetProposalArgs::parameters(),
+        Option::<Proposal>::cl_type(),
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_timelock_delay() -> EntryPoint {
+    EntryPoint::new(
+        EP_SET_TIMELOCK_DELAY,
+        SetTimelockDelayArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_timelock_delay() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_TIMELOCK_DELAY,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn set_max_deadline_horizon() -> EntryPoint {
+    EntryPoint::new(
+        EP_SET_MAX_DEADLINE_HORIZON,
+        SetMaxDeadlineHorizonArgs::parameters(),
+        CLType::Unit,
+        role_access(ROLE_ADMIN),
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_max_deadline_horizon() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_MAX_DEADLINE_HORIZON,
+        vec![],
+        CLType::U64,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_commission_pool() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_COMMISSION_POOL,
+        GetCommissionPoolArgs::parameters(),
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_available_liquidity() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_AVAILABLE_LIQUIDITY,
+        GetAvailableLiquidityArgs::parameters(),
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
+
+pub fn get_total_commission() -> EntryPoint {
+    EntryPoint::new(
+        EP_GET_TOTAL_COMMISSION,
+        GetTotalCommissionArgs::parameters(),
+        CLType::U256,
+        EntryPointAccess::Public,
+        EntryPointType::Contract,
+    )
+}
@@ -36,6 +36,84 @@ pub enum BridgeError {
     #[cfg_attr(std, error("Total commission bigger than transferred amount"))]
     CommissionBiggerThanTransferredAmount = 9,
 
+    #[cfg_attr(std, error("Contract is paused"))]
+    ContractPaused = 10,
+
+    #[cfg_attr(std, error("Token is not registered in the bridge"))]
+    TokenNotRegistered = 11,
+
+    #[cfg_attr(std, error("Amount is below the minimal bridge in amount"))]
+    AmountBelowMinimum = 12,
+
+    #[cfg_attr(std, error("Amount is above the maximal bridge in amount"))]
+    AmountAboveMaximum = 13,
+
+    #[cfg_attr(std, error("Amount exceed the maximal bridge out amount"))]
+    AmountExceedTransferLimit = 14,
+
+    #[cfg_attr(std, error("Amount exceed the bridge out cap of the current window"))]
+    AmountExceedOutflowCap = 15,
+
+    #[cfg_attr(std, error("Invalid token limits"))]
+    InvalidTokenLimits = 16,
+
+    #[cfg_attr(std, error("Invalid signer set or threshold"))]
+    InvalidSignerSet = 17,
+
+    #[cfg_attr(std, error("Message signed more than once by the same signer"))]
+    DuplicateSigner = 18,
+
+    #[cfg_attr(std, error("Not enough signatures to reach the signer threshold"))]
+    NotEnoughSignatures = 19,
+
+    #[cfg_attr(std, error("Unknown role"))]
+    UnknownRole = 20,
+
+    #[cfg_attr(std, error("Role is not granted to the account"))]
+    RoleNotGranted = 21,
+
+    #[cfg_attr(std, error("Role is already granted to the account"))]
+    RoleAlreadyGranted = 22,
+
+    #[cfg_attr(std, error("Inbound transfer is already processed"))]
+    TransferAlreadyProcessed = 23,
+
+    #[cfg_attr(std, error("Native CSPR can only be sent to an account"))]
+    RecipientNotAccount = 24,
+
+    #[cfg_attr(std, error("Unknown token mode"))]
+    InvalidTokenMode = 25,
+
+    #[cfg_attr(
+        std,
+        error("Token mode cannot change while wrapped supply is outstanding")
+    )]
+    WrappedSupplyOutstanding = 26,
+
+    #[cfg_attr(std, error("Change must be proposed while the timelock is active"))]
+    TimelockActive = 27,
+
+    #[cfg_attr(std, error("Unknown change proposal"))]
+    UnknownProposal = 28,
+
+    #[cfg_attr(std, error("Timelock of the change proposal has not elapsed"))]
+    TimelockNotElapsed = 29,
+
+    #[cfg_attr(std, error("Unknown event mode"))]
+    InvalidEventMode = 30,
+
+    #[cfg_attr(
+        std,
+        error("Signature deadline is beyond the maximal deadline horizon")
+    )]
+    DeadlineTooFar = 31,
+
+    #[cfg_attr(std, error("Unknown signature mode"))]
+    InvalidSignatureMode = 32,
+
+    #[cfg_attr(std, error("Treasury is not set"))]
+    TreasuryNotSet = 33,
+
     #[cfg_attr(std, error("Integer Underflow"))]
     Underflow = 253,
 
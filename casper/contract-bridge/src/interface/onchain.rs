@@ -1,13 +1,18 @@
 use alloc::{string::String, vec::Vec};
 use casper_contract::{contract_api::runtime::call_contract, unwrap_or_revert::UnwrapOrRevert};
-use casper_types::{
-    bytesrepr::Bytes, ContractHash, ContractPackageHash, Key, RuntimeArgs, U128, U256,
-};
+use casper_types::{bytesrepr::Bytes, ContractHash, ContractPackageHash, Key, URef, U128, U256};
 
-use crate::entry_points::{
-    EP_BRIDGE_IN_CONFIRM, EP_CHECK_PARAMS, PARAM_AMOUNT, PARAM_BYTES, PARAM_DESTINATION_ADDRESS,
-    PARAM_DESTINATION_CHAIN, PARAM_GAS_COMMISSION, PARAM_NONCE, PARAM_SENDER, PARAM_SIGNATURE,
-    PARAM_TOKEN_CONTRACT, PARAM_TRANSACTION_ID,
+use crate::{
+    args::{
+        BridgeInConfirmArgs, BridgeInFromArgs, BridgeInNativeArgs, CheckParamsArgs,
+        GetAvailableLiquidityArgs, GetCommissionPoolArgs, GetTotalCommissionArgs, IsNonceUsedArgs,
+        IsTransferProcessedArgs, MigrateArgs, TakeRoleAccessArgs,
+    },
+    entry_points::{
+        EP_BRIDGE_IN_CONFIRM, EP_BRIDGE_IN_FROM, EP_BRIDGE_IN_NATIVE, EP_CHECK_PARAMS,
+        EP_GET_AVAILABLE_LIQUIDITY, EP_GET_COMMISSION_POOL, EP_GET_TOTAL_COMMISSION,
+        EP_IS_NONCE_USED, EP_IS_TRANSFER_PROCESSED, EP_MIGRATE, EP_TAKE_ROLE_ACCESS,
+    },
 };
 
 pub fn bridge_in_confirm(
@@ -24,31 +29,190 @@ pub fn bridge_in_confirm(
     call_contract::<()>(
         bridge_contract,
         EP_BRIDGE_IN_CONFIRM,
-        RuntimeArgs::try_new(|args| {
-            args.insert(PARAM_TOKEN_CONTRACT, token_contract)?;
-            args.insert(PARAM_AMOUNT, amount)?;
-            args.insert(PARAM_GAS_COMMISSION, gas_commission)?;
-            args.insert(PARAM_NONCE, nonce)?;
-            args.insert(PARAM_TRANSACTION_ID, transaction_id)?;
-            args.insert(PARAM_DESTINATION_CHAIN, destination_chain)?;
-            args.insert(PARAM_DESTINATION_ADDRESS, destination_address)?;
-            args.insert(PARAM_SENDER, from)?;
-            Ok(())
-        })
+        BridgeInConfirmArgs {
+            token_contract,
+            amount,
+            gas_commission,
+            nonce,
+            transaction_id,
+            destination_chain,
+            destination_address,
+            sender: from,
+        }
+        .into_runtime_args()
         .unwrap_or_revert(),
     );
 }
 
-pub fn check_params(bridge_contract: ContractHash, bytes: Vec<u8>, signature: [u8; 64], nonce: U128) {
+pub fn check_params(
+    bridge_contract: ContractHash,
+    bytes: Vec<u8>,
+    signatures: Vec<Bytes>,
+    nonce: U128,
+) {
     call_contract::<()>(
         bridge_contract,
         EP_CHECK_PARAMS,
-        RuntimeArgs::try_new(|args| {
-            args.insert(PARAM_BYTES, bytes)?;
-            args.insert(PARAM_SIGNATURE, signature)?;
-            args.insert(PARAM_NONCE, nonce)?;
-            Ok(())
-        })
+        CheckParamsArgs {
+            bytes: Bytes::from(bytes),
+            signatures,
+            nonce,
+        }
+        .into_runtime_args()
+        .unwrap_or_revert(),
+    );
+}
+
+pub fn take_role_access(bridge_contract: ContractHash, role: String) -> URef {
+    call_contract::<URef>(
+        bridge_contract,
+        EP_TAKE_ROLE_ACCESS,
+        TakeRoleAccessArgs { role }
+            .into_runtime_args()
+            .unwrap_or_revert(),
+    )
+}
+
+pub fn migrate(bridge_contract: ContractHash, chain_name: String) {
+    call_contract::<()>(
+        bridge_contract,
+        EP_MIGRATE,
+        MigrateArgs { chain_name }
+            .into_runtime_args()
+            .unwrap_or_revert(),
+    );
+}
+
+pub fn bridge_in_native(
+    bridge_contract: ContractHash,
+    amount: U256,
+    gas_commission: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    destination_chain: String,
+    destination_address: String,
+    signatures: Vec<Bytes>,
+    purse: URef,
+) {
+    call_contract::<()>(
+        bridge_contract,
+        EP_BRIDGE_IN_NATIVE,
+        BridgeInNativeArgs {
+            amount,
+            gas_commission,
+            deadline,
+            nonce,
+            transaction_id,
+            destination_chain,
+            destination_address,
+            signatures,
+            purse,
+        }
+        .into_runtime_args()
+        .unwrap_or_revert(),
+    );
+}
+
+pub fn bridge_in_from(
+    bridge_contract: ContractHash,
+    token_contract: ContractPackageHash,
+    amount: U256,
+    gas_commission: U256,
+    deadline: U256,
+    nonce: U128,
+    transaction_id: U256,
+    destination_chain: String,
+    destination_address: String,
+    signatures: Vec<Bytes>,
+) {
+    call_contract::<()>(
+        bridge_contract,
+        EP_BRIDGE_IN_FROM,
+        BridgeInFromArgs {
+            token_contract,
+            amount,
+            gas_commission,
+            deadline,
+            nonce,
+            transaction_id,
+            destination_chain,
+            destination_address,
+            signatures,
+        }
+        .into_runtime_args()
         .unwrap_or_revert(),
     );
 }
+
+pub fn is_nonce_used(bridge_contract: ContractHash, nonce: U128) -> bool {
+    call_contract::<bool>(
+        bridge_contract,
+        EP_IS_NONCE_USED,
+        IsNonceUsedArgs { nonce }
+            .into_runtime_args()
+            .unwrap_or_revert(),
+    )
+}
+
+pub fn is_transfer_processed(
+    bridge_contract: ContractHash,
+    source_chain: String,
+    transaction_id: U256,
+) -> bool {
+    call_contract::<bool>(
+        bridge_contract,
+        EP_IS_TRANSFER_PROCESSED,
+        IsTransferProcessedArgs {
+            source_chain,
+            transaction_id,
+        }
+        .into_runtime_args()
+        .unwrap_or_revert(),
+    )
+}
+
+pub fn get_commission_pool(
+    bridge_contract: ContractHash,
+    token_contract: ContractPackageHash,
+) -> U256 {
+    call_contract::<U256>(
+        bridge_contract,
+        EP_GET_COMMISSION_POOL,
+        GetCommissionPoolArgs { token_contract }
+            .into_runtime_args()
+            .unwrap_or_revert(),
+    )
+}
+
+pub fn get_available_liquidity(
+    bridge_contract: ContractHash,
+    token_contract: ContractPackageHash,
+) -> U256 {
+    call_contract::<U256>(
+        bridge_contract,
+        EP_GET_AVAILABLE_LIQUIDITY,
+        GetAvailableLiquidityArgs { token_contract }
+            .into_runtime_args()
+            .unwrap_or_revert(),
+    )
+}
+
+pub fn get_total_commission(
+    bridge_contract: ContractHash,
+    token_contract: ContractPackageHash,
+    amount: U256,
+    gas_commission: U256,
+) -> U256 {
+    call_contract::<U256>(
+        bridge_contract,
+        EP_GET_TOTAL_COMMISSION,
+        GetTotalCommissionArgs {
+            token_contract,
+            amount,
+            gas_commission,
+        }
+        .into_runtime_args()
+        .unwrap_or_revert(),
+    )
+}
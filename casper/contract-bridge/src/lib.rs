@@ -2,14 +2,26 @@
 
 extern crate alloc;
 
+#[cfg(feature = "abi")]
+pub mod abi;
+pub mod args;
 pub mod commissions;
 pub mod constants;
 pub mod contract;
 pub mod entry_points;
 pub mod error;
+pub mod limits;
+pub mod native;
+pub mod processed_transfers;
+pub mod roles;
+pub mod signers;
+pub mod stable_commissions;
+pub mod timelock;
+pub mod token_registry;
 pub mod uref;
 pub mod used_nonces;
 pub mod util;
+pub mod wrapped_supply;
 pub mod interface {
     pub mod offchain;
     pub mod onchain;
@@ -0,0 +1,149 @@
+//! Implementation of per-token transfer limits.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractPackageHash, URef, U256,
+};
+
+use crate::{
+    constants::{LIMITS_BY_TOKEN_KEY_NAME, OUTFLOW_BY_TOKEN_KEY_NAME},
+    util,
+};
+
+/// Number of buckets an outflow window is split into.
+///
+/// A payout stays accounted until the whole bucket it fell in has left the window, so the volume
+/// paid out within any `outflow_window` never exceeds `outflow_cap`.
+pub(crate) const OUTFLOW_BUCKETS: u64 = 8;
+
+/// Risk limits of a token contract. A zero maximum means there is no limit.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TokenLimits {
+    /// Minimal amount accepted by `bridge_in`.
+    pub min_bridge_in: U256,
+    /// Maximal amount accepted by `bridge_in`.
+    pub max_bridge_in: U256,
+    /// Maximal amount paid out by a single `bridge_out`.
+    pub max_bridge_out: U256,
+    /// Maximal total amount paid out by `bridge_out` within any span of `outflow_window`.
+    pub outflow_cap: U256,
+    /// Length of the outflow window, in block time units.
+    pub outflow_window: u64,
+}
+
+impl CLTyped for TokenLimits {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TokenLimits {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.min_bridge_in.to_bytes()?);
+        buffer.extend(self.max_bridge_in.to_bytes()?);
+        buffer.extend(self.max_bridge_out.to_bytes()?);
+        buffer.extend(self.outflow_cap.to_bytes()?);
+        buffer.extend(self.outflow_window.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.min_bridge_in.serialized_length()
+            + self.max_bridge_in.serialized_length()
+            + self.max_bridge_out.serialized_length()
+            + self.outflow_cap.serialized_length()
+            + self.outflow_window.serialized_length()
+    }
+}
+
+impl FromBytes for TokenLimits {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (min_bridge_in, remainder) = U256::from_bytes(bytes)?;
+        let (max_bridge_in, remainder) = U256::from_bytes(remainder)?;
+        let (max_bridge_out, remainder) = U256::from_bytes(remainder)?;
+        let (outflow_cap, remainder) = U256::from_bytes(remainder)?;
+        let (outflow_window, remainder) = u64::from_bytes(remainder)?;
+        Ok((
+            TokenLimits {
+                min_bridge_in,
+                max_bridge_in,
+                max_bridge_out,
+                outflow_cap,
+                outflow_window,
+            },
+            remainder,
+        ))
+    }
+}
+
+/// Creates a dictionary item key for a dictionary item.
+fn make_dictionary_item_key(token_contract_address: ContractPackageHash) -> String {
+    let preimage = token_contract_address.to_bytes().unwrap_or_revert();
+    base64::encode(preimage)
+}
+
+fn limits_uref() -> URef {
+    util::get_uref(LIMITS_BY_TOKEN_KEY_NAME)
+}
+
+fn outflow_uref() -> URef {
+    util::get_uref(OUTFLOW_BY_TOKEN_KEY_NAME)
+}
+
+/// Writes limits of a specified token contract.
+pub(crate) fn write(token_contract_address: ContractPackageHash, limits: TokenLimits) {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+    storage::dictionary_put(limits_uref(), &dictionary_item_key, limits);
+}
+
+/// Reads limits of a specified token contract.
+///
+/// If a given token does not have limits, then all of them are unset.
+pub(crate) fn read(token_contract_address: ContractPackageHash) -> TokenLimits {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+
+    storage::dictionary_get(limits_uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Length of the buckets an outflow window is split into, rounded up.
+fn bucket_length(outflow_window: u64) -> u64 {
+    let length =
+        outflow_window / OUTFLOW_BUCKETS + u64::from(outflow_window % OUTFLOW_BUCKETS != 0);
+    length.max(1)
+}
+
+/// Block time at which the bucket containing `current_time` ends.
+///
+/// Buckets are keyed by their end rather than by an index over the bucket length, so recorded
+/// volume keeps its meaning when `outflow_window` changes.
+pub(crate) fn bucket_end(current_time: u64, outflow_window: u64) -> u64 {
+    let length = bucket_length(outflow_window);
+    (current_time / length)
+        .saturating_add(1)
+        .saturating_mul(length)
+}
+
+/// Writes the volume paid out in each bucket still within the outflow window.
+pub(crate) fn write_outflow(
+    token_contract_address: ContractPackageHash,
+    buckets: Vec<(u64, U256)>,
+) {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+    storage::dictionary_put(outflow_uref(), &dictionary_item_key, buckets);
+}
+
+/// Reads the volume paid out in each bucket, keyed by the block time the bucket ends at.
+///
+/// If a given token has never been paid out, then no buckets are returned.
+pub(crate) fn read_outflow(token_contract_address: ContractPackageHash) -> Vec<(u64, U256)> {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+
+    storage::dictionary_get(outflow_uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
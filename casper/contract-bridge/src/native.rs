@@ -0,0 +1,72 @@
+//! Implementation of native CSPR custody.
+use casper_contract::{
+    contract_api::{runtime::revert, system},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{account::AccountHash, URef, U256, U512};
+
+use crate::{
+    constants::{NATIVE_COMMISSION_KEY_NAME, NATIVE_PURSE_KEY_NAME},
+    error::BridgeError,
+    uref, util,
+};
+
+fn purse() -> URef {
+    util::get_uref(NATIVE_PURSE_KEY_NAME)
+}
+
+/// Converts a bridged amount into motes.
+pub fn to_motes(amount: U256) -> U512 {
+    let mut bytes = [0u8; 32];
+    amount.to_little_endian(&mut bytes);
+    U512::from_little_endian(&bytes)
+}
+
+/// Converts motes into a bridged amount.
+pub fn from_motes(motes: U512) -> U256 {
+    let mut bytes = [0u8; 64];
+    motes.to_little_endian(&mut bytes);
+    if bytes[32..].iter().any(|byte| *byte != 0) {
+        revert(BridgeError::Overflow)
+    }
+    U256::from_little_endian(&bytes[..32])
+}
+
+/// Reads the balance of the contract purse.
+pub(crate) fn balance() -> U256 {
+    let motes = system::get_purse_balance(purse()).unwrap_or_revert();
+    from_motes(motes)
+}
+
+/// Moves `amount` from `source` purse into the contract purse.
+pub(crate) fn deposit(source: URef, amount: U256) {
+    system::transfer_from_purse_to_purse(source, purse(), to_motes(amount), None)
+        .unwrap_or_revert();
+}
+
+/// Moves `amount` from the contract purse into the main purse of `recipient`.
+pub(crate) fn withdraw(recipient: AccountHash, amount: U256) {
+    system::transfer_from_purse_to_account(purse(), recipient, to_motes(amount), None)
+        .unwrap_or_revert();
+}
+
+/// Add native commission into the pool.
+pub(crate) fn increase_commission(amount: U256) {
+    let new_commission = read_commission()
+        .checked_add(amount)
+        .unwrap_or_revert_with(BridgeError::Overflow);
+    uref::write(NATIVE_COMMISSION_KEY_NAME, new_commission);
+}
+
+/// Substract native commission from the pool.
+pub(crate) fn decrease_commission(amount: U256) {
+    let new_commission = read_commission()
+        .checked_sub(amount)
+        .unwrap_or_revert_with(BridgeError::Underflow);
+    uref::write(NATIVE_COMMISSION_KEY_NAME, new_commission);
+}
+
+/// Reads the native commission pool.
+pub(crate) fn read_commission() -> U256 {
+    uref::read(NATIVE_COMMISSION_KEY_NAME)
+}
@@ -0,0 +1,39 @@
+//! Implementation of processed inbound transfers.
+use alloc::string::String;
+
+use casper_contract::{
+    contract_api::{runtime, storage},
+    unwrap_or_revert::UnwrapOrRevert,
+};
+use casper_types::{bytesrepr::ToBytes, URef, U256};
+
+use crate::{constants::PROCESSED_TRANSFERS_KEY_NAME, util};
+
+/// Creates a dictionary item key for a dictionary item.
+///
+/// The source chain is supplied by the caller, so the preimage is hashed to keep the key within
+/// the dictionary item key length limit.
+fn make_dictionary_item_key(source_chain: &str, transaction_id: U256) -> String {
+    let mut preimage = source_chain.to_bytes().unwrap_or_revert();
+    preimage.append(&mut transaction_id.to_bytes().unwrap_or_revert());
+    base64::encode(runtime::blake2b(preimage))
+}
+
+fn uref() -> URef {
+    util::get_uref(PROCESSED_TRANSFERS_KEY_NAME)
+}
+
+/// Marks an inbound transfer of a source chain as paid out.
+pub(crate) fn mark_processed(source_chain: &str, transaction_id: U256) {
+    let dictionary_item_key = make_dictionary_item_key(source_chain, transaction_id);
+    storage::dictionary_put(uref(), &dictionary_item_key, true);
+}
+
+/// Checks whether an inbound transfer of a source chain was already paid out.
+pub(crate) fn is_processed(source_chain: &str, transaction_id: U256) -> bool {
+    let dictionary_item_key = make_dictionary_item_key(source_chain, transaction_id);
+
+    storage::dictionary_get(uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
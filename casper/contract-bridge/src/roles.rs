@@ -0,0 +1,45 @@
+//! Implementation of role grants.
+use alloc::string::String;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, Key, URef};
+
+use crate::{
+    constants::{ROLES, ROLE_GRANTS_KEY_NAME},
+    util,
+};
+
+/// Checks whether `role` is one of the roles known to the bridge.
+pub(crate) fn is_known(role: &str) -> bool {
+    ROLES.contains(&role)
+}
+
+/// Creates a dictionary item key for a dictionary item.
+fn make_dictionary_item_key(role: &str, account: Key) -> String {
+    let mut preimage = role.to_bytes().unwrap_or_revert();
+    preimage.append(&mut account.to_bytes().unwrap_or_revert());
+    base64::encode(preimage)
+}
+
+fn uref() -> URef {
+    util::get_uref(ROLE_GRANTS_KEY_NAME)
+}
+
+/// Writes the access URef granted to an account for a specified role.
+///
+/// Writing `None` revokes the grant.
+pub(crate) fn write(role: &str, account: Key, access_uref: Option<URef>) {
+    let dictionary_item_key = make_dictionary_item_key(role, account);
+    storage::dictionary_put(uref(), &dictionary_item_key, access_uref);
+}
+
+/// Reads the access URef granted to an account for a specified role.
+///
+/// If a given account does not hold the role, then `None` is returned.
+pub(crate) fn read(role: &str, account: Key) -> Option<URef> {
+    let dictionary_item_key = make_dictionary_item_key(role, account);
+
+    storage::dictionary_get::<Option<URef>>(uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
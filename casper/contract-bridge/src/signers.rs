@@ -0,0 +1,78 @@
+//! Implementation of the signer set.
+use alloc::{string::String, vec::Vec};
+
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped,
+};
+use contract_util::signatures::SignerId;
+
+use crate::{constants::SIGNER_KEYS_KEY_NAME, uref};
+
+/// Public keys allowed to sign bridge messages, and how many of them must sign each message.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct SignerSet {
+    /// Signers as parsed by `contract_util::signatures::parse_signer` for the signature mode.
+    pub signers: Vec<SignerId>,
+    /// Minimal number of distinct signers required to authorize a message.
+    pub threshold: u8,
+}
+
+impl CLTyped for SignerSet {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for SignerSet {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.signers.to_bytes()?);
+        buffer.extend(self.threshold.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.signers.serialized_length() + self.threshold.serialized_length()
+    }
+}
+
+impl FromBytes for SignerSet {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (signers, remainder) = Vec::<SignerId>::from_bytes(bytes)?;
+        let (threshold, remainder) = u8::from_bytes(remainder)?;
+        Ok((SignerSet { signers, threshold }, remainder))
+    }
+}
+
+/// Signer set of deployments made before signers were stored parsed, with the signers as given to
+/// `set_signer_set`.
+#[derive(Debug)]
+pub(crate) struct LegacySignerSet {
+    pub signers: Vec<String>,
+    pub threshold: u8,
+}
+
+impl CLTyped for LegacySignerSet {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl FromBytes for LegacySignerSet {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (signers, remainder) = Vec::<String>::from_bytes(bytes)?;
+        let (threshold, remainder) = u8::from_bytes(remainder)?;
+        Ok((LegacySignerSet { signers, threshold }, remainder))
+    }
+}
+
+/// Writes the signer set.
+pub(crate) fn write(signer_set: SignerSet) {
+    uref::write(SIGNER_KEYS_KEY_NAME, signer_set)
+}
+
+/// Reads the signer set.
+pub(crate) fn read() -> SignerSet {
+    uref::read(SIGNER_KEYS_KEY_NAME)
+}
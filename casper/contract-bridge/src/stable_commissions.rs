@@ -0,0 +1,36 @@
+//! Implementation of per-token stable commission percent overrides.
+use alloc::string::String;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, ContractPackageHash, URef, U256};
+
+use crate::{constants::STABLE_COMMISSION_BY_TOKEN_KEY_NAME, util};
+
+/// Creates a dictionary item key for a dictionary item.
+fn make_dictionary_item_key(token_contract_address: ContractPackageHash) -> String {
+    let preimage = token_contract_address.to_bytes().unwrap_or_revert();
+    base64::encode(preimage)
+}
+
+fn uref() -> URef {
+    util::get_uref(STABLE_COMMISSION_BY_TOKEN_KEY_NAME)
+}
+
+/// Writes the stable commission percent override of a specified token contract.
+///
+/// Writing `None` clears the override.
+pub(crate) fn write(token_contract_address: ContractPackageHash, percent: Option<U256>) {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+    storage::dictionary_put(uref(), &dictionary_item_key, percent);
+}
+
+/// Reads the stable commission percent override of a specified token contract.
+///
+/// If a given token does not have an override, then `None` is returned.
+pub(crate) fn read(token_contract_address: ContractPackageHash) -> Option<U256> {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+
+    storage::dictionary_get::<Option<U256>>(uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
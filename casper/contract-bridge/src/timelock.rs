@@ -0,0 +1,100 @@
+//! Implementation of timelocked change proposals.
+use alloc::{string::String, vec::Vec};
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, URef,
+};
+
+use crate::{
+    constants::{NEXT_PROPOSAL_ID_KEY_NAME, PROPOSALS_KEY_NAME, TIMELOCK_DELAY_KEY_NAME},
+    error::BridgeError,
+    uref, util,
+};
+use casper_common::change::Change;
+
+/// Change waiting for its timelock to elapse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Proposal {
+    /// Change applied on execution.
+    pub change: Change,
+    /// Block time from which the proposal can be executed.
+    pub eta: u64,
+}
+
+impl CLTyped for Proposal {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Proposal {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.change.to_bytes()?);
+        buffer.extend(self.eta.to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.change.serialized_length() + self.eta.serialized_length()
+    }
+}
+
+impl FromBytes for Proposal {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (change, remainder) = Change::from_bytes(bytes)?;
+        let (eta, remainder) = u64::from_bytes(remainder)?;
+        Ok((Proposal { change, eta }, remainder))
+    }
+}
+
+/// Creates a dictionary item key for a dictionary item.
+fn make_dictionary_item_key(id: u64) -> String {
+    let preimage = id.to_bytes().unwrap_or_revert();
+    base64::encode(preimage)
+}
+
+fn uref() -> URef {
+    util::get_uref(PROPOSALS_KEY_NAME)
+}
+
+/// Writes a proposal under a specified id.
+///
+/// Writing `None` removes the proposal.
+pub(crate) fn write(id: u64, proposal: Option<Proposal>) {
+    let dictionary_item_key = make_dictionary_item_key(id);
+    storage::dictionary_put(uref(), &dictionary_item_key, proposal);
+}
+
+/// Reads the proposal stored under a specified id.
+///
+/// If there is no pending proposal with a given id, then `None` is returned.
+pub(crate) fn read(id: u64) -> Option<Proposal> {
+    let dictionary_item_key = make_dictionary_item_key(id);
+
+    storage::dictionary_get::<Option<Proposal>>(uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .flatten()
+}
+
+/// Returns an unused proposal id and reserves it.
+pub(crate) fn next_id() -> u64 {
+    let id: u64 = uref::read(NEXT_PROPOSAL_ID_KEY_NAME);
+    let next_id = id
+        .checked_add(1)
+        .unwrap_or_revert_with(BridgeError::Overflow);
+    uref::write(NEXT_PROPOSAL_ID_KEY_NAME, next_id);
+    id
+}
+
+/// Writes the delay between proposing a change and executing it.
+pub(crate) fn write_delay(delay: u64) {
+    uref::write(TIMELOCK_DELAY_KEY_NAME, delay)
+}
+
+/// Reads the delay between proposing a change and executing it.
+pub(crate) fn read_delay() -> u64 {
+    uref::read(TIMELOCK_DELAY_KEY_NAME)
+}
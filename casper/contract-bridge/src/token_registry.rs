@@ -0,0 +1,118 @@
+//! Implementation of the token registry.
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{
+    bytesrepr::{self, FromBytes, ToBytes},
+    CLType, CLTyped, ContractPackageHash, URef,
+};
+
+use crate::{constants::TOKEN_REGISTRY_KEY_NAME, util};
+
+/// How the bridge moves a token between chains.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TokenMode {
+    /// Tokens are locked in the bridge pool by `bridge_in` and released by `bridge_out`.
+    Lock = 0,
+    /// Wrapped tokens are burned by `bridge_in` and minted by `bridge_out`.
+    MintBurn = 1,
+}
+
+impl Default for TokenMode {
+    fn default() -> Self {
+        TokenMode::Lock
+    }
+}
+
+impl TryFrom<u8> for TokenMode {
+    type Error = bytesrepr::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(TokenMode::Lock),
+            1 => Ok(TokenMode::MintBurn),
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+/// Bridge-side configuration of a token contract.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TokenConfig {
+    /// Whether the token is allowed to be bridged.
+    pub enabled: bool,
+    /// Whether the token is locked or minted and burned by the bridge.
+    pub mode: TokenMode,
+}
+
+impl CLTyped for TokenConfig {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for TokenConfig {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.extend(self.enabled.to_bytes()?);
+        buffer.extend((self.mode as u8).to_bytes()?);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.enabled.serialized_length() + (self.mode as u8).serialized_length()
+    }
+}
+
+impl FromBytes for TokenConfig {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (enabled, remainder) = bool::from_bytes(bytes)?;
+        // Tokens registered before token modes were stored without a mode and are locked.
+        let (mode, remainder) = if remainder.is_empty() {
+            (TokenMode::Lock, remainder)
+        } else {
+            let (mode, remainder) = u8::from_bytes(remainder)?;
+            (TokenMode::try_from(mode)?, remainder)
+        };
+        Ok((TokenConfig { enabled, mode }, remainder))
+    }
+}
+
+/// Creates a dictionary item key for a dictionary item.
+fn make_dictionary_item_key(token_contract_address: ContractPackageHash) -> String {
+    let preimage = token_contract_address.to_bytes().unwrap_or_revert();
+    base64::encode(preimage)
+}
+
+fn uref() -> URef {
+    util::get_uref(TOKEN_REGISTRY_KEY_NAME)
+}
+
+/// Writes the configuration of a specified token contract.
+pub(crate) fn write(token_contract_address: ContractPackageHash, config: TokenConfig) {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+    storage::dictionary_put(uref(), &dictionary_item_key, config);
+}
+
+/// Reads the configuration of a specified token contract.
+///
+/// If a given token was never registered, then a disabled configuration is returned.
+pub(crate) fn read(token_contract_address: ContractPackageHash) -> TokenConfig {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+
+    storage::dictionary_get(uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
+
+/// Checks whether a specified token contract is allowed to be bridged.
+pub(crate) fn is_registered(token_contract_address: ContractPackageHash) -> bool {
+    read(token_contract_address).enabled
+}
+
+/// Checks whether a specified token contract is minted and burned by the bridge.
+pub(crate) fn is_wrapped(token_contract_address: ContractPackageHash) -> bool {
+    read(token_contract_address).mode == TokenMode::MintBurn
+}
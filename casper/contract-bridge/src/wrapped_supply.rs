@@ -0,0 +1,48 @@
+//! Implementation of wrapped token accounting.
+use alloc::string::String;
+
+use casper_contract::{contract_api::storage, unwrap_or_revert::UnwrapOrRevert};
+use casper_types::{bytesrepr::ToBytes, ContractPackageHash, URef, U256};
+
+use crate::{constants::WRAPPED_SUPPLY_BY_TOKEN_KEY_NAME, error::BridgeError, util};
+
+/// Creates a dictionary item key for a dictionary item.
+fn make_dictionary_item_key(token_contract_address: ContractPackageHash) -> String {
+    let preimage = token_contract_address.to_bytes().unwrap_or_revert();
+    base64::encode(preimage)
+}
+
+fn uref() -> URef {
+    util::get_uref(WRAPPED_SUPPLY_BY_TOKEN_KEY_NAME)
+}
+
+/// Account tokens minted by the bridge for a specified token.
+pub(crate) fn increase(token_contract_address: ContractPackageHash, amount: U256) {
+    let new_supply = read(token_contract_address)
+        .checked_add(amount)
+        .unwrap_or_revert_with(BridgeError::Overflow);
+
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+    storage::dictionary_put(uref(), &dictionary_item_key, new_supply);
+}
+
+/// Account tokens burned by the bridge for a specified token.
+pub(crate) fn decrease(token_contract_address: ContractPackageHash, amount: U256) {
+    let new_supply = read(token_contract_address)
+        .checked_sub(amount)
+        .unwrap_or_revert_with(BridgeError::Underflow);
+
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+    storage::dictionary_put(uref(), &dictionary_item_key, new_supply);
+}
+
+/// Reads the supply minted by the bridge and not yet burned of a specified token contract.
+///
+/// If a given token was never minted, then a 0 is returned.
+pub(crate) fn read(token_contract_address: ContractPackageHash) -> U256 {
+    let dictionary_item_key = make_dictionary_item_key(token_contract_address);
+
+    storage::dictionary_get(uref(), &dictionary_item_key)
+        .unwrap_or_revert()
+        .unwrap_or_default()
+}
@@ -15,6 +15,8 @@ mod consts {
     pub(super) const EP_ALLOWANCE: &str = "allowance";
     pub(super) const EP_TRANSFER_FROM: &str = "transfer_from";
     pub(super) const EP_TOTAL_SUPPLY: &str = "total_supply";
+    pub(super) const EP_MINT: &str = "mint";
+    pub(super) const EP_BURN: &str = "burn";
 
     pub(super) const PARAM_ADDRESS: &str = "address";
     pub(super) const PARAM_OWNER: &str = "owner";
@@ -49,3 +51,64 @@ pub fn balance_of(contract: ContractPackageHash, address: Key) -> U256 {
 
     call_versioned_contract::<U256>(contract, None, EP_BALANCE_OF, args)
 }
+
+/// Mints `amount` tokens to `owner`. The caller must be a minter of the token contract.
+pub fn mint(contract: ContractPackageHash, owner: Key, amount: U256) {
+    let args = RuntimeArgs::try_new(|args| {
+        args.insert(PARAM_OWNER, owner)?;
+        args.insert(PARAM_AMOUNT, amount)?;
+        Ok(())
+    })
+    .unwrap_or_revert();
+
+    call_versioned_contract::<()>(contract, None, EP_MINT, args);
+}
+
+/// Burns `amount` tokens of `owner`. The caller must be the owner of the tokens.
+pub fn burn(contract: ContractPackageHash, owner: Key, amount: U256) {
+    let args = RuntimeArgs::try_new(|args| {
+        args.insert(PARAM_OWNER, owner)?;
+        args.insert(PARAM_AMOUNT, amount)?;
+        Ok(())
+    })
+    .unwrap_or_revert();
+
+    call_versioned_contract::<()>(contract, None, EP_BURN, args);
+}
+
+/// Allows `spender` to transfer up to `amount` tokens of the caller.
+pub fn approve(contract: ContractPackageHash, spender: Key, amount: U256) {
+    let args = RuntimeArgs::try_new(|args| {
+        args.insert(PARAM_SPENDER, spender)?;
+        args.insert(PARAM_AMOUNT, amount)?;
+        Ok(())
+    })
+    .unwrap_or_revert();
+
+    call_versioned_contract::<()>(contract, None, EP_APPROVE, args);
+}
+
+/// Amount of tokens of `owner` that `spender` is still allowed to transfer.
+pub fn allowance(contract: ContractPackageHash, owner: Key, spender: Key) -> U256 {
+    let args = RuntimeArgs::try_new(|args| {
+        args.insert(PARAM_OWNER, owner)?;
+        args.insert(PARAM_SPENDER, spender)?;
+        Ok(())
+    })
+    .unwrap_or_revert();
+
+    call_versioned_contract::<U256>(contract, None, EP_ALLOWANCE, args)
+}
+
+/// Transfers `amount` tokens of `owner` to `recipient`, spending the caller's allowance.
+pub fn transfer_from(contract: ContractPackageHash, owner: Key, recipient: Key, amount: U256) {
+    let args = RuntimeArgs::try_new(|args| {
+        args.insert(PARAM_OWNER, owner)?;
+        args.insert(PARAM_RECIPIENT, recipient)?;
+        args.insert(PARAM_AMOUNT, amount)?;
+        Ok(())
+    })
+    .unwrap_or_revert();
+
+    call_versioned_contract::<()>(contract, None, EP_TRANSFER_FROM, args);
+}
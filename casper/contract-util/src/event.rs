@@ -1,14 +1,45 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 use casper_contract::{
-    contract_api::storage::{self, new_uref},
+    contract_api::{
+        runtime,
+        storage::{self, new_uref},
+    },
     unwrap_or_revert::UnwrapOrRevert,
 };
 use casper_types::{
-    bytesrepr::{Bytes, FromBytes, ToBytes},
+    bytesrepr::{self, Bytes, FromBytes, ToBytes},
     contracts::NamedKeys,
-    Key, URef,
+    CLType, CLTyped, Key, URef,
 };
+use num_enum::{IntoPrimitive, TryFromPrimitive};
 
 pub const EVENT_TRIGGER_UREF_NAME: &str = "event_trigger";
+pub const EVENT_MODE_UREF_NAME: &str = "event_mode";
+
+/// Named keys of the Casper Event Standard.
+pub const EVENTS_DICT_NAME: &str = "__events";
+pub const EVENTS_LENGTH_UREF_NAME: &str = "__events_length";
+pub const EVENTS_SCHEMA_UREF_NAME: &str = "__events_schema";
+pub const EVENTS_CES_VERSION_UREF_NAME: &str = "__events_ces_version";
+
+/// Version of the Casper Event Standard events are emitted with.
+pub const CES_VERSION: &str = "0.1.0";
+/// Prefix of the event name every CES event starts with.
+pub const CES_EVENT_PREFIX: &str = "event_";
+
+/// How a contract emits its events, chosen at install.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum EventMode {
+    /// Overwrites the `event_trigger` URef with the latest event.
+    Legacy = 0,
+    /// Appends to the `__events` dictionary, following the Casper Event Standard.
+    Ces = 1,
+}
 
 mod trigger_cache {
     use super::*;
@@ -26,21 +57,145 @@ mod trigger_cache {
     );
 }
 
-pub fn install(named_keys: &mut NamedKeys) {
+/// Event schemas of a contract, stored under `__events_schema`.
+///
+/// Maps every event name, without the CES prefix, to its fields in serialization order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Schemas(pub BTreeMap<String, Vec<(String, CLType)>>);
+
+impl Schemas {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the schema of an event.
+    pub fn with(mut self, name: &str, fields: Vec<(&str, CLType)>) -> Self {
+        let fields = fields
+            .into_iter()
+            .map(|(field, cl_type)| (field.into(), cl_type))
+            .collect();
+        self.0.insert(name.into(), fields);
+        self
+    }
+}
+
+impl CLTyped for Schemas {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for Schemas {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        self.0.to_bytes()
+    }
+
+    fn serialized_length(&self) -> usize {
+        self.0.serialized_length()
+    }
+}
+
+impl FromBytes for Schemas {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (schemas, remainder) = BTreeMap::from_bytes(bytes)?;
+        Ok((Schemas(schemas), remainder))
+    }
+}
+
+/// Adds the named keys the selected event mode writes to.
+///
+/// The legacy trigger is installed in both modes, so clients that look it up keep working.
+pub fn install(named_keys: &mut NamedKeys, mode: EventMode, schemas: Schemas) {
     let event_trigger_uref = new_uref(Bytes::new());
 
     named_keys.insert(
         EVENT_TRIGGER_UREF_NAME.into(),
         Key::URef(event_trigger_uref),
     );
+
+    named_keys.insert(
+        EVENT_MODE_UREF_NAME.into(),
+        Key::URef(new_uref(u8::from(mode))),
+    );
+
+    if mode == EventMode::Ces {
+        let events_uref = storage::new_dictionary(EVENTS_DICT_NAME).unwrap_or_revert();
+        // The dictionary is created under the installer named keys, it should be removed
+        // from there and moved to the contract.
+        runtime::remove_key(EVENTS_DICT_NAME);
+        named_keys.insert(EVENTS_DICT_NAME.into(), Key::URef(events_uref));
+
+        named_keys.insert(EVENTS_LENGTH_UREF_NAME.into(), Key::URef(new_uref(0u32)));
+        named_keys.insert(EVENTS_SCHEMA_UREF_NAME.into(), Key::URef(new_uref(schemas)));
+        named_keys.insert(
+            EVENTS_CES_VERSION_UREF_NAME.into(),
+            Key::URef(new_uref(String::from(CES_VERSION))),
+        );
+    }
 }
 
 pub fn trigger_uref() -> URef {
     **trigger_cache::get()
 }
 
+fn get_uref(name: &str) -> URef {
+    runtime::get_key(name)
+        .and_then(Key::into_uref)
+        .unwrap_or_revert()
+}
+
+/// Reads the event mode of the contract.
+///
+/// Contracts installed before the mode was selectable use the legacy trigger.
+pub fn mode() -> EventMode {
+    match runtime::get_key(EVENT_MODE_UREF_NAME).and_then(Key::into_uref) {
+        Some(uref) => {
+            let mode: u8 = storage::read(uref).unwrap_or_revert().unwrap_or_revert();
+            EventMode::try_from(mode).ok().unwrap_or_revert()
+        }
+        None => EventMode::Legacy,
+    }
+}
+
+/// Dictionary item key of the CES event at `index`.
+pub fn ces_item_key(index: u32) -> String {
+    index.to_string()
+}
+
+/// Serializes an event the way the Casper Event Standard stores it: the prefixed event name
+/// followed by the event fields.
+pub fn to_ces_bytes<T: ContractEvent>(event: &T) -> Result<Vec<u8>, bytesrepr::Error> {
+    let mut name = String::from(CES_EVENT_PREFIX);
+    name.push_str(event.name());
+
+    let mut buffer = name.to_bytes()?;
+    buffer.extend(event.fields_to_bytes()?);
+    Ok(buffer)
+}
+
+fn emit_ces<T: ContractEvent>(event: T) {
+    let length_uref = get_uref(EVENTS_LENGTH_UREF_NAME);
+    let length: u32 = storage::read(length_uref)
+        .unwrap_or_revert()
+        .unwrap_or_revert();
+
+    let bytes = Bytes::from(to_ces_bytes(&event).unwrap_or_revert());
+    storage::dictionary_put(get_uref(EVENTS_DICT_NAME), &ces_item_key(length), bytes);
+
+    storage::write(length_uref, length.checked_add(1).unwrap_or_revert());
+}
+
 pub fn fire<T: ContractEvent>(event: T) {
-    storage::write(trigger_uref(), event.to_bytes().unwrap_or_revert())
+    match mode() {
+        EventMode::Legacy => storage::write(trigger_uref(), event.to_bytes().unwrap_or_revert()),
+        EventMode::Ces => emit_ces(event),
+    }
 }
 
-pub trait ContractEvent: ToBytes + FromBytes {}
+pub trait ContractEvent: ToBytes + FromBytes {
+    /// Name of the event, without the CES prefix.
+    fn name(&self) -> &'static str;
+
+    /// Serializes the fields of the event, without anything identifying the event itself.
+    fn fields_to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error>;
+}
@@ -1,21 +1,223 @@
 use alloc::vec::Vec;
 use casper_types::{
     account::AccountHash,
-    bytesrepr::{Bytes, ToBytes},
-    ContractPackageHash, Key, U128, U256, ContractHash,
+    bytesrepr::{self, Bytes, FromBytes, ToBytes, U8_SERIALIZED_LENGTH},
+    crypto, AsymmetricType, CLType, CLTyped, ContractHash, ContractPackageHash, Key, PublicKey,
+    U128, U256,
 };
+use core::fmt;
 
 use k256::{
     ecdsa::{
+        recoverable,
         signature::Signer,
-        signature::{Signature as SignatureTrait, Verifier},
+        signature::{DigestSigner, Signature as SignatureTrait, Verifier},
         Signature, SigningKey, VerifyingKey,
     },
+    elliptic_curve::sec1::ToEncodedPoint,
     pkcs8::DecodePublicKey,
-    SecretKey as SignatureSecretKey,
+    FieldBytes, SecretKey as SignatureSecretKey,
 };
+use num_enum::{IntoPrimitive, TryFromPrimitive};
+use sha3::{Digest, Keccak256};
+
+/// Prefix of EIP-191 personal messages signing a 32-byte hash.
+const EIP191_PREFIX: &[u8] = b"\x19Ethereum Signed Message:\n32";
+
+/// Tag of Ed25519 keys in the hex encoding of Casper public keys and in stored signers.
+const ED25519_TAG: u8 = 1;
+/// Tag of secp256k1 keys in the hex encoding of Casper public keys and in stored signers.
+const SECP256K1_TAG: u8 = 2;
+/// Tag of Ethereum addresses in stored signers.
+const ADDRESS_TAG: u8 = 3;
+/// Tag of compressed keys recovered from EIP-191 signatures in stored signers.
+const COMPRESSED_KEY_TAG: u8 = 4;
+
+const ED25519_KEY_LENGTH: usize = 32;
+const COMPRESSED_KEY_LENGTH: usize = 33;
+const ADDRESS_LENGTH: usize = 20;
+
+/// How bridge messages are signed, chosen at install.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, IntoPrimitive, TryFromPrimitive)]
+#[repr(u8)]
+pub enum SignatureMode {
+    /// 64-byte signatures over the message, checked against the `SignerKey` of each signer.
+    Raw = 0,
+    /// 65-byte recoverable `(r, s, v)` signatures over the EIP-191 hash of the keccak256 digest
+    /// of the message, as produced by Ethereum signers. Signers are hex encoded 20-byte addresses
+    /// or 33-byte compressed public keys, compared with the key recovered from the signature.
+    Eip191 = 1,
+}
+
+/// Public key of a signer, tagged with its signature algorithm.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerKey {
+    /// Ed25519 public key, as used by Casper accounts.
+    Ed25519([u8; 32]),
+    /// secp256k1 public key.
+    Secp256k1(VerifyingKey),
+}
+
+impl SignerKey {
+    /// Parses a signer given either as a PEM encoded secp256k1 public key, or as a hex encoded
+    /// Casper public key: the tag `01` followed by an Ed25519 key, or the tag `02` followed by a
+    /// compressed secp256k1 key.
+    pub fn parse(signer: &str) -> Option<Self> {
+        if let Ok(key) = VerifyingKey::from_public_key_pem(signer) {
+            return Some(SignerKey::Secp256k1(key));
+        }
+
+        let mut bytes = [0u8; 34];
+        let hex = signer.trim();
+        if hex.len() > 2 * bytes.len() {
+            return None;
+        }
+        let length = base16::decode_slice(hex, &mut bytes).ok()?;
+        match (bytes[0], length) {
+            (ED25519_TAG, 33) => {
+                let key: [u8; 32] = bytes[1..33].try_into().ok()?;
+                PublicKey::ed25519_from_bytes(key)
+                    .ok()
+                    .map(|_| SignerKey::Ed25519(key))
+            }
+            (SECP256K1_TAG, 34) => VerifyingKey::from_sec1_bytes(&bytes[1..])
+                .ok()
+                .map(SignerKey::Secp256k1),
+            _ => None,
+        }
+    }
+
+    /// Whether `signature_bytes` is a valid signature of `bytes` by this key.
+    pub fn verify(&self, signature_bytes: &[u8; 64], bytes: &[u8]) -> bool {
+        match self {
+            SignerKey::Ed25519(key) => {
+                let (key, signature) = match (
+                    PublicKey::ed25519_from_bytes(key),
+                    crypto::Signature::ed25519(*signature_bytes),
+                ) {
+                    (Ok(key), Ok(signature)) => (key, signature),
+                    _ => return false,
+                };
+                crypto::verify(bytes, &signature, &key).is_ok()
+            }
+            SignerKey::Secp256k1(key) => verify_signature_by_key(key, signature_bytes, bytes),
+        }
+    }
+}
+
+/// Signer as parsed for the signature mode of a deployment.
+///
+/// Stored as a tag followed by the key bytes, so that signatures are checked without parsing the
+/// signer again. Displayed in hex, the way `parse_signer` reads it for the signature mode.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerId {
+    /// Public key verifying `SignatureMode::Raw` signatures.
+    Key(SignerKey),
+    /// Ethereum address of the key recovering from `SignatureMode::Eip191` signatures.
+    Address([u8; 20]),
+    /// Compressed public key recovering from `SignatureMode::Eip191` signatures.
+    CompressedKey([u8; 33]),
+}
+
+impl SignerId {
+    /// Whether `key`, recovered from a signature, belongs to this signer.
+    pub fn is_recovered(&self, key: &VerifyingKey) -> bool {
+        match self {
+            SignerId::Key(_) => false,
+            SignerId::Address(address) => *address == ethereum_address(key),
+            SignerId::CompressedKey(compressed) => {
+                compressed[..] == *key.to_encoded_point(true).as_bytes()
+            }
+        }
+    }
+
+    /// Tag and key bytes the signer is stored with.
+    fn tagged_bytes(&self) -> (u8, Vec<u8>) {
+        match self {
+            SignerId::Key(SignerKey::Ed25519(key)) => (ED25519_TAG, key.to_vec()),
+            SignerId::Key(SignerKey::Secp256k1(key)) => (
+                SECP256K1_TAG,
+                key.to_encoded_point(true).as_bytes().to_vec(),
+            ),
+            SignerId::Address(address) => (ADDRESS_TAG, address.to_vec()),
+            SignerId::CompressedKey(compressed) => (COMPRESSED_KEY_TAG, compressed.to_vec()),
+        }
+    }
+}
+
+impl CLTyped for SignerId {
+    fn cl_type() -> CLType {
+        CLType::Any
+    }
+}
+
+impl ToBytes for SignerId {
+    fn to_bytes(&self) -> Result<Vec<u8>, bytesrepr::Error> {
+        let (tag, key) = self.tagged_bytes();
+        let mut buffer = bytesrepr::allocate_buffer(self)?;
+        buffer.push(tag);
+        buffer.extend(key);
+        Ok(buffer)
+    }
+
+    fn serialized_length(&self) -> usize {
+        let key_length = match self {
+            SignerId::Key(SignerKey::Ed25519(_)) => ED25519_KEY_LENGTH,
+            SignerId::Key(SignerKey::Secp256k1(_)) => COMPRESSED_KEY_LENGTH,
+            SignerId::Address(_) => ADDRESS_LENGTH,
+            SignerId::CompressedKey(_) => COMPRESSED_KEY_LENGTH,
+        };
+        U8_SERIALIZED_LENGTH + key_length
+    }
+}
+
+impl FromBytes for SignerId {
+    fn from_bytes(bytes: &[u8]) -> Result<(Self, &[u8]), bytesrepr::Error> {
+        let (tag, remainder) = u8::from_bytes(bytes)?;
+        match tag {
+            ED25519_TAG => {
+                let (key, remainder) = key_from_bytes::<ED25519_KEY_LENGTH>(remainder)?;
+                Ok((SignerId::Key(SignerKey::Ed25519(key)), remainder))
+            }
+            SECP256K1_TAG => {
+                let (key, remainder) = key_from_bytes::<COMPRESSED_KEY_LENGTH>(remainder)?;
+                let key = VerifyingKey::from_sec1_bytes(&key)
+                    .map_err(|_| bytesrepr::Error::Formatting)?;
+                Ok((SignerId::Key(SignerKey::Secp256k1(key)), remainder))
+            }
+            ADDRESS_TAG => {
+                let (address, remainder) = key_from_bytes::<ADDRESS_LENGTH>(remainder)?;
+                Ok((SignerId::Address(address), remainder))
+            }
+            COMPRESSED_KEY_TAG => {
+                let (compressed, remainder) = key_from_bytes::<COMPRESSED_KEY_LENGTH>(remainder)?;
+                Ok((SignerId::CompressedKey(compressed), remainder))
+            }
+            _ => Err(bytesrepr::Error::Formatting),
+        }
+    }
+}
+
+impl fmt::Display for SignerId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (tag, key) = self.tagged_bytes();
+        // Casper public keys are hex encoded with their tag, Ethereum signers without.
+        if let SignerId::Key(_) = self {
+            write!(f, "{tag:02x}")?;
+        }
+        key.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+/// Splits the `N` bytes of a fixed-width key off `bytes`.
+fn key_from_bytes<const N: usize>(bytes: &[u8]) -> Result<([u8; N], &[u8]), bytesrepr::Error> {
+    let (key, remainder) = bytesrepr::safe_split_at(bytes, N)?;
+    let key = key.try_into().map_err(|_| bytesrepr::Error::Formatting)?;
+    Ok((key, remainder))
+}
 
 pub fn cook_msg_bridge_in(
+    chain_name: &str,
     bridge_hash: ContractHash,
     token_package_hash: ContractPackageHash,
     account_address: AccountHash,
@@ -38,6 +240,8 @@ pub fn cook_msg_bridge_in(
 
     let mut bytes = Vec::new();
     bytes.extend_from_slice(prefix.as_bytes());
+    bytes.extend_from_slice(chain_name.as_bytes());
+    bytes.push(0x00);
     bytes.extend_from_slice(bridge_hash.as_bytes());
     bytes.extend_from_slice(token_package_hash.as_bytes());
     bytes.extend_from_slice(account_address.as_bytes());
@@ -53,35 +257,134 @@ pub fn cook_msg_bridge_in(
 }
 
 pub fn cook_msg_transfer_out(
+    chain_name: &str,
     bridge_hash: ContractHash,
     token_package_hash: ContractPackageHash,
     account_address: AccountHash,
     recipient: Key,
     amount_to_transfer: U256,
     commission: U256,
+    deadline: U256,
     nonce: U128,
     transaction_id: U256
 ) -> Vec<u8> {
     let prefix = "TRICORN_TRANSFER_OUT";
     let amount = amount_to_transfer.to_bytes().unwrap();
     let commission = commission.to_bytes().unwrap();
+    let deadline = deadline.to_bytes().unwrap();
     let nonce = nonce.to_bytes().unwrap();
     let transaction_id = transaction_id.to_bytes().unwrap();
     let recipient = recipient.to_bytes().unwrap();
 
     let mut bytes = Vec::new();
     bytes.extend_from_slice(prefix.as_bytes());
+    bytes.extend_from_slice(chain_name.as_bytes());
+    bytes.push(0x00);
     bytes.extend_from_slice(bridge_hash.as_bytes());
     bytes.extend_from_slice(token_package_hash.as_bytes());
     bytes.extend_from_slice(account_address.as_bytes());
     bytes.extend_from_slice(&recipient);
     bytes.extend_from_slice(&amount);
     bytes.extend_from_slice(&commission);
+    bytes.extend_from_slice(&deadline);
     bytes.extend_from_slice(&nonce);
     bytes.extend_from_slice(&transaction_id);
     bytes
 }
 
+/// Parses a signer the way `mode` identifies signers, `None` if it is malformed.
+pub fn parse_signer(mode: SignatureMode, signer: &str) -> Option<SignerId> {
+    match mode {
+        SignatureMode::Raw => SignerKey::parse(signer).map(SignerId::Key),
+        SignatureMode::Eip191 => {
+            let hex = signer.trim();
+            let hex = hex.strip_prefix("0x").unwrap_or(hex);
+            let mut bytes = [0u8; 33];
+            if hex.len() > 2 * bytes.len() {
+                return None;
+            }
+            let length = base16::decode_slice(hex, &mut bytes).ok()?;
+            match length {
+                20 => Some(SignerId::Address(bytes[..20].try_into().ok()?)),
+                33 => VerifyingKey::from_sec1_bytes(&bytes)
+                    .ok()
+                    .map(|_| SignerId::CompressedKey(bytes)),
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Position of the signer in `signers` who signed `bytes` with `signature`.
+pub fn find_signer(
+    mode: SignatureMode,
+    signers: &[SignerId],
+    signature: &[u8],
+    bytes: &[u8],
+) -> Option<usize> {
+    match mode {
+        SignatureMode::Raw => {
+            let signature: &[u8; 64] = signature.try_into().ok()?;
+            signers.iter().position(|signer| match signer {
+                SignerId::Key(key) => key.verify(signature, bytes),
+                _ => false,
+            })
+        }
+        SignatureMode::Eip191 => {
+            let key = recover_signer(signature, bytes)?;
+            signers.iter().position(|signer| signer.is_recovered(&key))
+        }
+    }
+}
+
+/// Hasher fed with the EIP-191 personal message of the keccak256 digest of `bytes`.
+pub fn eip191_hasher(bytes: &[u8]) -> Keccak256 {
+    Keccak256::new()
+        .chain(EIP191_PREFIX)
+        .chain(Keccak256::digest(bytes))
+}
+
+/// Ethereum address of a public key: the last 20 bytes of the keccak256 digest of the
+/// uncompressed key, without its SEC1 tag.
+pub fn ethereum_address(key: &VerifyingKey) -> [u8; 20] {
+    let point = key.to_encoded_point(false);
+    let digest = Keccak256::digest(&point.as_bytes()[1..]);
+    digest[12..].try_into().unwrap()
+}
+
+/// Recovers the public key of an EIP-191 signature, accepting both `v` in `{27, 28}` and
+/// a raw recovery id.
+pub fn recover_signer(signature: &[u8], bytes: &[u8]) -> Option<VerifyingKey> {
+    let mut signature: [u8; 65] = signature.try_into().ok()?;
+    if signature[64] >= 27 {
+        signature[64] -= 27;
+    }
+    let signature = recoverable::Signature::try_from(&signature[..]).ok()?;
+
+    let digest = eip191_hasher(bytes).finalize();
+    signature
+        .recover_verify_key_from_digest_bytes(FieldBytes::from_slice(&digest))
+        .ok()
+}
+
+/// Signs `bytes` with an EIP-191 recoverable signature, with `v` in `{27, 28}`.
+pub fn get_recoverable_signature_bytes(bytes: &[u8], signer: &str) -> [u8; 65] {
+    let se = SignatureSecretKey::from_sec1_pem(signer).unwrap();
+    let signature: recoverable::Signature = SigningKey::from(se).sign_digest(eip191_hasher(bytes));
+
+    let mut signature_bytes: [u8; 65] = signature.as_ref().try_into().unwrap();
+    signature_bytes[64] += 27;
+    signature_bytes
+}
+
+/// Signs `bytes` the way `mode` expects bridge signatures.
+pub fn sign_for_mode(mode: SignatureMode, bytes: &[u8], signer: &str) -> Vec<u8> {
+    match mode {
+        SignatureMode::Raw => get_signature_bytes(bytes, signer).to_vec(),
+        SignatureMode::Eip191 => get_recoverable_signature_bytes(bytes, signer).to_vec(),
+    }
+}
+
 #[inline(always)]
 pub fn check_public_key(signer: &str) -> VerifyingKey {
     VerifyingKey::from_public_key_pem(signer).unwrap_or_else(|e| panic!("Not valid key {e}"))
@@ -97,11 +400,21 @@ pub fn get_signature_bytes(bytes: &[u8], signer: &str) -> [u8; 64] {
 }
 
 pub fn verify_signature(signer: &str, signature_bytes: &[u8; 64], bytes: &Bytes) -> bool {
-    let verify_key = check_public_key(signer);
-
-    let signature_bytes = signature_bytes.to_vec();
+    match SignerKey::parse(signer) {
+        Some(key) => key.verify(signature_bytes, bytes),
+        None => false,
+    }
+}
 
-    let signature = Signature::from_bytes(&signature_bytes).unwrap();
+pub fn verify_signature_by_key(
+    verify_key: &VerifyingKey,
+    signature_bytes: &[u8; 64],
+    bytes: &[u8],
+) -> bool {
+    let signature = match Signature::from_bytes(signature_bytes) {
+        Ok(signature) => signature,
+        Err(_) => return false,
+    };
 
     let res = verify_key.verify(bytes, &signature);
 
@@ -10,11 +10,14 @@ use casper_types::{U128, CLValue};
 use casper_types::{
     account::AccountHash,
     bytesrepr::{Bytes, FromBytes},
-    ContractHash, DeployHash, Key, PublicKey, RuntimeArgs, SecretKey, StoredValue, U256, U512,
+    ContractHash, ContractPackageHash, DeployHash, Key, PublicKey, RuntimeArgs, SecretKey,
+    StoredValue, U256, U512,
 };
 use casper_util::util::{BridgeEnv, CommonEnv};
 use clap::Parser;
 use connectors_common::connector_config::ConnectorConfig;
+use contract_bridge::constants::DEFAULT_TIMELOCK_DELAY;
+use contract_util::{event::EventMode, signatures::SignatureMode};
 use reqwest::Url;
 use serde_json::json;
 
@@ -65,6 +68,24 @@ enum Command {
     DeployBridgeContract {
         #[clap(short = 'c')]
         session_code_path: String,
+        /// Emit events following the Casper Event Standard instead of the legacy trigger
+        #[clap(long)]
+        ces_events: bool,
+        /// Verify EIP-191 recoverable signatures against Ethereum-style signers
+        #[clap(long)]
+        eip191_signatures: bool,
+        /// Delay between proposing and executing a privileged change, in milliseconds
+        #[clap(long, default_value_t = DEFAULT_TIMELOCK_DELAY)]
+        timelock_delay: u64,
+    },
+    UpgradeBridgeContract {
+        #[clap(short = 'c')]
+        session_code_path: String,
+        #[clap(long)]
+        disable_old_version: bool,
+        /// Only needed for deployments that didn't store the package hash in the account
+        #[clap(long)]
+        contract_package_hash: Option<String>,
     },
 
     BridgeIn {
@@ -156,8 +177,33 @@ async fn main() -> anyhow::Result<()> {
             amount,
         } => erc20_transfer(&env, token_contract, destination, amount).await?,
 
-        Command::DeployBridgeContract { session_code_path } => {
-            deploy_bridge_contract(&env, session_code_path).await?
+        Command::DeployBridgeContract {
+            session_code_path,
+            ces_events,
+            eip191_signatures,
+            timelock_delay,
+        } => {
+            deploy_bridge_contract(
+                &env,
+                session_code_path,
+                ces_events,
+                eip191_signatures,
+                timelock_delay,
+            )
+            .await?
+        }
+        Command::UpgradeBridgeContract {
+            session_code_path,
+            disable_old_version,
+            contract_package_hash,
+        } => {
+            upgrade_bridge_contract(
+                &env,
+                session_code_path,
+                disable_old_version,
+                contract_package_hash,
+            )
+            .await?
         }
 
         Command::BridgeIn {
@@ -363,7 +409,13 @@ async fn erc20_transfer(
     Ok(())
 }
 
-async fn deploy_bridge_contract(env: &CommonEnv, session_code_path: String) -> anyhow::Result<()> {
+async fn deploy_bridge_contract(
+    env: &CommonEnv,
+    session_code_path: String,
+    ces_events: bool,
+    eip191_signatures: bool,
+    timelock_delay: u64,
+) -> anyhow::Result<()> {
     let client = env.make_client()?;
     let session_code = tokio::fs::read(session_code_path)
         .await
@@ -371,7 +423,33 @@ async fn deploy_bridge_contract(env: &CommonEnv, session_code_path: String) -> a
     let mut args = RuntimeArgs::new();
 
     args.insert_cl_value("signer", CLValue::from_t("").expect("infallible"));
-    
+    args.insert_cl_value(
+        "chain_name",
+        CLValue::from_t(env.chain_name()?.to_string()).expect("infallible"),
+    );
+    let event_mode = if ces_events {
+        EventMode::Ces
+    } else {
+        EventMode::Legacy
+    };
+    args.insert_cl_value(
+        "event_mode",
+        CLValue::from_t(u8::from(event_mode)).expect("infallible"),
+    );
+    let signature_mode = if eip191_signatures {
+        SignatureMode::Eip191
+    } else {
+        SignatureMode::Raw
+    };
+    args.insert_cl_value(
+        "signature_mode",
+        CLValue::from_t(u8::from(signature_mode)).expect("infallible"),
+    );
+    args.insert_cl_value(
+        "timelock_delay",
+        CLValue::from_t(timelock_delay).expect("infallible"),
+    );
+
     let deploy = client.make_simple_deploy(
         U512::one() * 200_000_000_000u64,
         ExecutableDeployItem::ModuleBytes {
@@ -390,6 +468,53 @@ async fn deploy_bridge_contract(env: &CommonEnv, session_code_path: String) -> a
     Ok(())
 }
 
+async fn upgrade_bridge_contract(
+    env: &CommonEnv,
+    session_code_path: String,
+    disable_old_version: bool,
+    contract_package_hash: Option<String>,
+) -> anyhow::Result<()> {
+    let client = env.make_client()?;
+    let session_code = tokio::fs::read(session_code_path)
+        .await
+        .context("couldn't read session code file")?;
+    let mut args = RuntimeArgs::new();
+
+    args.insert_cl_value(
+        "disable_old_version",
+        CLValue::from_t(disable_old_version).expect("infallible"),
+    );
+    args.insert_cl_value(
+        "chain_name",
+        CLValue::from_t(env.chain_name()?.to_string()).expect("infallible"),
+    );
+    if let Some(contract_package_hash) = contract_package_hash {
+        let contract_package_hash = ContractPackageHash::from_formatted_str(&contract_package_hash)
+            .map_err(|_| anyhow!("invalid contract package hash"))?;
+        args.insert_cl_value(
+            "contract_package_hash",
+            CLValue::from_t(contract_package_hash).expect("infallible"),
+        );
+    }
+
+    let deploy = client.make_simple_deploy(
+        U512::one() * 200_000_000_000u64,
+        ExecutableDeployItem::ModuleBytes {
+            module_bytes: Bytes::from(session_code),
+            args,
+        },
+    )?;
+
+    let deploy_hash = client.put_deploy(deploy).await?;
+
+    println!("{}", json!(deploy_hash));
+    eprintln!("waiting for deploy to be confirmed");
+    client.confirm_deploy(deploy_hash).await?;
+    eprintln!("deploy confirmed");
+
+    Ok(())
+}
+
 async fn bridge_transfer_in(
     env: &CommonEnv,
     destination_chain: String,
@@ -415,6 +540,16 @@ async fn bridge_transfer_in(
     let deadline = U256::from_dec_str(&deadline).context("couldn't parse deadline")?;
     let nonce = U128::from_dec_str(&nonce).context("couldn't parse amount")?;
     let transaction_id = U256::from_dec_str(&transaction_id).context("couldn't parse amount")?;
+    let signatures = signature
+        .split(',')
+        .map(|signature| {
+            let bytes = base16::decode(signature.trim()).context("couldn't decode signature")?;
+            match bytes.len() {
+                64 | 65 => Ok(Bytes::from(bytes)),
+                _ => Err(anyhow!("signature must be 64 or 65 bytes long")),
+            }
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
 
     let deploy_hash = client
         .bridge_in(
@@ -427,7 +562,7 @@ async fn bridge_transfer_in(
             transaction_id,
             destination_chain,
             destination_address,
-            signature,
+            signatures,
         )
         .await?;
 
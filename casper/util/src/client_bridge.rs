@@ -1,10 +1,80 @@
 use casper_execution_engine::core::engine_state::ExecutableDeployItem;
+use casper_hashing::Digest;
+use casper_node::rpcs::state::{DictionaryIdentifier, GlobalStateIdentifier};
 use casper_types::{
-    ContractHash, ContractPackageHash, DeployHash, Key, RuntimeArgs, U128, U256, U512,
+    bytesrepr::{Bytes, FromBytes, ToBytes},
+    CLTyped, CLValue, ContractHash, ContractPackageHash, DeployHash, Key, RuntimeArgs, StoredValue,
+    U128, U256, U512,
+};
+
+use contract_bridge::{
+    args::{BridgeInArgs, BridgeOutArgs, SetSignerArgs, SetStableCommissionPercentArgs},
+    entry_points::{
+        EP_BRIDGE_IN, EP_BRIDGE_OUT, EP_GET_SIGNER, EP_GET_STABLE_COMMISSION_PERCENT,
+        EP_SET_SIGNER, EP_SET_STABLE_COMMISSION_PERCENT,
+    },
 };
 
 use crate::{client::CasperClient, error::Error};
 
+/// Hundred percent in BPS, the denomination of stable commission percents.
+const HUNDRED_PERCENT_BPS: u64 = 10000;
+
+/// Code of the `QueryFailed` error the node answers queries of missing keys with.
+///
+/// Checked against the casper-node 1.4 revision this client is built with. Only the code is relied
+/// on, as the message text is not part of the RPC interface and may change between node versions.
+/// The code is also used for an unknown state root, which cannot happen here as the root is always
+/// read from the same node first.
+const QUERY_FAILED_ERROR_CODE: i64 = -32003;
+
+fn dictionary_item_key(token_contract: ContractPackageHash) -> String {
+    base64::encode(token_contract.to_bytes().expect("infallible"))
+}
+
+fn into_cl_value(stored_value: StoredValue) -> Result<CLValue, Error> {
+    match stored_value {
+        StoredValue::CLValue(cl_value) => Ok(cl_value),
+        other => Err(Error::UnexpectedStoredValueType {
+            expected: "ClValue".into(),
+            got: other.type_name(),
+        }),
+    }
+}
+
+fn parse_cl_value<T: CLTyped + FromBytes>(cl_value: CLValue) -> Result<T, Error> {
+    cl_value.into_t().map_err(Error::CLValue)
+}
+
+fn is_value_not_found(error: &jsonrpc_lite::Error) -> bool {
+    error.code == QUERY_FAILED_ERROR_CODE
+}
+
+/// Value of a dictionary item. An item that was never written reads as `None`, as the node
+/// answers with an error for it.
+fn dictionary_item_value<T: CLTyped + FromBytes>(
+    result: Result<StoredValue, Error>,
+) -> Result<Option<T>, Error> {
+    match result {
+        Ok(stored_value) => parse_cl_value(into_cl_value(stored_value)?).map(Some),
+        Err(Error::RpcError(error)) if is_value_not_found(&error) => Ok(None),
+        Err(error) => Err(error),
+    }
+}
+
+/// Stable commission percent in BPS. Deployments made before BPS precision store whole percents.
+fn stable_commission_in_bps(percent: U256, in_bps: bool) -> U256 {
+    if in_bps {
+        percent
+    } else {
+        percent * (HUNDRED_PERCENT_BPS / 100)
+    }
+}
+
+fn total_commission(amount: U256, stable_commission_percent: U256, gas_commission: U256) -> U256 {
+    amount * stable_commission_percent / U256::from(HUNDRED_PERCENT_BPS) + gas_commission
+}
+
 impl CasperClient {
     pub async fn bridge_in(
         &self,
@@ -17,7 +87,7 @@ impl CasperClient {
         transaction_id: U256,
         destination_chain: String,
         destination_address: String,
-        signature: String,
+        signatures: Vec<Bytes>,
     ) -> Result<DeployHash, Error> {
         let token_contract = ContractPackageHash::new(token_contract.into_hash().unwrap());
 
@@ -25,18 +95,19 @@ impl CasperClient {
             U512::one() * 1_000_000_000u64,
             ExecutableDeployItem::StoredContractByHash {
                 hash: bridge_contract,
-                entry_point: "bridge_in".into(),
-                args: RuntimeArgs::try_new(|args| {
-                    args.insert("token_contract", token_contract)?;
-                    args.insert("amount", amount)?;
-                    args.insert("gas_commission", gas_commission)?;
-                    args.insert("deadline", deadline)?;
-                    args.insert("nonce", nonce)?;
-                    args.insert("destination_chain", destination_chain)?;
-                    args.insert("destination_address", destination_address)?;
-                    args.insert("signature", signature)?;
-                    Ok(())
-                })
+                entry_point: EP_BRIDGE_IN.into(),
+                args: BridgeInArgs {
+                    token_contract,
+                    amount,
+                    gas_commission,
+                    deadline,
+                    nonce,
+                    transaction_id,
+                    destination_chain,
+                    destination_address,
+                    signatures,
+                }
+                .into_runtime_args()
                 .expect("args"),
             },
         )?;
@@ -60,16 +131,16 @@ impl CasperClient {
             U512::one() * 1_000_000_000u64,
             ExecutableDeployItem::StoredContractByHash {
                 hash: bridge_contract,
-                entry_point: "bridge_out".into(),
-                args: RuntimeArgs::try_new(|args| {
-                    args.insert("token_contract", token_contract)?;
-                    args.insert("transaction_id", transaction_id)?;
-                    args.insert("amount", amount)?;
-                    args.insert("source_chain", source_chain)?;
-                    args.insert("source_address", source_address)?;
-                    args.insert("recipient", recipient)?;
-                    Ok(())
-                })
+                entry_point: EP_BRIDGE_OUT.into(),
+                args: BridgeOutArgs {
+                    token_contract,
+                    amount,
+                    transaction_id,
+                    source_chain,
+                    source_address,
+                    recipient,
+                }
+                .into_runtime_args()
                 .expect("args"),
             },
         )?;
@@ -86,11 +157,11 @@ impl CasperClient {
             U512::one() * 1_000_000_000u64,
             ExecutableDeployItem::StoredContractByHash {
                 hash: bridge_contract,
-                entry_point: "set_stable_commission_percent".into(),
-                args: RuntimeArgs::try_new(|args| {
-                    args.insert("stable_commission_percent", stable_commission_percent)?;
-                    Ok(())
-                })
+                entry_point: EP_SET_STABLE_COMMISSION_PERCENT.into(),
+                args: SetStableCommissionPercentArgs {
+                    stable_commission_percent,
+                }
+                .into_runtime_args()
                 .expect("args"),
             },
         )?;
@@ -107,12 +178,8 @@ impl CasperClient {
             U512::one() * 1_000_000_000u64,
             ExecutableDeployItem::StoredContractByHash {
                 hash: bridge_contract,
-                entry_point: "set_signer".into(),
-                args: RuntimeArgs::try_new(|args| {
-                    args.insert("signer", signer)?;
-                    Ok(())
-                })
-                .expect("args"),
+                entry_point: EP_SET_SIGNER.into(),
+                args: SetSignerArgs { signer }.into_runtime_args().expect("args"),
             },
         )?;
 
@@ -127,7 +194,7 @@ impl CasperClient {
             U512::one() * 1_000_000_000u64, // vvvq do we need payment?
             ExecutableDeployItem::StoredContractByHash {
                 hash: bridge_contract,
-                entry_point: "get_stable_commission_percent".into(), // vvvq do we need this way ofn runtime args declaration?
+                entry_point: EP_GET_STABLE_COMMISSION_PERCENT.into(), // vvvq do we need this way ofn runtime args declaration?
                 args: RuntimeArgs::try_new(|args| Ok(())).expect("args"),
             },
         )?;
@@ -140,11 +207,249 @@ impl CasperClient {
             U512::one() * 1_000_000_000u64, // vvvq do we need payment?
             ExecutableDeployItem::StoredContractByHash {
                 hash: bridge_contract,
-                entry_point: "get_signer".into(), // vvvq do we need this way ofn runtime args declaration?
+                entry_point: EP_GET_SIGNER.into(), // vvvq do we need this way ofn runtime args declaration?
                 args: RuntimeArgs::try_new(|args| Ok(())).expect("args"),
             },
         )?;
 
         Ok(self.put_deploy(deploy).await?)
     }
+
+    /// Reads an item of a bridge dictionary. A missing item reads as `None`.
+    async fn bridge_dictionary_item<T: CLTyped + FromBytes>(
+        &self,
+        state_root_hash: Digest,
+        bridge_contract: ContractHash,
+        dictionary_name: &str,
+        token_contract: ContractPackageHash,
+    ) -> Result<Option<T>, Error> {
+        let result = self
+            .get_dictionary_item(
+                state_root_hash,
+                DictionaryIdentifier::ContractNamedKey {
+                    key: Key::from(bridge_contract).to_formatted_string(),
+                    dictionary_name: dictionary_name.into(),
+                    dictionary_item_key: dictionary_item_key(token_contract),
+                },
+            )
+            .await;
+
+        dictionary_item_value(result.map(|item| item.stored_value))
+    }
+
+    /// Commission collected by the bridge in a token, read from global state.
+    pub async fn bridge_query_commission_pool(
+        &self,
+        bridge_contract: ContractHash,
+        token_contract: ContractPackageHash,
+    ) -> Result<U256, Error> {
+        let state_root_hash = self.get_state_root_hash().await?;
+
+        let commission = self
+            .bridge_dictionary_item(
+                state_root_hash,
+                bridge_contract,
+                "commission_by_token",
+                token_contract,
+            )
+            .await?;
+
+        Ok(commission.unwrap_or_default())
+    }
+
+    /// Balance of a token held by the bridge minus its commission pool, read from global state.
+    pub async fn bridge_query_available_liquidity(
+        &self,
+        bridge_contract: ContractHash,
+        token_contract: ContractPackageHash,
+    ) -> Result<U256, Error> {
+        let state_root_hash = self.get_state_root_hash().await?;
+
+        let bridge_package = match self
+            .query_global_state(
+                GlobalStateIdentifier::StateRootHash(state_root_hash),
+                Key::from(bridge_contract),
+                vec![],
+            )
+            .await?
+            .stored_value
+        {
+            StoredValue::Contract(contract) => contract.contract_package_hash(),
+            other => {
+                return Err(Error::UnexpectedStoredValueType {
+                    expected: "Contract".into(),
+                    got: other.type_name(),
+                })
+            }
+        };
+
+        let token_hash = match self
+            .query_global_state(
+                GlobalStateIdentifier::StateRootHash(state_root_hash),
+                Key::from(token_contract),
+                vec![],
+            )
+            .await?
+            .stored_value
+        {
+            StoredValue::ContractPackage(package) => {
+                package
+                    .current_contract_hash()
+                    .ok_or(Error::NoEnabledContractVersion {
+                        package: token_contract.to_formatted_string(),
+                    })?
+            }
+            other => {
+                return Err(Error::UnexpectedStoredValueType {
+                    expected: "ContractPackage".into(),
+                    got: other.type_name(),
+                })
+            }
+        };
+
+        let balance: U256 = parse_cl_value(
+            self.erc20_query_balance(Key::from(token_hash), Key::from(bridge_package))
+                .await?,
+        )?;
+        let commission = self
+            .bridge_query_commission_pool(bridge_contract, token_contract)
+            .await?;
+
+        Ok(balance.saturating_sub(commission))
+    }
+
+    /// Global stable commission percent in BPS, read from global state the same way as
+    /// `get_stable_commission_percent`.
+    async fn bridge_query_stable_commission_percent(
+        &self,
+        state_root_hash: Digest,
+        bridge_contract: ContractHash,
+    ) -> Result<U256, Error> {
+        let in_bps = match self
+            .query_global_state(
+                GlobalStateIdentifier::StateRootHash(state_root_hash),
+                Key::from(bridge_contract),
+                vec![],
+            )
+            .await?
+            .stored_value
+        {
+            StoredValue::Contract(contract) => contract
+                .named_keys()
+                .contains_key("stable_commission_in_bps"),
+            other => {
+                return Err(Error::UnexpectedStoredValueType {
+                    expected: "Contract".into(),
+                    got: other.type_name(),
+                })
+            }
+        };
+
+        let percent: U256 = parse_cl_value(into_cl_value(
+            self.query_global_state(
+                GlobalStateIdentifier::StateRootHash(state_root_hash),
+                Key::from(bridge_contract),
+                vec!["stable_commission_percent"],
+            )
+            .await?
+            .stored_value,
+        )?)?;
+
+        Ok(stable_commission_in_bps(percent, in_bps))
+    }
+
+    /// Commission charged by the bridge for transferring `amount` of a token, computed from
+    /// global state the same way as `get_total_commission`.
+    pub async fn bridge_query_total_commission(
+        &self,
+        bridge_contract: ContractHash,
+        token_contract: ContractPackageHash,
+        amount: U256,
+        gas_commission: U256,
+    ) -> Result<U256, Error> {
+        let state_root_hash = self.get_state_root_hash().await?;
+
+        let token_percent: Option<Option<U256>> = self
+            .bridge_dictionary_item(
+                state_root_hash,
+                bridge_contract,
+                "stable_commission_by_token",
+                token_contract,
+            )
+            .await?;
+
+        let percent = match token_percent.flatten() {
+            Some(percent) => percent,
+            None => {
+                self.bridge_query_stable_commission_percent(state_root_hash, bridge_contract)
+                    .await?
+            }
+        };
+
+        Ok(total_commission(amount, percent, gas_commission))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rpc_error(code: i64, message: &str) -> jsonrpc_lite::Error {
+        jsonrpc_lite::Error {
+            code,
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    #[test]
+    fn value_not_found_is_keyed_off_the_error_code() {
+        for message in ["Failed to find base key at path", "value not found"] {
+            let error = rpc_error(QUERY_FAILED_ERROR_CODE, message);
+            assert!(is_value_not_found(&error));
+        }
+
+        let error = rpc_error(-32010, "Failed to get dictionary URef");
+        assert!(!is_value_not_found(&error));
+    }
+
+    #[test]
+    fn missing_dictionary_item_reads_as_none() {
+        let error = rpc_error(QUERY_FAILED_ERROR_CODE, "Failed to find base key");
+        let value: Option<U256> = dictionary_item_value(Err(Error::RpcError(error))).unwrap();
+        assert_eq!(value, None);
+
+        let stored_value = StoredValue::CLValue(CLValue::from_t(U256::from(7)).unwrap());
+        let value: Option<U256> = dictionary_item_value(Ok(stored_value)).unwrap();
+        assert_eq!(value, Some(U256::from(7)));
+
+        let error = rpc_error(-32010, "Failed to get dictionary URef");
+        let result = dictionary_item_value::<U256>(Err(Error::RpcError(error)));
+        assert!(matches!(result, Err(Error::RpcError(_))));
+
+        let stored_value = StoredValue::CLValue(CLValue::from_t(true).unwrap());
+        let result = dictionary_item_value::<U256>(Ok(stored_value));
+        assert!(matches!(result, Err(Error::CLValue(_))));
+    }
+
+    #[test]
+    fn legacy_whole_percent_is_normalized_to_bps() {
+        let percent = stable_commission_in_bps(U256::from(4), false);
+        assert_eq!(percent, U256::from(400));
+
+        let percent = stable_commission_in_bps(U256::from(400), true);
+        assert_eq!(percent, U256::from(400));
+    }
+
+    #[test]
+    fn total_commission_applies_bps_percent() {
+        let amount = U256::from(1_000_000);
+        let gas_commission = U256::from(1_000);
+
+        let commission = total_commission(amount, U256::from(25), gas_commission);
+        assert_eq!(commission, U256::from(2_500 + 1_000));
+
+        let commission = total_commission(amount, U256::zero(), gas_commission);
+        assert_eq!(commission, gas_commission);
+    }
 }
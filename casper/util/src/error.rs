@@ -23,6 +23,12 @@ pub enum Error {
     #[error("unexpected StoredValue type (expected: {expected}, got: {got}")]
     UnexpectedStoredValueType { expected: String, got: String },
 
+    #[error("unexpected CLValue: {0:?}")]
+    CLValue(casper_types::CLValueError),
+
+    #[error("contract package {package} has no enabled version")]
+    NoEnabledContractVersion { package: String },
+
     #[error("invalid key format ({given})")]
     InvalidKeyFormat { given: String },
 